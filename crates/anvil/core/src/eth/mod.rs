@@ -1,4 +1,7 @@
-use crate::{eth::subscription::SubscriptionId, types::ReorgOptions};
+use crate::{
+    eth::subscription::SubscriptionId,
+    types::{ReorgOptions, SubmitRetryableRequest},
+};
 use alloy_primitives::{Address, B64, B256, Bytes, TxHash, U256};
 use alloy_rpc_types::{
     BlockId, BlockNumberOrTag as BlockNumber, BlockOverrides, Filter, Index,
@@ -708,6 +711,18 @@ pub enum EthRequest {
     #[serde(rename = "anvil_rollback", with = "sequence")]
     Rollback(Option<u64>),
 
+    /// Submits an Arbitrum L1 to L2 retryable ticket
+    #[serde(rename = "anvil_submitRetryable", with = "sequence")]
+    SubmitRetryable(SubmitRetryableRequest),
+
+    /// Returns the Arbitrum L2 to L1 messages sent so far
+    #[serde(rename = "anvil_getL2ToL1Messages", with = "empty_params")]
+    GetL2ToL1Messages(()),
+
+    /// Returns the send Merkle proof of an Arbitrum L2 to L1 message
+    #[serde(rename = "anvil_getL2ToL1MessageProof", with = "sequence")]
+    GetL2ToL1MessageProof(u64),
+
     /// Wallet
     #[serde(rename = "wallet_getCapabilities", with = "empty_params")]
     WalletGetCapabilities(()),
@@ -1047,6 +1062,43 @@ fn test_custom_reset() {
         }
     }
 
+    #[test]
+    fn test_custom_submit_retryable() {
+        let s = r#"{"method": "anvil_submitRetryable", "params": [{
+            "from": "0xd84de507f3fada7df80908082d3239466db55a71",
+            "to": "0x0000000000000000000000000000000000001337",
+            "value": "0x1",
+            "gas": "0x186a0",
+            "data": "0xabcdef"
+        }]}"#;
+        let value: serde_json::Value = serde_json::from_str(s).unwrap();
+        let req = serde_json::from_value::<EthRequest>(value).unwrap();
+        match req {
+            EthRequest::SubmitRetryable(request) => {
+                assert_eq!(request.value, U256::from(1));
+                assert_eq!(request.gas, 100_000);
+                assert_eq!(request.deposit, None);
+                assert_eq!(request.data, Bytes::from_static(&[0xab, 0xcd, 0xef]));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_custom_l2_to_l1_messages() {
+        let s = r#"{"method": "anvil_getL2ToL1Messages", "params": []}"#;
+        let value: serde_json::Value = serde_json::from_str(s).unwrap();
+        let _req = serde_json::from_value::<EthRequest>(value).unwrap();
+
+        let s = r#"{"method": "anvil_getL2ToL1MessageProof", "params": [2]}"#;
+        let value: serde_json::Value = serde_json::from_str(s).unwrap();
+        let req = serde_json::from_value::<EthRequest>(value).unwrap();
+        match req {
+            EthRequest::GetL2ToL1MessageProof(index) => assert_eq!(index, 2),
+            _ => unreachable!(),
+        }
+    }
+
     #[test]
     fn test_custom_set_balance() {
         let s = r#"{"method": "anvil_setBalance", "params":
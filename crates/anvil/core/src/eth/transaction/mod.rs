@@ -18,7 +18,18 @@
 };
 use alloy_serde::WithOtherFields;
 use bytes::BufMut;
-use foundry_evm::{core::FromRecoveredTx, traces::CallTraceNode};
+use foundry_evm::{
+    core::{
+        FromRecoveredTx,
+        tx::arbitrum::{
+            ARBITRUM_CONTRACT_TX_TYPE_ID, ARBITRUM_DEPOSIT_TX_TYPE_ID,
+            ARBITRUM_INTERNAL_TX_TYPE_ID, ARBITRUM_RETRY_TX_TYPE_ID,
+            ARBITRUM_SUBMIT_RETRYABLE_TX_TYPE_ID, ARBITRUM_UNSIGNED_TX_TYPE_ID, ArbitrumTxEnvelope,
+            is_arbitrum_tx_type,
+        },
+    },
+    traces::CallTraceNode,
+};
 
 use revm::{context::TxEnv, interpreter::InstructionResult};
 use serde::{Deserialize, Serialize};
@@ -214,10 +225,14 @@ pub fn hash(&self) -> B256 {
     }
 
     /// Converts the transaction into an [`RpcTransaction`]
-    pub fn into_rpc_transaction(self) -> RpcTransaction {
+    ///
+    /// Fails for Arbitrum transactions, which have no Ethereum envelope.
+    pub fn into_rpc_transaction(self) -> Result<RpcTransaction, ConversionError> {
         let hash = self.hash();
         let from = self.recover().unwrap_or_default();
-        let envelope = self.transaction.try_into_eth().expect("cant build deposit transactions");
+        let envelope = self.transaction.try_into_eth().map_err(|tx| {
+            ConversionError::Custom(format!("unsupported transaction type {:#x}", tx.ty()))
+        })?;
 
         // NOTE: we must update the hash because the tx can be impersonated, this requires forcing
         // the hash
@@ -244,13 +259,13 @@ pub fn into_rpc_transaction(self) -> RpcTransaction {
             }
         };
 
-        RpcTransaction {
+        Ok(RpcTransaction {
             block_hash: None,
             block_number: None,
             transaction_index: None,
             effective_gas_price: None,
             inner: Recovered::new_unchecked(inner_envelope, from),
-        }
+        })
     }
 }
 
@@ -302,8 +317,10 @@ fn deref(&self) -> &Self::Target {
     }
 }
 
-impl From<MaybeImpersonatedTransaction> for RpcTransaction {
-    fn from(value: MaybeImpersonatedTransaction) -> Self {
+impl TryFrom<MaybeImpersonatedTransaction> for RpcTransaction {
+    type Error = ConversionError;
+
+    fn try_from(value: MaybeImpersonatedTransaction) -> Result<Self, Self::Error> {
         value.into_rpc_transaction()
     }
 }
@@ -372,6 +389,8 @@ pub enum TypedTransaction {
     EIP4844(Signed<TxEip4844Variant>),
     /// EIP-7702 transaction
     EIP7702(Signed<TxEip7702>),
+    /// Arbitrum transaction, derived from an L1 message or created by ArbOS
+    Arbitrum(ArbitrumTxEnvelope),
 }
 
 impl TryFrom<AnyRpcTransaction> for TypedTransaction {
@@ -379,6 +398,7 @@ impl TryFrom<AnyRpcTransaction> for TypedTransaction {
 
     fn try_from(value: AnyRpcTransaction) -> Result<Self, Self::Error> {
         let WithOtherFields { inner, .. } = value.0;
+        let from = inner.inner.signer();
         match inner.inner.into_inner() {
             AnyTxEnvelope::Ethereum(tx) => match tx {
                 TxEnvelope::Legacy(tx) => Ok(Self::Legacy(tx)),
@@ -387,6 +407,15 @@ fn try_from(value: AnyRpcTransaction) -> Result<Self, Self::Error> {
                 TxEnvelope::Eip4844(tx) => Ok(Self::EIP4844(tx)),
                 TxEnvelope::Eip7702(tx) => Ok(Self::EIP7702(tx)),
             },
+            AnyTxEnvelope::Unknown(tx) if is_arbitrum_tx_type(tx.ty()) => {
+                let mut fields = tx.inner.fields;
+                fields.insert("type".to_string(), format!("{:#x}", tx.ty()).into());
+                fields.entry("from".to_string()).or_insert_with(|| from.to_string().into());
+                let tx: ArbitrumTxEnvelope = fields
+                    .deserialize_into()
+                    .map_err(|err| ConversionError::Custom(err.to_string()))?;
+                Ok(Self::Arbitrum(tx))
+            }
             AnyTxEnvelope::Unknown(_) => Err(ConversionError::Custom("UnknownTxType".to_string())),
         }
     }
@@ -401,6 +430,7 @@ pub fn try_into_eth(self) -> Result<TxEnvelope, Self> {
             Self::EIP1559(tx) => Ok(TxEnvelope::Eip1559(tx)),
             Self::EIP4844(tx) => Ok(TxEnvelope::Eip4844(tx)),
             Self::EIP7702(tx) => Ok(TxEnvelope::Eip7702(tx)),
+            Self::Arbitrum(_) => Err(self),
         }
     }
 
@@ -416,6 +446,7 @@ pub fn gas_price(&self) -> u128 {
             Self::EIP1559(tx) => tx.tx().max_fee_per_gas,
             Self::EIP4844(tx) => tx.tx().tx().max_fee_per_gas,
             Self::EIP7702(tx) => tx.tx().max_fee_per_gas,
+            Self::Arbitrum(tx) => tx.gas_fee_cap().saturating_to(),
         }
     }
 
@@ -426,6 +457,7 @@ pub fn gas_limit(&self) -> u64 {
             Self::EIP1559(tx) => tx.tx().gas_limit,
             Self::EIP4844(tx) => tx.tx().tx().gas_limit,
             Self::EIP7702(tx) => tx.tx().gas_limit,
+            Self::Arbitrum(tx) => tx.gas_limit(),
         }
     }
 
@@ -436,6 +468,7 @@ pub fn value(&self) -> U256 {
             Self::EIP1559(tx) => tx.tx().value,
             Self::EIP4844(tx) => tx.tx().tx().value,
             Self::EIP7702(tx) => tx.tx().value,
+            Self::Arbitrum(tx) => tx.value(),
         })
     }
 
@@ -446,6 +479,7 @@ pub fn data(&self) -> &Bytes {
             Self::EIP1559(tx) => &tx.tx().input,
             Self::EIP4844(tx) => &tx.tx().tx().input,
             Self::EIP7702(tx) => &tx.tx().input,
+            Self::Arbitrum(tx) => tx.data(),
         }
     }
 
@@ -457,6 +491,7 @@ pub fn r#type(&self) -> Option<u8> {
             Self::EIP1559(_) => Some(2),
             Self::EIP4844(_) => Some(3),
             Self::EIP7702(_) => Some(4),
+            Self::Arbitrum(tx) => Some(tx.ty()),
         }
     }
 
@@ -576,6 +611,20 @@ pub fn essentials(&self) -> TransactionEssentials {
                 chain_id: Some(t.tx().chain_id),
                 access_list: t.tx().access_list.clone(),
             },
+            Self::Arbitrum(t) => TransactionEssentials {
+                kind: t.kind(),
+                input: t.input(),
+                nonce: t.nonce(),
+                gas_limit: t.gas_limit(),
+                gas_price: None,
+                max_fee_per_gas: Some(t.gas_fee_cap().saturating_to()),
+                max_priority_fee_per_gas: None,
+                max_fee_per_blob_gas: None,
+                blob_versioned_hashes: None,
+                value: t.value(),
+                chain_id: Some(t.chain_id().saturating_to()),
+                access_list: Default::default(),
+            },
         }
     }
 
@@ -586,6 +635,7 @@ pub fn nonce(&self) -> u64 {
             Self::EIP1559(t) => t.tx().nonce,
             Self::EIP4844(t) => t.tx().tx().nonce,
             Self::EIP7702(t) => t.tx().nonce,
+            Self::Arbitrum(t) => t.nonce(),
         }
     }
 
@@ -596,6 +646,7 @@ pub fn chain_id(&self) -> Option<u64> {
             Self::EIP1559(t) => Some(t.tx().chain_id),
             Self::EIP4844(t) => Some(t.tx().tx().chain_id),
             Self::EIP7702(t) => Some(t.tx().chain_id),
+            Self::Arbitrum(t) => Some(t.chain_id().saturating_to()),
         }
     }
 
@@ -631,6 +682,17 @@ pub fn is_eip7702(&self) -> bool {
         matches!(self, Self::EIP7702(_))
     }
 
+    /// Returns true whether this tx is an Arbitrum transaction
+    pub fn is_arbitrum(&self) -> bool {
+        matches!(self, Self::Arbitrum(_))
+    }
+
+    /// Returns true if the sender's nonce is not checked against this tx, as for all Arbitrum
+    /// transactions but unsigned ones
+    pub fn skips_nonce_check(&self) -> bool {
+        matches!(self, Self::Arbitrum(tx) if tx.skip_account_checks())
+    }
+
     /// Returns the hash of the transaction.
     ///
     /// Note: If this transaction has the Impersonated signature then this returns a modified unique
@@ -642,6 +704,7 @@ pub fn hash(&self) -> B256 {
             Self::EIP1559(t) => *t.hash(),
             Self::EIP4844(t) => *t.hash(),
             Self::EIP7702(t) => *t.hash(),
+            Self::Arbitrum(t) => t.hash(),
         }
     }
 
@@ -663,6 +726,7 @@ pub fn recover(&self) -> Result<Address, alloy_primitives::SignatureError> {
             Self::EIP1559(tx) => tx.recover_signer(),
             Self::EIP4844(tx) => tx.recover_signer(),
             Self::EIP7702(tx) => tx.recover_signer(),
+            Self::Arbitrum(tx) => Ok(tx.sender()),
         }
     }
 
@@ -674,6 +738,7 @@ pub fn kind(&self) -> TxKind {
             Self::EIP1559(tx) => tx.tx().to,
             Self::EIP4844(tx) => TxKind::Call(tx.tx().tx().to),
             Self::EIP7702(tx) => TxKind::Call(tx.tx().to),
+            Self::Arbitrum(tx) => tx.kind(),
         }
     }
 
@@ -690,6 +755,8 @@ pub fn signature(&self) -> Signature {
             Self::EIP1559(tx) => *tx.signature(),
             Self::EIP4844(tx) => *tx.signature(),
             Self::EIP7702(tx) => *tx.signature(),
+            // Arbitrum transactions are not signed
+            Self::Arbitrum(_) => Signature::new(U256::ZERO, U256::ZERO, false),
         }
     }
 }
@@ -714,7 +781,7 @@ fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
             return Ok(TxEnvelope::decode(buf)?.into());
         }
 
-        Ok(TxEnvelope::decode(buf)?.into())
+        Ok(Self::network_decode(buf)?)
     }
 }
 
@@ -732,6 +799,7 @@ fn encode_2718_len(&self) -> usize {
             Self::EIP1559(tx) => TxEnvelope::from(tx.clone()).encode_2718_len(),
             Self::EIP4844(tx) => TxEnvelope::from(tx.clone()).encode_2718_len(),
             Self::EIP7702(tx) => TxEnvelope::from(tx.clone()).encode_2718_len(),
+            Self::Arbitrum(tx) => tx.encode_2718_len(),
         }
     }
 
@@ -742,12 +810,16 @@ fn encode_2718(&self, out: &mut dyn BufMut) {
             Self::EIP1559(tx) => TxEnvelope::from(tx.clone()).encode_2718(out),
             Self::EIP4844(tx) => TxEnvelope::from(tx.clone()).encode_2718(out),
             Self::EIP7702(tx) => TxEnvelope::from(tx.clone()).encode_2718(out),
+            Self::Arbitrum(tx) => tx.encode_2718(out),
         }
     }
 }
 
 impl Decodable2718 for TypedTransaction {
     fn typed_decode(ty: u8, buf: &mut &[u8]) -> Result<Self, Eip2718Error> {
+        if is_arbitrum_tx_type(ty) {
+            return Ok(Self::Arbitrum(ArbitrumTxEnvelope::typed_decode(ty, buf)?));
+        }
         match TxEnvelope::typed_decode(ty, buf)? {
             TxEnvelope::Eip2930(tx) => Ok(Self::EIP2930(tx)),
             TxEnvelope::Eip1559(tx) => Ok(Self::EIP1559(tx)),
@@ -806,6 +878,9 @@ pub struct TransactionInfo {
     pub out: Option<Bytes>,
     pub nonce: u64,
     pub gas_used: u64,
+    /// The gas paying for the L1 cost of posting the transaction, on Arbitrum chains
+    #[serde(default)]
+    pub gas_used_for_l1: u64,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -821,6 +896,18 @@ pub enum TypedReceipt {
     EIP4844(ReceiptWithBloom<Receipt<alloy_primitives::Log>>),
     #[serde(rename = "0x4", alias = "0x04")]
     EIP7702(ReceiptWithBloom<Receipt<alloy_primitives::Log>>),
+    #[serde(rename = "0x64")]
+    ArbitrumDeposit(ReceiptWithBloom<Receipt<alloy_primitives::Log>>),
+    #[serde(rename = "0x65")]
+    ArbitrumUnsigned(ReceiptWithBloom<Receipt<alloy_primitives::Log>>),
+    #[serde(rename = "0x66")]
+    ArbitrumContract(ReceiptWithBloom<Receipt<alloy_primitives::Log>>),
+    #[serde(rename = "0x68")]
+    ArbitrumRetry(ReceiptWithBloom<Receipt<alloy_primitives::Log>>),
+    #[serde(rename = "0x69")]
+    ArbitrumSubmitRetryable(ReceiptWithBloom<Receipt<alloy_primitives::Log>>),
+    #[serde(rename = "0x6a")]
+    ArbitrumInternal(ReceiptWithBloom<Receipt<alloy_primitives::Log>>),
 }
 
 /// RPC-specific variant of TypedReceipt for boundary conversion
@@ -837,9 +924,52 @@ pub enum TypedReceiptRpc {
     EIP4844(ReceiptWithBloom<Receipt<alloy_rpc_types::Log>>),
     #[serde(rename = "0x4", alias = "0x04")]
     EIP7702(ReceiptWithBloom<Receipt<alloy_rpc_types::Log>>),
+    #[serde(rename = "0x64")]
+    ArbitrumDeposit(ReceiptWithBloom<Receipt<alloy_rpc_types::Log>>),
+    #[serde(rename = "0x65")]
+    ArbitrumUnsigned(ReceiptWithBloom<Receipt<alloy_rpc_types::Log>>),
+    #[serde(rename = "0x66")]
+    ArbitrumContract(ReceiptWithBloom<Receipt<alloy_rpc_types::Log>>),
+    #[serde(rename = "0x68")]
+    ArbitrumRetry(ReceiptWithBloom<Receipt<alloy_rpc_types::Log>>),
+    #[serde(rename = "0x69")]
+    ArbitrumSubmitRetryable(ReceiptWithBloom<Receipt<alloy_rpc_types::Log>>),
+    #[serde(rename = "0x6a")]
+    ArbitrumInternal(ReceiptWithBloom<Receipt<alloy_rpc_types::Log>>),
 }
 
 impl TypedReceipt {
+    /// Creates the receipt of the given Arbitrum transaction.
+    pub fn for_arbitrum_tx(
+        tx: &ArbitrumTxEnvelope,
+        receipt: ReceiptWithBloom<Receipt<alloy_primitives::Log>>,
+    ) -> Self {
+        match tx {
+            ArbitrumTxEnvelope::Deposit(_) => Self::ArbitrumDeposit(receipt),
+            ArbitrumTxEnvelope::Unsigned(_) => Self::ArbitrumUnsigned(receipt),
+            ArbitrumTxEnvelope::Contract(_) => Self::ArbitrumContract(receipt),
+            ArbitrumTxEnvelope::Retry(_) => Self::ArbitrumRetry(receipt),
+            ArbitrumTxEnvelope::SubmitRetryable(_) => Self::ArbitrumSubmitRetryable(receipt),
+            ArbitrumTxEnvelope::Internal(_) => Self::ArbitrumInternal(receipt),
+        }
+    }
+
+    /// Creates the receipt of an Arbitrum transaction of type `ty`, if `ty` is one.
+    pub fn arbitrum(
+        ty: u8,
+        receipt: ReceiptWithBloom<Receipt<alloy_primitives::Log>>,
+    ) -> Option<Self> {
+        Some(match ty {
+            ARBITRUM_DEPOSIT_TX_TYPE_ID => Self::ArbitrumDeposit(receipt),
+            ARBITRUM_UNSIGNED_TX_TYPE_ID => Self::ArbitrumUnsigned(receipt),
+            ARBITRUM_CONTRACT_TX_TYPE_ID => Self::ArbitrumContract(receipt),
+            ARBITRUM_RETRY_TX_TYPE_ID => Self::ArbitrumRetry(receipt),
+            ARBITRUM_SUBMIT_RETRYABLE_TX_TYPE_ID => Self::ArbitrumSubmitRetryable(receipt),
+            ARBITRUM_INTERNAL_TX_TYPE_ID => Self::ArbitrumInternal(receipt),
+            _ => return None,
+        })
+    }
+
     /// Convert to RPC-specific receipt type
     pub fn into_rpc_receipt(self) -> TypedReceiptRpc {
         match self {
@@ -848,6 +978,20 @@ pub fn into_rpc_receipt(self) -> TypedReceiptRpc {
             Self::EIP1559(r) => TypedReceiptRpc::EIP1559(convert_receipt_to_rpc(r)),
             Self::EIP4844(r) => TypedReceiptRpc::EIP4844(convert_receipt_to_rpc(r)),
             Self::EIP7702(r) => TypedReceiptRpc::EIP7702(convert_receipt_to_rpc(r)),
+            Self::ArbitrumDeposit(r) => TypedReceiptRpc::ArbitrumDeposit(convert_receipt_to_rpc(r)),
+            Self::ArbitrumUnsigned(r) => {
+                TypedReceiptRpc::ArbitrumUnsigned(convert_receipt_to_rpc(r))
+            }
+            Self::ArbitrumContract(r) => {
+                TypedReceiptRpc::ArbitrumContract(convert_receipt_to_rpc(r))
+            }
+            Self::ArbitrumRetry(r) => TypedReceiptRpc::ArbitrumRetry(convert_receipt_to_rpc(r)),
+            Self::ArbitrumSubmitRetryable(r) => {
+                TypedReceiptRpc::ArbitrumSubmitRetryable(convert_receipt_to_rpc(r))
+            }
+            Self::ArbitrumInternal(r) => {
+                TypedReceiptRpc::ArbitrumInternal(convert_receipt_to_rpc(r))
+            }
         }
     }
 
@@ -857,7 +1001,13 @@ pub fn as_receipt_with_bloom(&self) -> &ReceiptWithBloom<Receipt<alloy_primitive
             | Self::EIP1559(r)
             | Self::EIP2930(r)
             | Self::EIP4844(r)
-            | Self::EIP7702(r) => r,
+            | Self::EIP7702(r)
+            | Self::ArbitrumDeposit(r)
+            | Self::ArbitrumUnsigned(r)
+            | Self::ArbitrumContract(r)
+            | Self::ArbitrumRetry(r)
+            | Self::ArbitrumSubmitRetryable(r)
+            | Self::ArbitrumInternal(r) => r,
         }
     }
 
@@ -867,7 +1017,13 @@ pub fn logs(&self) -> &[alloy_primitives::Log] {
             | Self::EIP1559(r)
             | Self::EIP2930(r)
             | Self::EIP4844(r)
-            | Self::EIP7702(r) => &r.receipt.logs,
+            | Self::EIP7702(r)
+            | Self::ArbitrumDeposit(r)
+            | Self::ArbitrumUnsigned(r)
+            | Self::ArbitrumContract(r)
+            | Self::ArbitrumRetry(r)
+            | Self::ArbitrumSubmitRetryable(r)
+            | Self::ArbitrumInternal(r) => &r.receipt.logs,
         }
     }
 
@@ -877,7 +1033,13 @@ pub fn logs_bloom(&self) -> &Bloom {
             | Self::EIP1559(r)
             | Self::EIP2930(r)
             | Self::EIP4844(r)
-            | Self::EIP7702(r) => &r.logs_bloom,
+            | Self::EIP7702(r)
+            | Self::ArbitrumDeposit(r)
+            | Self::ArbitrumUnsigned(r)
+            | Self::ArbitrumContract(r)
+            | Self::ArbitrumRetry(r)
+            | Self::ArbitrumSubmitRetryable(r)
+            | Self::ArbitrumInternal(r) => &r.logs_bloom,
         }
     }
 
@@ -903,13 +1065,35 @@ fn convert_receipt_to_rpc(
 }
 
 impl TypedReceiptRpc {
+    /// Creates the receipt of an Arbitrum transaction of type `ty`.
+    pub fn arbitrum(
+        ty: u8,
+        receipt: ReceiptWithBloom<Receipt<alloy_rpc_types::Log>>,
+    ) -> Option<Self> {
+        Some(match ty {
+            ARBITRUM_DEPOSIT_TX_TYPE_ID => Self::ArbitrumDeposit(receipt),
+            ARBITRUM_UNSIGNED_TX_TYPE_ID => Self::ArbitrumUnsigned(receipt),
+            ARBITRUM_CONTRACT_TX_TYPE_ID => Self::ArbitrumContract(receipt),
+            ARBITRUM_RETRY_TX_TYPE_ID => Self::ArbitrumRetry(receipt),
+            ARBITRUM_SUBMIT_RETRYABLE_TX_TYPE_ID => Self::ArbitrumSubmitRetryable(receipt),
+            ARBITRUM_INTERNAL_TX_TYPE_ID => Self::ArbitrumInternal(receipt),
+            _ => return None,
+        })
+    }
+
     pub fn as_receipt_with_bloom(&self) -> &ReceiptWithBloom<Receipt<alloy_rpc_types::Log>> {
         match self {
             Self::Legacy(r)
             | Self::EIP1559(r)
             | Self::EIP2930(r)
             | Self::EIP4844(r)
-            | Self::EIP7702(r) => r,
+            | Self::EIP7702(r)
+            | Self::ArbitrumDeposit(r)
+            | Self::ArbitrumUnsigned(r)
+            | Self::ArbitrumContract(r)
+            | Self::ArbitrumRetry(r)
+            | Self::ArbitrumSubmitRetryable(r)
+            | Self::ArbitrumInternal(r) => r,
         }
     }
 
@@ -919,7 +1103,13 @@ pub fn logs_bloom(&self) -> &Bloom {
             | Self::EIP1559(r)
             | Self::EIP2930(r)
             | Self::EIP4844(r)
-            | Self::EIP7702(r) => &r.logs_bloom,
+            | Self::EIP7702(r)
+            | Self::ArbitrumDeposit(r)
+            | Self::ArbitrumUnsigned(r)
+            | Self::ArbitrumContract(r)
+            | Self::ArbitrumRetry(r)
+            | Self::ArbitrumSubmitRetryable(r)
+            | Self::ArbitrumInternal(r) => &r.logs_bloom,
         }
     }
 
@@ -929,7 +1119,13 @@ pub fn logs(&self) -> &[alloy_rpc_types::Log] {
             | Self::EIP1559(r)
             | Self::EIP2930(r)
             | Self::EIP4844(r)
-            | Self::EIP7702(r) => &r.receipt.logs,
+            | Self::EIP7702(r)
+            | Self::ArbitrumDeposit(r)
+            | Self::ArbitrumUnsigned(r)
+            | Self::ArbitrumContract(r)
+            | Self::ArbitrumRetry(r)
+            | Self::ArbitrumSubmitRetryable(r)
+            | Self::ArbitrumInternal(r) => &r.receipt.logs,
         }
     }
 
@@ -946,7 +1142,13 @@ fn from(value: TypedReceiptRpc) -> Self {
             | TypedReceiptRpc::EIP1559(r)
             | TypedReceiptRpc::EIP2930(r)
             | TypedReceiptRpc::EIP4844(r)
-            | TypedReceiptRpc::EIP7702(r) => r,
+            | TypedReceiptRpc::EIP7702(r)
+            | TypedReceiptRpc::ArbitrumDeposit(r)
+            | TypedReceiptRpc::ArbitrumUnsigned(r)
+            | TypedReceiptRpc::ArbitrumContract(r)
+            | TypedReceiptRpc::ArbitrumRetry(r)
+            | TypedReceiptRpc::ArbitrumSubmitRetryable(r)
+            | TypedReceiptRpc::ArbitrumInternal(r) => r,
         }
     }
 }
@@ -959,6 +1161,12 @@ fn from(value: TypedReceiptRpc) -> Self {
             TypedReceiptRpc::EIP1559(_) => 0x02,
             TypedReceiptRpc::EIP4844(_) => 0x03,
             TypedReceiptRpc::EIP7702(_) => 0x04,
+            TypedReceiptRpc::ArbitrumDeposit(_) => ARBITRUM_DEPOSIT_TX_TYPE_ID,
+            TypedReceiptRpc::ArbitrumUnsigned(_) => ARBITRUM_UNSIGNED_TX_TYPE_ID,
+            TypedReceiptRpc::ArbitrumContract(_) => ARBITRUM_CONTRACT_TX_TYPE_ID,
+            TypedReceiptRpc::ArbitrumRetry(_) => ARBITRUM_RETRY_TX_TYPE_ID,
+            TypedReceiptRpc::ArbitrumSubmitRetryable(_) => ARBITRUM_SUBMIT_RETRYABLE_TX_TYPE_ID,
+            TypedReceiptRpc::ArbitrumInternal(_) => ARBITRUM_INTERNAL_TX_TYPE_ID,
         } as u8;
         let receipt = ReceiptWithBloom::<Receipt<alloy_rpc_types::Log>>::from(value);
         let status = receipt.status();
@@ -992,6 +1200,12 @@ fn encode(&self, out: &mut dyn bytes::BufMut) {
                     Self::EIP1559(r) => r.length() + 1,
                     Self::EIP4844(r) => r.length() + 1,
                     Self::EIP7702(r) => r.length() + 1,
+                    Self::ArbitrumDeposit(r) => r.length() + 1,
+                    Self::ArbitrumUnsigned(r) => r.length() + 1,
+                    Self::ArbitrumContract(r) => r.length() + 1,
+                    Self::ArbitrumRetry(r) => r.length() + 1,
+                    Self::ArbitrumSubmitRetryable(r) => r.length() + 1,
+                    Self::ArbitrumInternal(r) => r.length() + 1,
                     _ => unreachable!("receipt already matched"),
                 };
 
@@ -1016,6 +1230,36 @@ fn encode(&self, out: &mut dyn bytes::BufMut) {
                         4u8.encode(out);
                         r.encode(out);
                     }
+                    Self::ArbitrumDeposit(r) => {
+                        Header { list: true, payload_length: payload_len }.encode(out);
+                        ARBITRUM_DEPOSIT_TX_TYPE_ID.encode(out);
+                        r.encode(out);
+                    }
+                    Self::ArbitrumUnsigned(r) => {
+                        Header { list: true, payload_length: payload_len }.encode(out);
+                        ARBITRUM_UNSIGNED_TX_TYPE_ID.encode(out);
+                        r.encode(out);
+                    }
+                    Self::ArbitrumContract(r) => {
+                        Header { list: true, payload_length: payload_len }.encode(out);
+                        ARBITRUM_CONTRACT_TX_TYPE_ID.encode(out);
+                        r.encode(out);
+                    }
+                    Self::ArbitrumRetry(r) => {
+                        Header { list: true, payload_length: payload_len }.encode(out);
+                        ARBITRUM_RETRY_TX_TYPE_ID.encode(out);
+                        r.encode(out);
+                    }
+                    Self::ArbitrumSubmitRetryable(r) => {
+                        Header { list: true, payload_length: payload_len }.encode(out);
+                        ARBITRUM_SUBMIT_RETRYABLE_TX_TYPE_ID.encode(out);
+                        r.encode(out);
+                    }
+                    Self::ArbitrumInternal(r) => {
+                        Header { list: true, payload_length: payload_len }.encode(out);
+                        ARBITRUM_INTERNAL_TX_TYPE_ID.encode(out);
+                        r.encode(out);
+                    }
                     _ => unreachable!("receipt already matched"),
                 }
             }
@@ -1054,6 +1298,11 @@ fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
                 } else if receipt_type == 0x04 {
                     buf.advance(1);
                     <ReceiptWithBloom as Decodable>::decode(buf).map(TypedReceipt::EIP7702)
+                } else if is_arbitrum_tx_type(receipt_type) {
+                    buf.advance(1);
+                    let receipt = <ReceiptWithBloom as Decodable>::decode(buf)?;
+                    TypedReceipt::arbitrum(receipt_type, receipt)
+                        .ok_or(alloy_rlp::Error::Custom("invalid receipt type"))
                 } else {
                     Err(alloy_rlp::Error::Custom("invalid receipt type"))
                 }
@@ -1076,6 +1325,12 @@ fn ty(&self) -> u8 {
             Self::EIP1559(_) => alloy_consensus::constants::EIP1559_TX_TYPE_ID,
             Self::EIP4844(_) => alloy_consensus::constants::EIP4844_TX_TYPE_ID,
             Self::EIP7702(_) => alloy_consensus::constants::EIP7702_TX_TYPE_ID,
+            Self::ArbitrumDeposit(_) => ARBITRUM_DEPOSIT_TX_TYPE_ID,
+            Self::ArbitrumUnsigned(_) => ARBITRUM_UNSIGNED_TX_TYPE_ID,
+            Self::ArbitrumContract(_) => ARBITRUM_CONTRACT_TX_TYPE_ID,
+            Self::ArbitrumRetry(_) => ARBITRUM_RETRY_TX_TYPE_ID,
+            Self::ArbitrumSubmitRetryable(_) => ARBITRUM_SUBMIT_RETRYABLE_TX_TYPE_ID,
+            Self::ArbitrumInternal(_) => ARBITRUM_INTERNAL_TX_TYPE_ID,
         }
     }
 }
@@ -1088,6 +1343,12 @@ fn encode_2718_len(&self) -> usize {
             Self::EIP1559(r) => ReceiptEnvelope::Eip1559(r.clone()).encode_2718_len(),
             Self::EIP4844(r) => ReceiptEnvelope::Eip4844(r.clone()).encode_2718_len(),
             Self::EIP7702(r) => 1 + r.length(),
+            Self::ArbitrumDeposit(r) => 1 + r.length(),
+            Self::ArbitrumUnsigned(r) => 1 + r.length(),
+            Self::ArbitrumContract(r) => 1 + r.length(),
+            Self::ArbitrumRetry(r) => 1 + r.length(),
+            Self::ArbitrumSubmitRetryable(r) => 1 + r.length(),
+            Self::ArbitrumInternal(r) => 1 + r.length(),
         }
     }
 
@@ -1100,13 +1361,23 @@ fn encode_2718(&self, out: &mut dyn BufMut) {
             | Self::EIP2930(r)
             | Self::EIP1559(r)
             | Self::EIP4844(r)
-            | Self::EIP7702(r) => r.encode(out),
+            | Self::EIP7702(r)
+            | Self::ArbitrumDeposit(r)
+            | Self::ArbitrumUnsigned(r)
+            | Self::ArbitrumContract(r)
+            | Self::ArbitrumRetry(r)
+            | Self::ArbitrumSubmitRetryable(r)
+            | Self::ArbitrumInternal(r) => r.encode(out),
         }
     }
 }
 
 impl Decodable2718 for TypedReceipt {
     fn typed_decode(ty: u8, buf: &mut &[u8]) -> Result<Self, Eip2718Error> {
+        if is_arbitrum_tx_type(ty) {
+            let receipt = <ReceiptWithBloom as Decodable>::decode(buf)?;
+            return Self::arbitrum(ty, receipt).ok_or(Eip2718Error::UnexpectedType(ty));
+        }
         match ReceiptEnvelope::typed_decode(ty, buf)? {
             ReceiptEnvelope::Eip2930(tx) => Ok(Self::EIP2930(tx)),
             ReceiptEnvelope::Eip1559(tx) => Ok(Self::EIP1559(tx)),
@@ -1165,7 +1436,7 @@ pub fn convert_to_anvil_receipt(receipt: AnyTransactionReceipt) -> Option<Receip
                 0x02 => TypedReceiptRpc::EIP1559(receipt_with_bloom),
                 0x03 => TypedReceiptRpc::EIP4844(receipt_with_bloom),
                 0x04 => TypedReceiptRpc::EIP7702(receipt_with_bloom),
-                _ => return None,
+                ty => TypedReceiptRpc::arbitrum(ty, receipt_with_bloom)?,
             },
         },
         other,
@@ -1182,6 +1453,7 @@ fn from_recovered_tx(tx: &TypedTransaction, caller: Address) -> Self {
                 Self::from_recovered_tx(signed_tx.tx().tx(), caller)
             }
             TypedTransaction::EIP7702(signed_tx) => Self::from_recovered_tx(signed_tx.tx(), caller),
+            TypedTransaction::Arbitrum(tx) => Self::from_recovered_tx(tx, caller),
         }
     }
 }
@@ -1195,7 +1467,8 @@ fn from_recovered_tx(tx: &TypedTransaction, caller: Address) -> Self {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::{Log, LogData, b256, hex};
+    use alloy_primitives::{Log, LogData, address, b256, hex, keccak256};
+    use foundry_evm::core::tx::arbitrum::TxArbitrumDeposit;
     use std::str::FromStr;
 
     // <https://github.com/foundry-rs/foundry/issues/10852>
@@ -1445,4 +1718,53 @@ fn test_from_recovered_tx_legacy() {
         assert_eq!(tx_env.gas_limit, 0x5208);
         assert_eq!(tx_env.gas_price, 1);
     }
+
+    #[test]
+    fn test_arbitrum_deposit_tx() {
+        let deposit = TxArbitrumDeposit {
+            chain_id: U256::from(42161),
+            request_id: B256::repeat_byte(0x11),
+            from: address!("0x1111000000000000000000000000000000001111"),
+            to: address!("0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
+            value: U256::from(1_000_000_000_000_000_000u128),
+        };
+        let typed_tx = TypedTransaction::Arbitrum(ArbitrumTxEnvelope::Deposit(deposit.clone()));
+
+        let encoded = typed_tx.encoded_2718();
+        assert_eq!(encoded[0], 0x64);
+        assert_eq!(typed_tx.hash(), keccak256(&encoded));
+        assert_eq!(TypedTransaction::decode_2718(&mut encoded.as_slice()).unwrap(), typed_tx);
+        assert_eq!(typed_tx.recover().unwrap(), deposit.from);
+
+        let rpc_tx: AnyRpcTransaction = serde_json::from_value(serde_json::json!({
+            "type": "0x64",
+            "hash": typed_tx.hash(),
+            "chainId": "0xa4b1",
+            "requestId": deposit.request_id,
+            "from": deposit.from,
+            "to": deposit.to,
+            "value": "0xde0b6b3a7640000",
+            "nonce": "0x0",
+            "gas": "0x0",
+            "gasPrice": "0x0",
+            "input": "0x",
+            "v": "0x0",
+            "r": "0x0",
+            "s": "0x0",
+            "blockHash": null,
+            "blockNumber": null,
+            "transactionIndex": null,
+        }))
+        .unwrap();
+        assert_eq!(TypedTransaction::try_from(rpc_tx).unwrap(), typed_tx);
+
+        let receipt = TypedReceipt::arbitrum(
+            typed_tx.ty(),
+            Receipt { status: true.into(), cumulative_gas_used: 0, logs: vec![] }.into(),
+        )
+        .unwrap();
+        let encoded = receipt.encoded_2718();
+        assert_eq!(encoded[0], 0x64);
+        assert_eq!(TypedReceipt::decode_2718(&mut encoded.as_slice()).unwrap(), receipt);
+    }
 }
@@ -1,6 +1,6 @@
-use alloy_primitives::Bytes;
+use alloy_primitives::{Address, B256, Bytes, U256};
 use alloy_rpc_types::TransactionRequest;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Represents the options used in `anvil_reorg`
 #[derive(Debug, Clone, Deserialize)]
@@ -18,3 +18,38 @@ pub enum TransactionData {
     JSON(TransactionRequest),
     Raw(Bytes),
 }
+
+/// Represents the L1 to L2 message submitted with `anvil_submitRetryable`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitRetryableRequest {
+    /// The L1 sender, whose aliased address sends the retry
+    pub from: Address,
+    /// The L2 target of the retry
+    pub to: Address,
+    /// The value sent with the retry
+    #[serde(default)]
+    pub value: U256,
+    /// The ETH deposited to the aliased sender, defaults to the value and the gas of the retry
+    pub deposit: Option<U256>,
+    /// The gas of the auto-redeem, which is skipped if zero
+    #[serde(default, with = "alloy_serde::quantity")]
+    pub gas: u64,
+    /// The gas price of the auto-redeem, defaults to the base fee
+    pub max_fee_per_gas: Option<U256>,
+    /// The account which can cancel the ticket and receives its value, defaults to the L1 sender
+    pub beneficiary: Option<Address>,
+    /// The calldata of the retry
+    #[serde(default)]
+    pub data: Bytes,
+}
+
+/// Represents the proof of an L2 to L1 message returned by `anvil_getL2ToL1MessageProof`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L2ToL1MessageProof {
+    /// The root of the send Merkle tree over all the messages sent so far
+    pub root: B256,
+    /// The proof of the message, to be passed to `Outbox.executeTransaction`
+    pub proof: Vec<B256>,
+}
@@ -32,10 +32,10 @@
 use anvil_server::ServerConfig;
 use eyre::{Context, Result};
 use foundry_common::{
-    ALCHEMY_FREE_TIER_CUPS, NON_ARCHIVE_NODE_WARNING, REQUEST_TIMEOUT,
+    ALCHEMY_FREE_TIER_CUPS, ContractsByArtifactBuilder, NON_ARCHIVE_NODE_WARNING, REQUEST_TIMEOUT,
     provider::{ProviderBuilder, RetryProvider},
 };
-use foundry_config::{Config, apply_stylus_config, stylus::StylusConfig};
+use foundry_config::{Config, FigmentProviders, apply_stylus_config, stylus::StylusConfig};
 use foundry_evm::{
     backend::{BlockchainDb, BlockchainDbMeta, SharedBackend},
     constants::DEFAULT_CREATE2_DEPLOYER,
@@ -65,6 +65,7 @@
 
 pub use foundry_common::version::SHORT_VERSION as VERSION_MESSAGE;
 use foundry_evm::{
+    stylus::stylus_contracts,
     traces::{CallTraceDecoderBuilder, identifier::SignaturesIdentifier},
     utils::get_blob_params,
 };
@@ -1139,6 +1140,16 @@ pub(crate) async fn setup(&mut self) -> Result<mem::Backend> {
                 debug!(target: "node", "using signature identifier");
                 decoder_builder = decoder_builder.with_signature_identifier(identifier);
             }
+            // Stylus programs are not verified anywhere, so decode calls to the programs of the
+            // project anvil is started in with their ABIs
+            if let Ok(config) = Config::load_with_providers(FigmentProviders::Anvil)
+                && let Ok(contracts) = stylus_contracts(&config)
+                && !contracts.is_empty()
+            {
+                debug!(target: "node", count = contracts.len(), "using Stylus program ABIs");
+                let contracts = ContractsByArtifactBuilder::new(contracts).build();
+                decoder_builder = decoder_builder.with_known_contracts(&contracts);
+            }
         }
 
         // only memory based backend for now
@@ -77,11 +77,19 @@
         },
         wallet::WalletCapabilities,
     },
-    types::{ReorgOptions, TransactionData},
+    types::{L2ToL1MessageProof, ReorgOptions, SubmitRetryableRequest, TransactionData},
 };
 use anvil_rpc::{error::RpcError, response::ResponseResult};
 use foundry_common::provider::ProviderBuilder;
-use foundry_evm::decode::RevertDecoder;
+use foundry_evm::{
+    core::{
+        node_interface::{NodeInterfaceCall, NodeInterfaceMessage},
+        outbox::L2ToL1Message,
+        tx::arbitrum::{ArbitrumTxEnvelope, TxArbitrumSubmitRetryable},
+    },
+    decode::RevertDecoder,
+};
+use foundry_evm_networks::arbitrum::{NODE_INTERFACE, apply_l1_to_l2_alias};
 use futures::{
     StreamExt, TryFutureExt,
     channel::{mpsc::Receiver, oneshot},
@@ -515,6 +523,15 @@ pub async fn execute(&self, request: EthRequest) -> ResponseResult {
                 self.anvil_reorg(reorg_options).await.to_rpc_result()
             }
             EthRequest::Rollback(depth) => self.anvil_rollback(depth).await.to_rpc_result(),
+            EthRequest::SubmitRetryable(request) => {
+                self.anvil_submit_retryable(request).await.to_rpc_result()
+            }
+            EthRequest::GetL2ToL1Messages(()) => {
+                self.anvil_get_l2_to_l1_messages().await.to_rpc_result()
+            }
+            EthRequest::GetL2ToL1MessageProof(index) => {
+                self.anvil_get_l2_to_l1_message_proof(index).await.to_rpc_result()
+            }
             EthRequest::WalletGetCapabilities(()) => self.get_capabilities().to_rpc_result(),
             EthRequest::AnvilAddCapability(addr) => self.anvil_add_capability(addr).to_rpc_result(),
             EthRequest::AnvilSetExecutor(executor_pk) => {
@@ -864,7 +881,7 @@ pub async fn block_by_number(&self, number: BlockNumber) -> Result<Option<AnyRpc
     pub async fn block_by_number_full(&self, number: BlockNumber) -> Result<Option<AnyRpcBlock>> {
         node_info!("eth_getBlockByNumber");
         if number == BlockNumber::Pending {
-            return Ok(self.pending_block_full().await);
+            return self.pending_block_full().await;
         }
         self.backend.block_by_number_full(number).await
     }
@@ -1168,6 +1185,14 @@ pub async fn send_raw_transaction(&self, tx: Bytes) -> Result<TxHash> {
 
         self.ensure_typed_transaction_supported(&transaction)?;
 
+        // Arbitrum transactions carry their sender instead of a signature, so anyone could send
+        // them on behalf of any account
+        if let TypedTransaction::Arbitrum(tx) = &transaction
+            && !self.is_impersonated(tx.sender())
+        {
+            return Err(BlockchainError::UnsignedArbitrumTransaction(tx.sender()));
+        }
+
         let pending_transaction = PendingTransaction::new(transaction)?;
 
         // pre-validate
@@ -1176,15 +1201,16 @@ pub async fn send_raw_transaction(&self, tx: Bytes) -> Result<TxHash> {
         let on_chain_nonce = self.backend.current_nonce(*pending_transaction.sender()).await?;
         let from = *pending_transaction.sender();
         let nonce = pending_transaction.transaction.nonce();
-        let requires = required_marker(nonce, on_chain_nonce, from);
+        let (requires, provides) = if pending_transaction.transaction.skips_nonce_check() {
+            // transactions which don't use the sender's nonce are only identified by their hash
+            (Vec::new(), vec![pending_transaction.hash().to_vec()])
+        } else {
+            (required_marker(nonce, on_chain_nonce, from), vec![to_marker(nonce, from)])
+        };
 
         let priority = self.transaction_priority(&pending_transaction.transaction);
-        let pool_transaction = PoolTransaction {
-            requires,
-            provides: vec![to_marker(nonce, *pending_transaction.sender())],
-            pending_transaction,
-            priority,
-        };
+        let pool_transaction =
+            PoolTransaction { requires, provides, pending_transaction, priority };
 
         let tx = self.pool.add_transaction(pool_transaction)?;
         trace!(target: "node", "Added transaction: [{:?}] sender={:?}", tx.hash(), from);
@@ -1227,6 +1253,15 @@ pub async fn call(
             return Ok(fork.call(&request, Some(number.into())).await?);
         }
 
+        // `NodeInterface` is answered by the node instead of being executed
+        if request.to == Some(TxKind::Call(NODE_INTERFACE)) {
+            return self
+                .on_blocking_task(|this| async move {
+                    this.call_node_interface(request, block_request, overrides).await
+                })
+                .await;
+        }
+
         let fees = FeeDetails::new(
             request.gas_price,
             request.max_fee_per_gas,
@@ -1400,23 +1435,27 @@ pub fn anvil_get_genesis_time(&self) -> Result<u64> {
     /// Handler for ETH RPC call: `eth_getTransactionByHash`
     pub async fn transaction_by_hash(&self, hash: B256) -> Result<Option<AnyRpcTransaction>> {
         node_info!("eth_getTransactionByHash");
-        let mut tx = self.pool.get_transaction(hash).map(|pending| {
-            let from = *pending.sender();
-            let tx = transaction_build(
-                Some(*pending.hash()),
-                pending.transaction,
-                None,
-                None,
-                Some(self.backend.base_fee()),
-            );
+        let mut tx = self
+            .pool
+            .get_transaction(hash)
+            .map(|pending| -> Result<_> {
+                let from = *pending.sender();
+                let tx = transaction_build(
+                    Some(*pending.hash()),
+                    pending.transaction,
+                    None,
+                    None,
+                    Some(self.backend.base_fee()),
+                )?;
 
-            let WithOtherFields { inner: mut tx, other } = tx.0;
-            // we set the from field here explicitly to the set sender of the pending transaction,
-            // in case the transaction is impersonated.
-            tx.inner = Recovered::new_unchecked(tx.inner.into_inner(), from);
+                let WithOtherFields { inner: mut tx, other } = tx.0;
+                // we set the from field here explicitly to the set sender of the pending
+                // transaction, in case the transaction is impersonated.
+                tx.inner = Recovered::new_unchecked(tx.inner.into_inner(), from);
 
-            AnyRpcTransaction(WithOtherFields { inner: tx, other })
-        });
+                Ok(AnyRpcTransaction(WithOtherFields { inner: tx, other }))
+            })
+            .transpose()?;
         if tx.is_none() {
             tx = self.backend.transaction_by_hash(hash).await?
         }
@@ -1472,7 +1511,7 @@ pub async fn transaction_by_sender_and_nonce(
                     None,
                     None,
                     Some(self.backend.base_fee()),
-                );
+                )?;
 
                 let WithOtherFields { inner: mut tx, other } = tx.0;
                 // we set the from field here explicitly to the set sender of the pending
@@ -1518,7 +1557,7 @@ pub async fn transaction_by_sender_and_nonce(
         let target_block = low;
         if target_block <= latest_block
             && let Some(txs) =
-                self.backend.mined_transactions_by_block_number(target_block.into()).await
+                self.backend.mined_transactions_by_block_number(target_block.into()).await?
         {
             for tx in txs {
                 if tx.from() == sender
@@ -2622,6 +2661,96 @@ pub async fn anvil_rollback(&self, depth: Option<u64>) -> Result<()> {
         Ok(())
     }
 
+    /// Submits an Arbitrum L1 to L2 retryable ticket from an L1 sender.
+    ///
+    /// The ticket is created for the aliased sender once the submission is mined, and its
+    /// auto-redeem is executed right after it, in the same block. If the auto-redeem fails, the
+    /// ticket can be redeemed through `ArbRetryableTx` until it expires.
+    ///
+    /// Returns the ticket id, which is the hash of the submission.
+    ///
+    /// Handler for RPC call: `anvil_submitRetryable`
+    pub async fn anvil_submit_retryable(&self, request: SubmitRetryableRequest) -> Result<TxHash> {
+        node_info!("anvil_submitRetryable");
+        let SubmitRetryableRequest {
+            from,
+            to,
+            value,
+            deposit,
+            gas,
+            max_fee_per_gas,
+            beneficiary,
+            data,
+        } = request;
+
+        let gas_fee_cap = max_fee_per_gas.unwrap_or_else(|| U256::from(self.backend.base_fee()));
+        let deposit = deposit.unwrap_or_else(|| value + U256::from(gas) * gas_fee_cap);
+        if deposit < value {
+            return Err(BlockchainError::RpcError(RpcError::invalid_params(
+                "deposit must cover the L2 call value",
+            )));
+        }
+        let beneficiary = beneficiary.unwrap_or(from);
+
+        let tx = TxArbitrumSubmitRetryable {
+            chain_id: self.backend.chain_id(),
+            request_id: self.backend.retryables().write().next_request_id(),
+            from: apply_l1_to_l2_alias(from),
+            l1_base_fee: U256::ZERO,
+            deposit_value: deposit,
+            gas_fee_cap,
+            gas,
+            retry_to: TxKind::Call(to),
+            retry_value: value,
+            beneficiary,
+            max_submission_fee: U256::ZERO,
+            fee_refund_addr: beneficiary,
+            retry_data: data,
+        };
+        let transaction = TypedTransaction::Arbitrum(ArbitrumTxEnvelope::SubmitRetryable(tx));
+        let pending_transaction = PendingTransaction::new(transaction)?;
+        self.backend.validate_pool_transaction(&pending_transaction).await?;
+
+        let priority = self.transaction_priority(&pending_transaction.transaction);
+        let pool_transaction = PoolTransaction {
+            requires: Vec::new(),
+            provides: vec![pending_transaction.hash().to_vec()],
+            pending_transaction,
+            priority,
+        };
+
+        let tx = self.pool.add_transaction(pool_transaction)?;
+        trace!(target: "node", "Added retryable submission: [{:?}] sender={:?}", tx.hash(), from);
+        Ok(*tx.hash())
+    }
+
+    /// Returns the Arbitrum L2 to L1 messages sent through `ArbSys` in mined blocks, in order.
+    ///
+    /// Handler for RPC call: `anvil_getL2ToL1Messages`
+    pub async fn anvil_get_l2_to_l1_messages(&self) -> Result<Vec<L2ToL1Message>> {
+        node_info!("anvil_getL2ToL1Messages");
+        Ok(self.backend.l2_to_l1_messages().await?)
+    }
+
+    /// Returns the root of the send Merkle tree, and the proof of the L2 to L1 message at `index`
+    /// in `anvil_getL2ToL1Messages` against it. The path of the proof is the position of the
+    /// message.
+    ///
+    /// Handler for RPC call: `anvil_getL2ToL1MessageProof`
+    pub async fn anvil_get_l2_to_l1_message_proof(&self, index: u64) -> Result<L2ToL1MessageProof> {
+        node_info!("anvil_getL2ToL1MessageProof");
+        let proof = match usize::try_from(index) {
+            Ok(index) => self.backend.l2_to_l1_message_proof(index).await?,
+            Err(_) => None,
+        };
+        let (root, proof) = proof.ok_or_else(|| {
+            BlockchainError::RpcError(RpcError::invalid_params(format!(
+                "no L2 to L1 message at index {index}"
+            )))
+        })?;
+        Ok(L2ToL1MessageProof { root, proof })
+    }
+
     /// Snapshot the state of the blockchain at the current block.
     ///
     /// Handler for RPC call: `evm_snapshot`
@@ -2908,7 +3037,7 @@ fn convert(tx: Arc<PoolTransaction>) -> Result<AnyRpcTransaction> {
                 None,
                 None,
                 None,
-            );
+            )?;
 
             let WithOtherFields { inner: mut tx, other } = tx.0;
 
@@ -3052,13 +3181,93 @@ async fn do_estimate_gas(
                     if let Some(block_overrides) = overrides.block {
                         cache_db.apply_block_overrides(*block_overrides, &mut block);
                     }
-                    this.do_estimate_gas_with_state(request, &cache_db, block)
+                    // The gas of `estimateRetryableTicket` is the gas of the retry
+                    if let Some(NodeInterfaceCall::EstimateRetryableTicket { message, deposit }) =
+                        this.backend.node_interface_call_with_state(&cache_db, &request, &block)?
+                    {
+                        return this
+                            .node_interface_message_gas(&mut cache_db, &message, deposit, block)
+                            .map(u128::from);
+                    }
+                    // Nitro adds the L2 gas paying for the L1 calldata to the estimate
+                    let l1_gas =
+                        this.backend.l1_gas_estimate_with_state(&cache_db, &request, &block)?;
+                    let gas = this.do_estimate_gas_with_state(request, &cache_db, block)?;
+                    Ok(gas.saturating_add(l1_gas.into()))
                 })
                 .await?
         })
         .await
     }
 
+    /// Answers a call to `NodeInterface` like a Nitro node, using the ArbOS pricing state.
+    ///
+    /// Calls to unsupported methods return nothing, like calls to an empty account.
+    async fn call_node_interface(
+        &self,
+        request: WithOtherFields<TransactionRequest>,
+        block_request: BlockRequest,
+        overrides: EvmOverrides,
+    ) -> Result<Bytes> {
+        self.backend
+            .with_database_at(Some(block_request), |state, mut block| {
+                let mut cache_db = CacheDB::new(state);
+                if let Some(state_overrides) = overrides.state {
+                    apply_state_overrides(state_overrides.into_iter().collect(), &mut cache_db)?;
+                }
+                if let Some(block_overrides) = overrides.block {
+                    cache_db.apply_block_overrides(*block_overrides, &mut block);
+                }
+
+                let Some(call) =
+                    self.backend.node_interface_call_with_state(&cache_db, &request, &block)?
+                else {
+                    return Ok(Bytes::new());
+                };
+                let output = match call {
+                    NodeInterfaceCall::Return(output) => output,
+                    NodeInterfaceCall::GasEstimateComponents(components) => {
+                        let gas = self.node_interface_message_gas(
+                            &mut cache_db,
+                            &components.message,
+                            U256::ZERO,
+                            block,
+                        )?;
+                        components.encode_returns(gas)
+                    }
+                    NodeInterfaceCall::EstimateRetryableTicket { message, deposit } => {
+                        self.node_interface_message_gas(&mut cache_db, &message, deposit, block)?;
+                        Bytes::new()
+                    }
+                };
+                Ok(output)
+            })
+            .await?
+    }
+
+    /// Estimates the gas of a message needed to answer a `NodeInterface` call, after minting
+    /// `deposit` to its sender.
+    fn node_interface_message_gas<DB: DatabaseRef>(
+        &self,
+        cache_db: &mut CacheDB<DB>,
+        message: &NodeInterfaceMessage,
+        deposit: U256,
+        block_env: BlockEnv,
+    ) -> Result<u64> {
+        let sender = cache_db.load_account(message.from)?;
+        sender.info.balance = sender.info.balance.saturating_add(deposit);
+
+        let request = WithOtherFields::new(TransactionRequest {
+            from: Some(message.from),
+            to: Some(message.to),
+            value: Some(message.value),
+            input: message.data.clone().into(),
+            ..Default::default()
+        });
+        let gas = self.do_estimate_gas_with_state(request, &*cache_db, block_env)?;
+        Ok(gas.try_into().unwrap_or(u64::MAX))
+    }
+
     /// Estimates the gas usage of the `request` with the state.
     ///
     /// This will execute the transaction request and find the best gas limit via binary search.
@@ -3281,7 +3490,7 @@ async fn pending_block(&self) -> AnyRpcBlock {
     }
 
     /// Returns the full pending block with `Transaction` objects
-    async fn pending_block_full(&self) -> Option<AnyRpcBlock> {
+    async fn pending_block_full(&self) -> Result<Option<AnyRpcBlock>> {
         let transactions = self.pool.ready_transactions().collect::<Vec<_>>();
         let BlockInfo { block, transactions, receipts: _ } =
             self.backend.pending_block(transactions).await;
@@ -3292,7 +3501,10 @@ async fn pending_block_full(&self) -> Option<AnyRpcBlock> {
         let base_fee = self.backend.base_fee();
 
         for info in transactions {
-            let tx = block.body.transactions.get(info.transaction_index as usize)?.clone();
+            let Some(tx) = block.body.transactions.get(info.transaction_index as usize).cloned()
+            else {
+                return Ok(None);
+            };
 
             let tx = transaction_build(
                 Some(info.transaction_hash),
@@ -3300,13 +3512,13 @@ async fn pending_block_full(&self) -> Option<AnyRpcBlock> {
                 Some(&block),
                 Some(info),
                 Some(base_fee),
-            );
+            )?;
             block_transactions.push(tx);
         }
 
         partial_block.transactions = BlockTransactions::from(block_transactions);
 
-        Some(partial_block)
+        Ok(Some(partial_block))
     }
 
     fn build_typed_tx_request(
@@ -3492,7 +3704,7 @@ fn ensure_typed_transaction_supported(&self, tx: &TypedTransaction) -> Result<()
             TypedTransaction::EIP1559(_) => self.backend.ensure_eip1559_active(),
             TypedTransaction::EIP4844(_) => self.backend.ensure_eip4844_active(),
             TypedTransaction::EIP7702(_) => self.backend.ensure_eip7702_active(),
-            TypedTransaction::Legacy(_) => Ok(()),
+            TypedTransaction::Legacy(_) | TypedTransaction::Arbitrum(_) => Ok(()),
         }
     }
 }
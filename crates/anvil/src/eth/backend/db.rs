@@ -14,8 +14,12 @@
     transaction::{MaybeImpersonatedTransaction, TransactionInfo, TypedReceipt, TypedTransaction},
 };
 use foundry_common::errors::FsPathError;
-use foundry_evm::backend::{
-    BlockchainDb, DatabaseError, DatabaseResult, MemDb, RevertStateSnapshotAction, StateSnapshot,
+use foundry_evm::{
+    backend::{
+        BlockchainDb, DatabaseError, DatabaseResult, MemDb, RevertStateSnapshotAction,
+        StateSnapshot,
+    },
+    core::arbos::{ARBOS_STATE_ADDRESS, ArbosStorage},
 };
 use revm::{
     Database, DatabaseCommit,
@@ -194,6 +198,21 @@ fn maybe_state_root(&self) -> Option<B256> {
     fn current_state(&self) -> StateDb;
 }
 
+/// [`ArbosStorage`] in a [`Db`], for the ArbOS state changes applied outside of the EVM.
+pub(crate) struct DbArbosStorage<'a, DB: ?Sized>(pub(crate) &'a mut DB);
+
+impl<DB: Db + ?Sized> ArbosStorage for DbArbosStorage<'_, DB> {
+    type Error = DatabaseError;
+
+    fn sload(&mut self, slot: U256) -> Result<U256, DatabaseError> {
+        self.0.storage(ARBOS_STATE_ADDRESS, slot)
+    }
+
+    fn sstore(&mut self, slot: U256, value: U256) -> Result<(), DatabaseError> {
+        self.0.set_storage_at(ARBOS_STATE_ADDRESS, slot.into(), value.into())
+    }
+}
+
 /// Convenience impl only used to use any `Db` on the fly as the db layer for revm's CacheDB
 /// This is useful to create blocks without actually writing to the `Db`, but rather in the cache of
 /// the `CacheDB` see also
@@ -500,6 +519,9 @@ pub struct SerializableState {
     /// Note: This is an Option for backwards compatibility.
     #[serde(default)]
     pub historical_states: Option<SerializableHistoricalStates>,
+    /// The L1 block numbers of the blocks of an Arbitrum chain, by block hash.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub l1_block_numbers: BTreeMap<B256, u64>,
 }
 
 impl SerializableState {
@@ -3,25 +3,26 @@
     eth::{
         backend::{
             cheats::{CheatEcrecover, CheatsManager},
-            db::Db,
+            db::{Db, DbArbosStorage},
             env::Env,
             validate::TransactionValidator,
         },
         error::InvalidTransactionError,
         pool::transactions::PoolTransaction,
     },
-    mem::inspector::AnvilInspector,
+    mem::{inspector::AnvilInspector, is_arbitrum},
 };
 use alloy_consensus::{
     Header, Receipt, ReceiptWithBloom, constants::EMPTY_WITHDRAWALS,
     proofs::calculate_receipt_root, transaction::Either,
 };
 use alloy_eips::{
+    Encodable2718,
     eip7685::EMPTY_REQUESTS_HASH,
     eip7702::{RecoveredAuthority, RecoveredAuthorization},
     eip7840::BlobParams,
 };
-use alloy_primitives::{B256, Bloom, BloomInput, Log};
+use alloy_primitives::{Address, B256, Bloom, BloomInput, Log, U256};
 use anvil_core::eth::{
     block::{BlockInfo, create_block},
     transaction::{PendingTransaction, TransactionInfo, TypedReceipt, TypedTransaction},
@@ -32,22 +33,27 @@
     backend::DatabaseError,
     core::{
         FoundryCfgEnv, FoundryLocalContext,
+        arbos::{ARBOS_STATE_ADDRESS, ArbosParam},
         either_evm::EitherEvm,
+        node_interface::{ArbosPricing, L1_PRICER_FUNDS_POOL_ADDRESS},
+        outbox::append_l2_to_l1_message,
         precompiles::{DynPrecompile, EC_RECOVER, FoundryPrecompiles, Precompile},
+        retryables::{RetryableContext, RetryableState, retryable_escrow_address},
+        tx::arbitrum::{ArbitrumTxEnvelope, TxArbitrumRetry, TxArbitrumSubmitRetryable},
     },
     traces::{CallTraceDecoder, CallTraceNode},
 };
 use foundry_evm_networks::NetworkConfigs;
 use revm::{
     Database, DatabaseRef, InspectCommitEvm, Inspector, Journal,
-    context::{Block as RevmBlock, BlockEnv, Cfg, JournalTr, TxEnv},
+    context::{Block as RevmBlock, BlockEnv, Cfg, JournalTr, Transaction as _, TxEnv},
     context_interface::result::{EVMError, ExecutionResult, Output},
     database::WrapDatabaseRef,
     handler::instructions::EthInstructions,
     interpreter::InstructionResult,
     primitives::hardfork::SpecId,
 };
-use std::{fmt::Debug, sync::Arc};
+use std::{collections::VecDeque, fmt::Debug, sync::Arc};
 
 /// Represents an executed transaction (transacted on the DB)
 #[derive(Debug)]
@@ -56,6 +62,7 @@ pub struct ExecutedTransaction {
     exit_reason: InstructionResult,
     out: Option<Output>,
     gas_used: u64,
+    gas_used_for_l1: u64,
     logs: Vec<Log>,
     traces: Vec<CallTraceNode>,
     nonce: u64,
@@ -84,6 +91,7 @@ fn create_receipt(&self, cumulative_gas_used: &mut u64) -> TypedReceipt {
             TypedTransaction::EIP1559(_) => TypedReceipt::EIP1559(receipt_with_bloom),
             TypedTransaction::EIP4844(_) => TypedReceipt::EIP4844(receipt_with_bloom),
             TypedTransaction::EIP7702(_) => TypedReceipt::EIP7702(receipt_with_bloom),
+            TypedTransaction::Arbitrum(tx) => TypedReceipt::for_arbitrum_tx(tx, receipt_with_bloom),
         }
     }
 }
@@ -98,6 +106,8 @@ pub struct ExecutedTransactions {
     /// All transactions that were invalid at the point of their execution and were not included in
     /// the block
     pub invalid: Vec<Arc<PoolTransaction>>,
+    /// The L1 block number of the block, on Arbitrum chains
+    pub l1_block_number: Option<u64>,
 }
 
 /// An executor for a series of transactions
@@ -126,6 +136,14 @@ pub struct TransactionExecutor<'a, Db: ?Sized, V: TransactionValidator> {
     pub precompile_factory: Option<Arc<dyn PrecompileFactory>>,
     pub blob_params: BlobParams,
     pub cheats: CheatsManager,
+    /// Retryable processing state, used to schedule the redeems of submitted retryables.
+    ///
+    /// If not set, redeems are not scheduled and the retry transactions are expected to be part
+    /// of `pending`, e.g. when replaying a mined block.
+    pub retryables: Option<RetryableContext>,
+    /// Retry transactions scheduled by the executed transactions, executed before the next
+    /// `pending` transaction.
+    pub scheduled: VecDeque<Arc<PoolTransaction>>,
 }
 
 impl<DB: Db + ?Sized, V: TransactionValidator> TransactionExecutor<'_, DB, V> {
@@ -157,6 +175,12 @@ pub fn execute(mut self) -> ExecutedTransactions {
         let excess_blob_gas = if is_cancun { self.block_env.blob_excess_gas() } else { None };
         let mut cumulative_blob_gas_used = if is_cancun { Some(0u64) } else { None };
 
+        let l1_block_number = is_arbitrum(self.cfg_env.chain_id).then(|| {
+            let (_, l1_block_number) = self.l1_pricing();
+            // an unset L1 block number falls back to the L2 one
+            if l1_block_number == 0 { block_number.saturating_to() } else { l1_block_number }
+        });
+
         for tx in self.into_iter() {
             let tx = match tx {
                 TransactionExecutionOutcome::Executed(tx) => {
@@ -224,6 +248,7 @@ pub fn execute(mut self) -> ExecutedTransactions {
                 out: out.map(Output::into_data),
                 nonce: tx.nonce,
                 gas_used: tx.gas_used,
+                gas_used_for_l1: tx.gas_used_for_l1,
             };
 
             transaction_infos.push(info);
@@ -259,7 +284,136 @@ pub fn execute(mut self) -> ExecutedTransactions {
 
         let block = create_block(header, transactions);
         let block = BlockInfo { block, transactions: transaction_infos, receipts };
-        ExecutedTransactions { block, included, invalid }
+        ExecutedTransactions { block, included, invalid, l1_block_number }
+    }
+
+    /// Returns the ArbOS L1 pricing state and the L1 block number stored in the ArbOS state.
+    ///
+    /// Both are unset if the ArbOS state cannot be read, e.g. before it is initialized.
+    fn l1_pricing(&self) -> (ArbosPricing, u64) {
+        let sload = |slot| self.db.storage_ref(ARBOS_STATE_ADDRESS, slot);
+        let pricing = ArbosPricing::load(sload).unwrap_or_default();
+        let l1_block_number = ArbosParam::L1BlockNumber
+            .storage_slot()
+            .and_then(|slot| sload(slot).ok())
+            .map_or(0, |number| number.saturating_to());
+        (pricing, l1_block_number)
+    }
+
+    /// Returns the L2 gas charged for posting `tx` to L1, as reported in the `gasUsedForL1` field
+    /// of its receipt.
+    fn gas_used_for_l1(&self, tx: &TypedTransaction) -> u64 {
+        // only transactions posted by the sequencer pay for L1 calldata
+        if !is_arbitrum(self.cfg_env.chain_id) || tx.is_arbitrum() {
+            return 0;
+        }
+        let (pricing, _) = self.l1_pricing();
+        pricing.poster_gas(&tx.encoded_2718(), U256::from(self.block_env.basefee))
+    }
+
+    /// Creates the ticket of a retryable submission, and schedules its auto-redeem.
+    ///
+    /// The deposit has already been minted to the sender, and ArbOS moves the call value to the
+    /// escrow of the ticket.
+    fn submit_retryable(
+        &mut self,
+        transaction: Arc<PoolTransaction>,
+        tx: &TxArbitrumSubmitRetryable,
+        nonce: u64,
+    ) -> TransactionExecutionOutcome {
+        let ticket_id = ArbitrumTxEnvelope::SubmitRetryable(tx.clone()).hash();
+        if let Err(err) = move_balance(
+            &mut *self.db,
+            tx.from,
+            retryable_escrow_address(ticket_id),
+            tx.retry_value,
+        ) {
+            return TransactionExecutionOutcome::DatabaseError(transaction, err);
+        }
+
+        let (logs, auto_redeem) = match self.create_ticket(tx) {
+            Ok(created) => created,
+            Err(err) => return TransactionExecutionOutcome::DatabaseError(transaction, err),
+        };
+        if let Some(retry) = auto_redeem
+            && self.retryables.is_some()
+        {
+            self.schedule_retry(retry);
+        }
+
+        trace!(target: "backend", "[{:?}] submitted retryable ticket {ticket_id:?}", transaction.hash());
+        TransactionExecutionOutcome::Executed(ExecutedTransaction {
+            transaction,
+            exit_reason: InstructionResult::Stop,
+            out: None,
+            gas_used: 0,
+            gas_used_for_l1: 0,
+            logs,
+            traces: Vec::new(),
+            nonce,
+        })
+    }
+
+    /// Creates the ticket of a retryable submission in the ArbOS state, and returns the logs of
+    /// the submission together with its auto-redeem, if any.
+    fn create_ticket(
+        &mut self,
+        tx: &TxArbitrumSubmitRetryable,
+    ) -> Result<(Vec<Log>, Option<TxArbitrumRetry>), DatabaseError> {
+        let timestamp = self.block_env.timestamp.saturating_to();
+        let mut retryables = RetryableState::new(DbArbosStorage(&mut *self.db));
+        let (ticket, log) = retryables.create(tx, timestamp)?;
+        let mut logs = vec![log];
+        if tx.gas == 0 {
+            return Ok((logs, None));
+        }
+        let (redeem, log) =
+            retryables.schedule_redeem(&ticket, tx.chain_id, tx.gas_fee_cap, tx.gas, tx.from)?;
+        logs.push(log);
+        Ok((logs, Some(redeem.retry)))
+    }
+
+    /// Schedules a retry transaction right after the one being executed.
+    fn schedule_retry(&mut self, retry: TxArbitrumRetry) {
+        let transaction = TypedTransaction::Arbitrum(ArbitrumTxEnvelope::Retry(retry));
+        let pending = match PendingTransaction::new(transaction) {
+            Ok(pending) => pending,
+            Err(err) => {
+                warn!(target: "backend", "failed to schedule retry transaction: {err}");
+                return;
+            }
+        };
+        self.scheduled.push_back(Arc::new(PoolTransaction::new(pending)));
+    }
+
+    /// Records the outcome of a retry transaction, if `transaction` is one.
+    ///
+    /// A successful retry deletes the ticket, and a failed one puts the call value back in
+    /// escrow and leaves the ticket redeemable.
+    fn end_retry(
+        &mut self,
+        transaction: &PoolTransaction,
+        success: bool,
+    ) -> Result<(), DatabaseError> {
+        let TypedTransaction::Arbitrum(ArbitrumTxEnvelope::Retry(retry)) =
+            &transaction.pending_transaction.transaction.transaction
+        else {
+            return Ok(());
+        };
+        if success {
+            RetryableState::new(DbArbosStorage(&mut *self.db)).delete(retry.ticket_id)?;
+        } else {
+            move_balance(
+                &mut *self.db,
+                retry.from,
+                retryable_escrow_address(retry.ticket_id),
+                retry.value,
+            )?;
+        }
+        if let Some(retryables) = &mut self.retryables {
+            retryables.end_redeem();
+        }
+        Ok(())
     }
 
     fn env_for(&self, tx: &PendingTransaction) -> Env {
@@ -295,7 +449,19 @@ fn env_for(&self, tx: &PendingTransaction) -> Env {
             tx_env.authorization_list = cheated_auths;
         }
 
-        Env::new(self.cfg_env.clone(), self.block_env.clone(), tx_env.into(), self.networks)
+        let mut cfg_env = self.cfg_env.clone();
+        if let TypedTransaction::Arbitrum(tx) = &tx.transaction.transaction {
+            if tx.skip_account_checks() {
+                cfg_env.disable_nonce_check = true;
+            }
+            // the gas of a retry was paid by the submission or the redeem which scheduled it
+            if let ArbitrumTxEnvelope::Retry(_) = tx {
+                tx_env.gas_price = 0;
+                cfg_env.disable_base_fee = true;
+            }
+        }
+
+        Env::new(cfg_env, self.block_env.clone(), tx_env.into(), self.networks)
     }
 }
 
@@ -320,13 +486,16 @@ impl<DB: Db + ?Sized, V: TransactionValidator> Iterator for &mut TransactionExec
     type Item = TransactionExecutionOutcome;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let transaction = self.pending.next()?;
+        let transaction = match self.scheduled.pop_front() {
+            Some(transaction) => transaction,
+            None => self.pending.next()?,
+        };
         let sender = *transaction.pending_transaction.sender();
         let account = match self.db.basic(sender).map(|acc| acc.unwrap_or_default()) {
             Ok(account) => account,
             Err(err) => return Some(TransactionExecutionOutcome::DatabaseError(transaction, err)),
         };
-        let env = self.env_for(&transaction.pending_transaction);
+        let mut env = self.env_for(&transaction.pending_transaction);
 
         // check that we comply with the block's gas limit, if not disabled
         let max_block_gas = self.gas_used.saturating_add(env.tx.gas_limit);
@@ -364,6 +533,86 @@ fn next(&mut self) -> Option<Self::Item> {
 
         let nonce = account.nonce;
 
+        if let TypedTransaction::Arbitrum(tx) =
+            &transaction.pending_transaction.transaction.transaction
+        {
+            // ArbOS mints deposits before the transaction executes
+            if let Some((to, amount)) = tx.mint() {
+                let balance = match self.db.basic(to) {
+                    Ok(account) => account.unwrap_or_default().balance,
+                    Err(err) => {
+                        return Some(TransactionExecutionOutcome::DatabaseError(transaction, err));
+                    }
+                };
+                if let Err(err) = self.db.set_balance(to, balance.saturating_add(amount)) {
+                    return Some(TransactionExecutionOutcome::DatabaseError(transaction, err));
+                }
+            }
+
+            // retryable submissions create a ticket and schedule its auto-redeem
+            if let ArbitrumTxEnvelope::SubmitRetryable(submit) = tx {
+                let submit = submit.clone();
+                return Some(self.submit_retryable(transaction, &submit, nonce));
+            }
+
+            // a retry takes the call value of the ticket out of its escrow
+            if let ArbitrumTxEnvelope::Retry(retry) = tx {
+                if let Err(err) = move_balance(
+                    &mut *self.db,
+                    retryable_escrow_address(retry.ticket_id),
+                    retry.from,
+                    retry.value,
+                ) {
+                    return Some(TransactionExecutionOutcome::DatabaseError(transaction, err));
+                }
+                if let Some(retryables) = &mut self.retryables {
+                    retryables.start_redeem(retry);
+                }
+            }
+
+            // deposits and internal transactions are applied by ArbOS without executing any code
+            if tx.is_system() {
+                trace!(target: "backend", "[{:?}] applied Arbitrum system transaction", transaction.hash());
+                return Some(TransactionExecutionOutcome::Executed(ExecutedTransaction {
+                    transaction,
+                    exit_reason: InstructionResult::Stop,
+                    out: None,
+                    gas_used: 0,
+                    gas_used_for_l1: 0,
+                    logs: Vec::new(),
+                    traces: Vec::new(),
+                    nonce,
+                }));
+            }
+        }
+
+        // the L1 cost of the transaction is charged as gas before it executes, like ArbOS does
+        let gas_used_for_l1 =
+            self.gas_used_for_l1(&transaction.pending_transaction.transaction.transaction);
+        if gas_used_for_l1 > env.tx.gas_limit {
+            return Some(TransactionExecutionOutcome::Invalid(
+                transaction,
+                InvalidTransactionError::GasTooLow,
+            ));
+        }
+        env.tx.gas_limit -= gas_used_for_l1;
+        let gas_price = env.tx.effective_gas_price(u128::from(env.evm_env.block_env.basefee));
+
+        // the L1 cost is bought upfront together with the rest of the gas, so the transaction
+        // cannot spend the balance paying for it
+        let sender = *transaction.pending_transaction.sender();
+        let l1_fee = U256::from(gas_used_for_l1) * U256::from(gas_price);
+        if l1_fee > account.balance {
+            return Some(TransactionExecutionOutcome::Invalid(
+                transaction,
+                InvalidTransactionError::InsufficientFunds,
+            ));
+        }
+        if let Err(err) = move_balance(&mut *self.db, sender, L1_PRICER_FUNDS_POOL_ADDRESS, l1_fee)
+        {
+            return Some(TransactionExecutionOutcome::DatabaseError(transaction, err));
+        }
+
         let mut inspector = AnvilInspector::default().with_tracing();
         if self.enable_steps_tracing {
             inspector = inspector.with_steps_tracing();
@@ -374,6 +623,9 @@ fn next(&mut self) -> Option<Self::Item> {
         if self.print_traces {
             inspector = inspector.with_trace_printer();
         }
+        if let Some(retryables) = &self.retryables {
+            inspector = inspector.with_retryables(retryables.clone());
+        }
 
         let exec_result = {
             let mut evm = new_evm_with_inspector(&mut *self.db, &env, &mut inspector);
@@ -397,27 +649,31 @@ fn next(&mut self) -> Option<Self::Item> {
 
             trace!(target: "backend", "[{:?}] executing", transaction.hash());
             // transact and commit the transaction (using inspect_tx_commit to invoke inspectors)
-            match evm.inspect_tx_commit(env.tx) {
-                Ok(exec_result) => exec_result,
-                Err(err) => {
-                    warn!(target: "backend", "[{:?}] failed to execute: {:?}", transaction.hash(), err);
-                    match err {
-                        EVMError::Database(err) => {
-                            return Some(TransactionExecutionOutcome::DatabaseError(
-                                transaction,
-                                err,
-                            ));
-                        }
-                        EVMError::Transaction(err) => {
-                            return Some(TransactionExecutionOutcome::Invalid(
-                                transaction,
-                                err.into(),
-                            ));
-                        }
-                        // This will correspond to prevrandao not set, and it should never happen.
-                        // If it does, it's a bug.
-                        e => panic!("failed to execute transaction: {e}"),
+            evm.inspect_tx_commit(env.tx)
+        };
+        let exec_result = match exec_result {
+            Ok(exec_result) => exec_result,
+            Err(err) => {
+                warn!(target: "backend", "[{:?}] failed to execute: {:?}", transaction.hash(), err);
+                if let Err(err) = self.end_retry(&transaction, false) {
+                    return Some(TransactionExecutionOutcome::DatabaseError(transaction, err));
+                }
+                // the transaction is not included, so the L1 cost is refunded
+                if let Err(err) =
+                    move_balance(&mut *self.db, L1_PRICER_FUNDS_POOL_ADDRESS, sender, l1_fee)
+                {
+                    return Some(TransactionExecutionOutcome::DatabaseError(transaction, err));
+                }
+                match err {
+                    EVMError::Database(err) => {
+                        return Some(TransactionExecutionOutcome::DatabaseError(transaction, err));
+                    }
+                    EVMError::Transaction(err) => {
+                        return Some(TransactionExecutionOutcome::Invalid(transaction, err.into()));
                     }
+                    // This will correspond to prevrandao not set, and it should never happen.
+                    // If it does, it's a bug.
+                    e => panic!("failed to execute transaction: {e}"),
                 }
             }
         };
@@ -437,6 +693,26 @@ fn next(&mut self) -> Option<Self::Item> {
             ExecutionResult::Halt { reason, gas_used } => (reason.into(), gas_used, None, None),
         };
 
+        let gas_used = gas_used.saturating_add(gas_used_for_l1);
+
+        let success = exit_reason.is_ok();
+        // scheduled redeems are reverted together with the transaction
+        if success {
+            for redeem in std::mem::take(&mut inspector.scheduled_redeems) {
+                self.schedule_retry(redeem.retry);
+            }
+        }
+        if let Err(err) = self.end_retry(&transaction, success) {
+            return Some(TransactionExecutionOutcome::DatabaseError(transaction, err));
+        }
+
+        // ArbOS appends the L2 to L1 messages to the send Merkle tree in its state
+        for log in logs.iter().flatten() {
+            if let Err(err) = append_l2_to_l1_message(&mut DbArbosStorage(&mut *self.db), log) {
+                return Some(TransactionExecutionOutcome::DatabaseError(transaction, err));
+            }
+        }
+
         if exit_reason == InstructionResult::OutOfGas {
             // this currently useful for debugging estimations
             warn!(target: "backend", "[{:?}] executed with out of gas", transaction.hash())
@@ -459,6 +735,7 @@ fn next(&mut self) -> Option<Self::Item> {
             exit_reason,
             out,
             gas_used,
+            gas_used_for_l1,
             logs: logs.unwrap_or_default(),
             traces: inspector.tracer.map(|t| t.into_traces().into_nodes()).unwrap_or_default(),
             nonce,
@@ -468,6 +745,23 @@ fn next(&mut self) -> Option<Self::Item> {
     }
 }
 
+/// Moves `value` from one account to another.
+fn move_balance<DB: Db + ?Sized>(
+    db: &mut DB,
+    from: Address,
+    to: Address,
+    value: U256,
+) -> Result<(), DatabaseError> {
+    if value.is_zero() || from == to {
+        return Ok(());
+    }
+    let from_balance = db.basic(from)?.unwrap_or_default().balance;
+    db.set_balance(from, from_balance.saturating_sub(value))?;
+    let to_balance = db.basic(to)?.unwrap_or_default().balance;
+    db.set_balance(to, to_balance.saturating_add(value))?;
+    Ok(())
+}
+
 /// Inserts all logs into the bloom
 fn build_logs_bloom(logs: &[Log], bloom: &mut Bloom) {
     for log in logs {
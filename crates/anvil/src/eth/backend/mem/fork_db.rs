@@ -70,6 +70,7 @@ fn dump_state(
             blocks,
             transactions,
             historical_states,
+            l1_block_numbers: Default::default(),
         }))
     }
 
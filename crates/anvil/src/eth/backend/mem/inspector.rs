@@ -1,22 +1,24 @@
 //! Anvil specific [`revm::Inspector`] implementation
 
 use crate::eth::macros::node_info;
-use alloy_primitives::{Address, Log, U256};
+use alloy_primitives::{Address, Bytes, Log, U256};
 use foundry_evm::{
     call_inspectors,
+    core::retryables::{RetryableCall, RetryableCallEnv, RetryableContext, ScheduledRedeem},
     decode::decode_console_logs,
     inspectors::{LogCollector, TracingInspector},
     traces::{
         CallTraceDecoder, SparsedTraceArena, TracingInspectorConfig, render_trace_arena_inner,
     },
 };
+use foundry_evm_networks::arbitrum::ARB_RETRYABLE_TX;
 use revm::{
     Inspector,
-    context::ContextTr,
+    context::{Block, Cfg, ContextTr},
     inspector::JournalExt,
     interpreter::{
-        CallInputs, CallOutcome, CreateInputs, CreateOutcome, Interpreter,
-        interpreter::EthInterpreter,
+        CallInputs, CallOutcome, CreateInputs, CreateOutcome, Gas, InstructionResult, Interpreter,
+        InterpreterResult, interpreter::EthInterpreter,
     },
 };
 use revm_inspectors::transfer::TransferInspector;
@@ -31,6 +33,10 @@ pub struct AnvilInspector {
     pub log_collector: Option<LogCollector>,
     /// Collects all internal ETH transfers as ERC20 transfer events.
     pub transfer: Option<TransferInspector>,
+    /// Answers calls to `ArbRetryableTx` concerning retryable tickets.
+    pub retryables: Option<RetryableContext>,
+    /// Redeems scheduled through `ArbRetryableTx.redeem`.
+    pub scheduled_redeems: Vec<ScheduledRedeem>,
 }
 
 impl AnvilInspector {
@@ -93,6 +99,57 @@ pub fn with_trace_printer(mut self) -> Self {
         self.tracer = Some(TracingInspector::new(TracingInspectorConfig::all().with_state_diffs()));
         self
     }
+
+    /// Configures the [`revm::Inspector`] to answer calls to `ArbRetryableTx` concerning retryable
+    /// tickets
+    pub fn with_retryables(mut self, retryables: RetryableContext) -> Self {
+        self.retryables = Some(retryables);
+        self
+    }
+
+    /// Handles a call to `ArbRetryableTx` concerning a retryable ticket.
+    fn call_retryable_tx<CTX>(&mut self, ecx: &mut CTX, inputs: &CallInputs) -> Option<CallOutcome>
+    where
+        CTX: ContextTr<Journal: JournalExt>,
+    {
+        let retryables = self.retryables.as_ref()?;
+        let env = RetryableCallEnv {
+            chain_id: U256::from(ecx.cfg().chain_id()),
+            timestamp: ecx.block().timestamp().saturating_to(),
+            basefee: U256::from(ecx.block().basefee()),
+            gas_limit: inputs.gas_limit,
+        };
+        let input = inputs.input.bytes(ecx);
+        let mut gas = Gas::new(inputs.gas_limit);
+        let (result, output) = match retryables.call(ecx.journal_mut(), inputs.caller, &input, env)
+        {
+            Ok(None) => return None,
+            Ok(Some((outcome, gas_used))) => {
+                gas.record_cost(gas_used);
+                match outcome {
+                    RetryableCall::Return(output) => (InstructionResult::Return, output),
+                    RetryableCall::Revert(output) => (InstructionResult::Revert, output),
+                    RetryableCall::OutOfGas => (InstructionResult::PrecompileOOG, Bytes::new()),
+                    RetryableCall::Redeem(redeem) => {
+                        let output = Bytes::copy_from_slice(redeem.retry_tx_hash().as_slice());
+                        self.scheduled_redeems.push(redeem);
+                        (InstructionResult::Return, output)
+                    }
+                }
+            }
+            Err(_) => {
+                gas.spend_all();
+                (InstructionResult::Revert, Bytes::new())
+            }
+        };
+
+        Some(CallOutcome {
+            result: InterpreterResult { result, output, gas },
+            memory_offset: inputs.return_memory_offset.clone(),
+            was_precompile_called: true,
+            precompile_call_logs: vec![],
+        })
+    }
 }
 
 /// Prints the traces for the inspector
@@ -151,6 +208,9 @@ fn call(&mut self, ecx: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome
             [&mut self.tracer, &mut self.log_collector, &mut self.transfer],
             |inspector| inspector.call(ecx, inputs).map(Some),
         );
+        if inputs.target_address == ARB_RETRYABLE_TX {
+            return self.call_retryable_tx(ecx, inputs);
+        }
         None
     }
 
@@ -8,7 +8,7 @@
     eth::{
         backend::{
             cheats::{CheatEcrecover, CheatsManager},
-            db::{Db, MaybeFullDatabase, SerializableState, StateDb},
+            db::{Db, DbArbosStorage, MaybeFullDatabase, SerializableState, StateDb},
             env::Env,
             executor::{ExecutedTransactions, TransactionExecutor},
             fork::ClientFork,
@@ -42,13 +42,15 @@
 };
 use alloy_eip5792::{Capabilities, DelegationCapability};
 use alloy_eips::{
+    Typed2718,
     eip1559::BaseFeeParams,
     eip4844::{BlobTransactionSidecar, kzg_to_versioned_hash},
     eip7840::BlobParams,
     eip7910::SystemContract,
 };
 use alloy_network::{
-    AnyHeader, AnyRpcBlock, AnyRpcHeader, AnyRpcTransaction, AnyTxEnvelope, EthereumWallet,
+    AnyHeader, AnyRpcBlock, AnyRpcHeader, AnyRpcTransaction, AnyTxEnvelope, AnyTxType,
+    EthereumWallet, UnknownTxEnvelope, UnknownTypedTransaction,
 };
 use alloy_primitives::{
     Address, B256, Bytes, TxHash, TxKind, U64, U256, address, hex, keccak256, logs_bloom,
@@ -72,7 +74,7 @@
         parity::LocalizedTransactionTrace,
     },
 };
-use alloy_serde::WithOtherFields;
+use alloy_serde::{OtherFields, WithOtherFields};
 use alloy_signer::Signature;
 use alloy_signer_local::PrivateKeySigner;
 use alloy_trie::{HashBuilder, Nibbles, proof::ProofRetainer};
@@ -99,7 +101,12 @@
     constants::DEFAULT_CREATE2_DEPLOYER_RUNTIME_CODE,
     core::{
         FoundryTxEnv,
+        arbos::ARBOS_STATE_ADDRESS,
+        node_interface::{ArbosPricing, NodeInterfaceCall, NodeInterfaceMessage},
+        outbox::{L2ToL1Message, L2ToL1Outbox},
         precompiles::{DynPrecompile, EC_RECOVER, Precompile},
+        retryables::RetryableContext,
+        tx::arbitrum::ArbitrumTxEnvelope,
     },
     decode::RevertDecoder,
     inspectors::AccessListInspector,
@@ -109,6 +116,7 @@
     },
     utils::{get_blob_base_fee_update_fraction, get_blob_base_fee_update_fraction_by_spec_id},
 };
+use foundry_evm_networks::arbitrum::NODE_INTERFACE;
 use futures::channel::mpsc::{UnboundedSender, unbounded};
 use parking_lot::{Mutex, RwLock, RwLockUpgradableReadGuard};
 use revm::{
@@ -217,6 +225,11 @@ pub struct Backend {
     time: TimeManager,
     /// Contains state of custom overrides.
     cheats: CheatsManager,
+    /// Arbitrum retryable processing state which is not kept in the ArbOS state, like the
+    /// number of L1 messages submitted with `anvil_submitRetryable`.
+    retryables: Arc<RwLock<RetryableContext>>,
+    /// Arbitrum L2 to L1 messages sent in mined blocks.
+    l2_to_l1_outbox: Arc<RwLock<L2ToL1Outbox>>,
     /// Contains fee data.
     fees: FeeManager,
     /// Initialised genesis.
@@ -328,6 +341,8 @@ pub async fn with_genesis(
             fork,
             time: TimeManager::new(start_timestamp),
             cheats: Default::default(),
+            retryables: Default::default(),
+            l2_to_l1_outbox: Default::default(),
             new_block_listeners: Default::default(),
             fees,
             genesis,
@@ -455,23 +470,12 @@ async fn apply_genesis(&self) -> Result<(), DatabaseError> {
     ///
     /// This creates a temporary journal context, loads `ArbosStateParams` (with defaults
     /// populated from context if state is empty), applies the closure to modify them,
-    /// and only initializes/commits if the params were actually changed.
+    /// and only initializes/commits if the params were actually changed. In fork mode the
+    /// closure is applied on top of the forked chain's state, so the parameters it leaves alone
+    /// and the activated programs are kept.
     pub async fn apply_arbitrum_state_overrides(&self, f: impl FnOnce(&mut ArbosStateParams)) {
         let is_fork = self.fork.read().is_some();
 
-        // First, check if the closure would make any changes using default params.
-        // This avoids touching the database/journal if nothing would change.
-        // For non-fork mode, we can safely skip if defaults are unchanged.
-        let default_params = ArbosStateParams::default();
-        let mut test_params = default_params.clone();
-        f(&mut test_params);
-
-        // If no changes would be made and we're not in fork mode, skip journal operations
-        // In fork mode, we need to read actual state to compare
-        if test_params == default_params && !is_fork {
-            return;
-        }
-
         let mut db = self.db.write().await;
         let env = self.env.read();
 
@@ -491,15 +495,16 @@ pub async fn apply_arbitrum_state_overrides(&self, f: impl FnOnce(&mut ArbosStat
             // Get current state (with defaults populated from context if empty)
             let original_params = state.get().unwrap();
 
-            // In non-fork mode, use the pre-computed test_params
-            // In fork mode, we already checked that test_params != default_params,
-            // so we should initialize
-            if is_fork {
-                // For fork mode, always initialize with the modified params
-                state.initialize(&test_params).unwrap();
-                context.journaled_state.finalize()
-            } else if test_params != original_params {
-                state.initialize(&test_params).unwrap();
+            // Outside of fork mode the closure is applied to the default params, and nothing is
+            // initialized if it leaves them unchanged. In fork mode it is applied to the remote
+            // chain's state instead.
+            let base_params =
+                if is_fork { original_params.clone() } else { ArbosStateParams::default() };
+            let mut params = base_params.clone();
+            f(&mut params);
+
+            if params != base_params && params != original_params {
+                state.initialize(&params).unwrap();
                 context.journaled_state.finalize()
             } else {
                 Default::default()
@@ -748,6 +753,27 @@ pub fn cheats(&self) -> &CheatsManager {
         &self.cheats
     }
 
+    /// Returns the Arbitrum retryable processing state
+    pub fn retryables(&self) -> &Arc<RwLock<RetryableContext>> {
+        &self.retryables
+    }
+
+    /// Returns the Arbitrum L2 to L1 messages sent in the current state, in order
+    pub async fn l2_to_l1_messages(&self) -> DatabaseResult<Vec<L2ToL1Message>> {
+        let mut db = self.db.write().await;
+        self.l2_to_l1_outbox.read().messages(&mut DbArbosStorage(&mut **db))
+    }
+
+    /// Returns the root of the send Merkle tree in the current state, and the proof of the
+    /// Arbitrum L2 to L1 message at `index` against it
+    pub async fn l2_to_l1_message_proof(
+        &self,
+        index: usize,
+    ) -> DatabaseResult<Option<(B256, Vec<B256>)>> {
+        let mut db = self.db.write().await;
+        L2ToL1Outbox::proof(&mut DbArbosStorage(&mut **db), index)
+    }
+
     /// Whether to skip blob validation
     pub fn skip_blob_validation(&self, impersonator: Option<Address>) -> bool {
         self.cheats().auto_impersonate_accounts()
@@ -1026,6 +1052,7 @@ pub async fn revert_state_snapshot(&self, id: U256) -> Result<bool, BlockchainEr
                     if let Some(hash) = storage.hashes.remove(&n)
                         && let Some(block) = storage.blocks.remove(&hash)
                     {
+                        storage.l1_block_numbers.remove(&hash);
                         for tx in block.body.transactions {
                             let _ = storage.transactions.remove(&tx.hash());
                         }
@@ -1085,10 +1112,19 @@ pub async fn serialized_state(
             transactions,
             historical_states,
         )?;
-        state.ok_or_else(|| {
+        let mut state = state.ok_or_else(|| {
             RpcError::invalid_params("Dumping state not supported with the current configuration")
-                .into()
-        })
+        })?;
+        // the L1 block numbers are kept by the blockchain storage, not by the database
+        state.l1_block_numbers = self
+            .blockchain
+            .storage
+            .read()
+            .l1_block_numbers
+            .iter()
+            .map(|(hash, number)| (*hash, *number))
+            .collect();
+        Ok(state)
     }
 
     /// Write all chain data to serialized bytes buffer
@@ -1109,6 +1145,7 @@ pub async fn load_state(&self, state: SerializableState) -> Result<bool, Blockch
         // load the blocks and transactions into the storage
         self.blockchain.storage.write().load_blocks(state.blocks.clone());
         self.blockchain.storage.write().load_transactions(state.transactions.clone());
+        self.blockchain.storage.write().l1_block_numbers.extend(state.l1_block_numbers.clone());
         // reset the block env
         if let Some(block) = state.block.clone() {
             self.env.write().evm_env.block_env = block.clone();
@@ -1343,6 +1380,8 @@ pub async fn with_pending_block<F, T>(
             networks: self.env.read().networks,
             blob_params: self.blob_params(),
             cheats: self.cheats().clone(),
+            retryables: Some(self.retryables.read().clone()),
+            scheduled: Default::default(),
         };
 
         // create a new pending block
@@ -1432,6 +1471,8 @@ async fn do_mine_block(
                     precompile_factory: self.precompile_factory.clone(),
                     blob_params: self.blob_params(),
                     cheats: self.cheats().clone(),
+                    retryables: Some(self.retryables.read().clone()),
+                    scheduled: Default::default(),
                 };
                 let executed_tx = executor.execute();
 
@@ -1443,7 +1484,7 @@ async fn do_mine_block(
             };
 
             // create the new block with the current timestamp
-            let ExecutedTransactions { block, included, invalid } = executed_tx;
+            let ExecutedTransactions { block, included, invalid, l1_block_number } = executed_tx;
             let BlockInfo { block, transactions, receipts } = block;
 
             let header = block.header.clone();
@@ -1468,8 +1509,12 @@ async fn do_mine_block(
 
             storage.blocks.insert(block_hash, block);
             storage.hashes.insert(block_number, block_hash);
+            if let Some(l1_block_number) = l1_block_number {
+                storage.l1_block_numbers.insert(block_hash, l1_block_number);
+            }
 
             node_info!("");
+            let mut outbox = self.l2_to_l1_outbox.write();
             // insert all transactions
             for (info, receipt) in transactions.into_iter().zip(receipts) {
                 // log some tx info
@@ -1487,6 +1532,11 @@ async fn do_mine_block(
                 }
                 node_info!("");
 
+                // the messages themselves were appended to the send Merkle tree by the executor
+                for message in receipt.logs().iter().filter_map(L2ToL1Message::from_log) {
+                    outbox.insert(message);
+                }
+
                 let mined_tx = MinedTransaction { info, receipt, block_hash, block_number };
                 storage.transactions.insert(mined_tx.info.transaction_hash, mined_tx);
             }
@@ -1687,7 +1737,8 @@ fn build_call_env(
 
     /// Builds [`Inspector`] with the configured options.
     fn build_inspector(&self) -> AnvilInspector {
-        let mut inspector = AnvilInspector::default();
+        let mut inspector =
+            AnvilInspector::default().with_retryables(self.retryables.read().clone());
 
         if self.print_logs {
             inspector = inspector.with_log_collector();
@@ -1806,7 +1857,7 @@ pub async fn simulate(
                         None,
                         None,
                         Some(block_env.basefee),
-                    );
+                    )?;
                     transactions.push(rpc_tx);
 
                     let return_data = result.output().cloned().unwrap_or_default();
@@ -2256,7 +2307,7 @@ pub async fn block_by_hash_full(
         hash: B256,
     ) -> Result<Option<AnyRpcBlock>, BlockchainError> {
         trace!(target: "backend", "get block by hash {:?}", hash);
-        if let tx @ Some(_) = self.get_full_block(hash) {
+        if let tx @ Some(_) = self.get_full_block(hash)? {
             return Ok(tx);
         }
 
@@ -2275,29 +2326,34 @@ fn mined_block_by_hash(&self, hash: B256) -> Option<AnyRpcBlock> {
     pub(crate) async fn mined_transactions_by_block_number(
         &self,
         number: BlockNumber,
-    ) -> Option<Vec<AnyRpcTransaction>> {
+    ) -> Result<Option<Vec<AnyRpcTransaction>>, BlockchainError> {
         if let Some(block) = self.get_block(number) {
             return self.mined_transactions_in_block(&block);
         }
-        None
+        Ok(None)
     }
 
     /// Returns all transactions given a block
     pub(crate) fn mined_transactions_in_block(
         &self,
         block: &Block,
-    ) -> Option<Vec<AnyRpcTransaction>> {
+    ) -> Result<Option<Vec<AnyRpcTransaction>>, BlockchainError> {
         let mut transactions = Vec::with_capacity(block.body.transactions.len());
         let base_fee = block.header.base_fee_per_gas;
         let storage = self.blockchain.storage.read();
         for hash in block.body.transactions.iter().map(|tx| tx.hash()) {
-            let info = storage.transactions.get(&hash)?.info.clone();
-            let tx = block.body.transactions.get(info.transaction_index as usize)?.clone();
+            let Some(info) = storage.transactions.get(&hash).map(|tx| tx.info.clone()) else {
+                return Ok(None);
+            };
+            let Some(tx) = block.body.transactions.get(info.transaction_index as usize).cloned()
+            else {
+                return Ok(None);
+            };
 
-            let tx = transaction_build(Some(hash), tx, Some(block), Some(info), base_fee);
+            let tx = transaction_build(Some(hash), tx, Some(block), Some(info), base_fee)?;
             transactions.push(tx);
         }
-        Some(transactions)
+        Ok(Some(transactions))
     }
 
     pub async fn block_by_number(
@@ -2324,7 +2380,7 @@ pub async fn block_by_number_full(
         number: BlockNumber,
     ) -> Result<Option<AnyRpcBlock>, BlockchainError> {
         trace!(target: "backend", "get block by number {:?}", number);
-        if let tx @ Some(_) = self.get_full_block(number) {
+        if let tx @ Some(_) = self.get_full_block(number)? {
             return Ok(tx);
         }
 
@@ -2386,12 +2442,17 @@ pub fn mined_block_by_number(&self, number: BlockNumber) -> Option<AnyRpcBlock>
         Some(block)
     }
 
-    pub fn get_full_block(&self, id: impl Into<BlockId>) -> Option<AnyRpcBlock> {
-        let (block, hash) = self.get_block_with_hash(id)?;
-        let transactions = self.mined_transactions_in_block(&block)?;
+    pub fn get_full_block(
+        &self,
+        id: impl Into<BlockId>,
+    ) -> Result<Option<AnyRpcBlock>, BlockchainError> {
+        let Some((block, hash)) = self.get_block_with_hash(id) else { return Ok(None) };
+        let Some(transactions) = self.mined_transactions_in_block(&block)? else {
+            return Ok(None);
+        };
         let mut block = self.convert_block_with_hash(block, Some(hash));
         block.inner.transactions = BlockTransactions::Full(transactions);
-        Some(block)
+        Ok(Some(block))
     }
 
     /// Takes a block as it's stored internally and returns the eth api conform block format.
@@ -2429,12 +2490,21 @@ pub fn convert_block_with_hash(&self, block: Block, known_hash: Option<B256>) ->
         // If Arbitrum, apply chain specifics to converted block.
         if is_arbitrum(self.env.read().evm_env.cfg_env.chain_id) {
             // Set `l1BlockNumber` field.
-            block.other.insert("l1BlockNumber".to_string(), number.into());
+            let l1_block_number = self.l1_block_number(&hash, number);
+            block.other.insert("l1BlockNumber".to_string(), format!("{l1_block_number:#x}").into());
         }
 
         AnyRpcBlock::from(block)
     }
 
+    /// Returns the L1 block number of the Arbitrum block with the given hash and number.
+    ///
+    /// Blocks mined before the L1 block number was recorded, e.g. forked ones, fall back to their
+    /// L2 block number.
+    fn l1_block_number(&self, hash: &B256, number: u64) -> u64 {
+        self.blockchain.storage.read().l1_block_numbers.get(hash).copied().unwrap_or(number)
+    }
+
     /// Converts the `BlockNumber` into a numeric value
     ///
     /// # Errors
@@ -2630,6 +2700,51 @@ pub fn get_balance_with_state<D>(
         Ok(state.basic_ref(address)?.unwrap_or_default().balance)
     }
 
+    /// Decodes a call to `NodeInterface` from `request`, priced with the ArbOS state in `state`.
+    ///
+    /// Returns `None` if the request is not a call to a supported `NodeInterface` method.
+    pub fn node_interface_call_with_state(
+        &self,
+        state: &dyn DatabaseRef,
+        request: &TransactionRequest,
+        block_env: &BlockEnv,
+    ) -> Result<Option<NodeInterfaceCall>, BlockchainError> {
+        if request.to != Some(TxKind::Call(NODE_INTERFACE)) {
+            return Ok(None);
+        }
+        let pricing = ArbosPricing::load(|slot| state.storage_ref(ARBOS_STATE_ADDRESS, slot))?;
+        Ok(NodeInterfaceCall::decode(
+            request.from.unwrap_or_default(),
+            request.value.unwrap_or_default(),
+            request.input.input().map_or(&[][..], |input| input.as_ref()),
+            &pricing,
+            U256::from(block_env.basefee),
+            self.chain_id().to(),
+        ))
+    }
+
+    /// Returns the L2 gas paying for the L1 cost of `request` on Arbitrum chains, padded like
+    /// Nitro's gas estimates, or zero on other chains.
+    pub fn l1_gas_estimate_with_state(
+        &self,
+        state: &dyn DatabaseRef,
+        request: &TransactionRequest,
+        block_env: &BlockEnv,
+    ) -> Result<u64, BlockchainError> {
+        if !is_arbitrum(self.env.read().evm_env.cfg_env.chain_id) {
+            return Ok(0);
+        }
+        let pricing = ArbosPricing::load(|slot| state.storage_ref(ARBOS_STATE_ADDRESS, slot))?;
+        // the whole transaction is posted to L1, not only its calldata
+        let message = NodeInterfaceMessage {
+            from: request.from.unwrap_or_default(),
+            to: request.to.unwrap_or_default(),
+            value: request.value.unwrap_or_default(),
+            data: request.input.input().cloned().unwrap_or_default(),
+        };
+        Ok(pricing.estimate_l1_gas(&message.poster_tx(), U256::from(block_env.basefee)))
+    }
+
     /// Returns the nonce of the address
     ///
     /// If the requested number predates the fork then this will fetch it from the endpoint
@@ -2801,6 +2916,8 @@ fn replay_tx_with_inspector<I, F, T>(
                 networks: self.env.read().networks,
                 blob_params: self.blob_params(),
                 cheats: self.cheats().clone(),
+                retryables: None,
+                scheduled: Default::default(),
             };
 
             let _ = executor.execute();
@@ -3157,6 +3274,10 @@ pub(crate) fn mined_transaction_receipt(&self, hash: B256) -> Option<MinedTransa
                 .base_fee_per_gas
                 .map_or(self.base_fee() as u128, |g| g as u128)
                 .saturating_add(t.tx().max_priority_fee_per_gas),
+            // Arbitrum transactions do not pay tips
+            TypedTransaction::Arbitrum(_) => {
+                block.header.base_fee_per_gas.map_or(self.base_fee() as u128, |g| g as u128)
+            }
         };
 
         let receipts = self.get_receipts(block.body.transactions.iter().map(|tx| tx.hash()));
@@ -3200,6 +3321,22 @@ pub(crate) fn mined_transaction_receipt(&self, hash: B256) -> Option<MinedTransa
             TypedReceipt::EIP2930(_) => TypedReceiptRpc::EIP2930(receipt_with_bloom),
             TypedReceipt::EIP4844(_) => TypedReceiptRpc::EIP4844(receipt_with_bloom),
             TypedReceipt::EIP7702(_) => TypedReceiptRpc::EIP7702(receipt_with_bloom),
+            TypedReceipt::ArbitrumDeposit(_) => {
+                TypedReceiptRpc::ArbitrumDeposit(receipt_with_bloom)
+            }
+            TypedReceipt::ArbitrumUnsigned(_) => {
+                TypedReceiptRpc::ArbitrumUnsigned(receipt_with_bloom)
+            }
+            TypedReceipt::ArbitrumContract(_) => {
+                TypedReceiptRpc::ArbitrumContract(receipt_with_bloom)
+            }
+            TypedReceipt::ArbitrumRetry(_) => TypedReceiptRpc::ArbitrumRetry(receipt_with_bloom),
+            TypedReceipt::ArbitrumSubmitRetryable(_) => {
+                TypedReceiptRpc::ArbitrumSubmitRetryable(receipt_with_bloom)
+            }
+            TypedReceipt::ArbitrumInternal(_) => {
+                TypedReceiptRpc::ArbitrumInternal(receipt_with_bloom)
+            }
         };
 
         let inner = TransactionReceipt {
@@ -3217,7 +3354,17 @@ pub(crate) fn mined_transaction_receipt(&self, hash: B256) -> Option<MinedTransa
             blob_gas_used,
         };
 
-        let inner = WithOtherFields { inner, other: Default::default() };
+        let mut inner = WithOtherFields { inner, other: Default::default() };
+
+        // If Arbitrum, add the L1 fields of Nitro receipts.
+        if is_arbitrum(self.env.read().evm_env.cfg_env.chain_id) {
+            let l1_block_number = self.l1_block_number(&block_hash, block.header.number);
+            inner
+                .other
+                .insert("gasUsedForL1".to_string(), format!("{:#x}", info.gas_used_for_l1).into());
+            inner.other.insert("l1BlockNumber".to_string(), format!("{l1_block_number:#x}").into());
+        }
+
         Some(MinedTransactionReceipt { inner, out: info.out })
     }
 
@@ -3252,7 +3399,7 @@ pub async fn transaction_by_block_number_and_index(
         index: Index,
     ) -> Result<Option<AnyRpcTransaction>, BlockchainError> {
         if let Some(block) = self.mined_block_by_number(number) {
-            return Ok(self.mined_transaction_by_block_hash_and_index(block.header.hash, index));
+            return self.mined_transaction_by_block_hash_and_index(block.header.hash, index);
         }
 
         if let Some(fork) = self.get_fork() {
@@ -3272,7 +3419,7 @@ pub async fn transaction_by_block_hash_and_index(
         hash: B256,
         index: Index,
     ) -> Result<Option<AnyRpcTransaction>, BlockchainError> {
-        if let tx @ Some(_) = self.mined_transaction_by_block_hash_and_index(hash, index) {
+        if let tx @ Some(_) = self.mined_transaction_by_block_hash_and_index(hash, index)? {
             return Ok(tx);
         }
 
@@ -3287,23 +3434,26 @@ pub fn mined_transaction_by_block_hash_and_index(
         &self,
         block_hash: B256,
         index: Index,
-    ) -> Option<AnyRpcTransaction> {
-        let (info, block, tx) = {
+    ) -> Result<Option<AnyRpcTransaction>, BlockchainError> {
+        let found = {
             let storage = self.blockchain.storage.read();
-            let block = storage.blocks.get(&block_hash).cloned()?;
-            let index: usize = index.into();
-            let tx = block.body.transactions.get(index)?.clone();
-            let info = storage.transactions.get(&tx.hash())?.info.clone();
-            (info, block, tx)
+            storage.blocks.get(&block_hash).cloned().and_then(|block| {
+                let index: usize = index.into();
+                let tx = block.body.transactions.get(index)?.clone();
+                let info = storage.transactions.get(&tx.hash())?.info.clone();
+                Some((info, block, tx))
+            })
         };
+        let Some((info, block, tx)) = found else { return Ok(None) };
 
-        Some(transaction_build(
+        transaction_build(
             Some(info.transaction_hash),
             tx,
             Some(&block),
             Some(info),
             block.header.base_fee_per_gas,
-        ))
+        )
+        .map(Some)
     }
 
     pub async fn transaction_by_hash(
@@ -3311,7 +3461,7 @@ pub async fn transaction_by_hash(
         hash: B256,
     ) -> Result<Option<AnyRpcTransaction>, BlockchainError> {
         trace!(target: "backend", "transaction_by_hash={:?}", hash);
-        if let tx @ Some(_) = self.mined_transaction_by_hash(hash) {
+        if let tx @ Some(_) = self.mined_transaction_by_hash(hash)? {
             return Ok(tx);
         }
 
@@ -3325,28 +3475,37 @@ pub async fn transaction_by_hash(
         Ok(None)
     }
 
-    pub fn mined_transaction_by_hash(&self, hash: B256) -> Option<AnyRpcTransaction> {
-        let (info, block) = {
+    pub fn mined_transaction_by_hash(
+        &self,
+        hash: B256,
+    ) -> Result<Option<AnyRpcTransaction>, BlockchainError> {
+        let found = {
             let storage = self.blockchain.storage.read();
-            let MinedTransaction { info, block_hash, .. } =
-                storage.transactions.get(&hash)?.clone();
-            let block = storage.blocks.get(&block_hash).cloned()?;
-            (info, block)
+            storage.transactions.get(&hash).cloned().and_then(
+                |MinedTransaction { info, block_hash, .. }| {
+                    let block = storage.blocks.get(&block_hash).cloned()?;
+                    Some((info, block))
+                },
+            )
+        };
+        let Some((info, block)) = found else { return Ok(None) };
+        let Some(tx) = block.body.transactions.get(info.transaction_index as usize).cloned() else {
+            return Ok(None);
         };
-        let tx = block.body.transactions.get(info.transaction_index as usize)?.clone();
 
-        Some(transaction_build(
+        transaction_build(
             Some(info.transaction_hash),
             tx,
             Some(&block),
             Some(info),
             block.header.base_fee_per_gas,
-        ))
+        )
+        .map(Some)
     }
 
     pub fn get_blob_by_tx_hash(&self, hash: B256) -> Result<Option<Vec<alloy_consensus::Blob>>> {
         // Try to get the mined transaction by hash
-        if let Some(tx) = self.mined_transaction_by_hash(hash)
+        if let Some(tx) = self.mined_transaction_by_hash(hash)?
             && let Ok(typed_tx) = TypedTransaction::try_from(tx)
             && let Some(sidecar) = typed_tx.sidecar()
         {
@@ -3384,7 +3543,7 @@ pub fn get_blob_sidecars_by_block_id(
         &self,
         block_id: BlockId,
     ) -> Result<Option<BlobTransactionSidecar>> {
-        if let Some(full_block) = self.get_full_block(block_id) {
+        if let Some(full_block) = self.get_full_block(block_id)? {
             let sidecar = full_block
                 .into_transactions_iter()
                 .map(TypedTransaction::try_from)
@@ -3670,6 +3829,16 @@ fn validate_pool_transaction_for(
             }
         }
 
+        // Arbitrum transactions are derived from L1 messages or created by ArbOS, and are paid for
+        // on L1, so only the nonce of unsigned transactions is checked
+        if tx.transaction.is_arbitrum() {
+            if !tx.transaction.skips_nonce_check() && tx.nonce() < account.nonce {
+                warn!(target: "backend", "[{:?}] nonce too low", tx.hash());
+                return Err(InvalidTransactionError::NonceTooLow);
+            }
+            return Ok(());
+        }
+
         // Nonce validation
         let nonce = tx.nonce();
         if nonce < account.nonce {
@@ -3787,7 +3956,7 @@ fn validate_for(
         env: &Env,
     ) -> Result<(), InvalidTransactionError> {
         self.validate_pool_transaction_for(tx, account, env)?;
-        if tx.nonce() > account.nonce {
+        if tx.nonce() > account.nonce && !tx.transaction.skips_nonce_check() {
             return Err(InvalidTransactionError::NonceTooHigh);
         }
         Ok(())
@@ -3801,8 +3970,14 @@ pub fn transaction_build(
     block: Option<&Block>,
     info: Option<TransactionInfo>,
     base_fee: Option<u64>,
-) -> AnyRpcTransaction {
-    let transaction = eth_transaction.into_rpc_transaction();
+) -> Result<AnyRpcTransaction, BlockchainError> {
+    if let TypedTransaction::Arbitrum(arbitrum_tx) = &eth_transaction.transaction {
+        return arbitrum_transaction_build(tx_hash, arbitrum_tx, block, info, base_fee);
+    }
+
+    let transaction = eth_transaction
+        .into_rpc_transaction()
+        .map_err(|err| RpcError::internal_error_with(err.to_string()))?;
     let effective_gas_price = transaction.effective_gas_price(base_fee);
 
     let envelope = transaction.inner;
@@ -3851,7 +4026,46 @@ pub fn transaction_build(
         // deprecated
         effective_gas_price: Some(effective_gas_price),
     };
-    AnyRpcTransaction::from(WithOtherFields::new(tx))
+    Ok(AnyRpcTransaction::from(WithOtherFields::new(tx)))
+}
+
+/// Creates a `AnyRpcTransaction` for an Arbitrum transaction, serialized like Nitro does.
+fn arbitrum_transaction_build(
+    tx_hash: Option<B256>,
+    arbitrum_tx: &ArbitrumTxEnvelope,
+    block: Option<&Block>,
+    info: Option<TransactionInfo>,
+    base_fee: Option<u64>,
+) -> Result<AnyRpcTransaction, BlockchainError> {
+    let mut fields: OtherFields = serde_json::to_value(arbitrum_tx)
+        .and_then(serde_json::from_value)
+        .map_err(|err| RpcError::internal_error_with(err.to_string()))?;
+    // the type and sender are serialized by the envelope
+    fields.remove("type");
+    fields.remove("from");
+    // Arbitrum transactions are not signed
+    for key in ["v", "r", "s"] {
+        fields.insert(key.to_string(), "0x0".into());
+    }
+
+    let envelope = AnyTxEnvelope::Unknown(UnknownTxEnvelope {
+        hash: tx_hash.unwrap_or_else(|| arbitrum_tx.hash()),
+        inner: UnknownTypedTransaction {
+            ty: AnyTxType(arbitrum_tx.ty()),
+            fields,
+            memo: Default::default(),
+        },
+    });
+
+    let tx = Transaction {
+        inner: Recovered::new_unchecked(envelope, arbitrum_tx.sender()),
+        block_hash: block.as_ref().map(|block| block.header.hash_slow()),
+        block_number: block.as_ref().map(|block| block.header.number),
+        transaction_index: info.as_ref().map(|info| info.transaction_index),
+        // Arbitrum transactions do not pay tips
+        effective_gas_price: Some(base_fee.unwrap_or_default() as u128),
+    };
+    Ok(AnyRpcTransaction::from(WithOtherFields::new(tx)))
 }
 
 /// Prove a storage key's existence or nonexistence in the account's storage trie.
@@ -263,6 +263,8 @@ pub struct BlockchainStorage {
     pub transactions: B256HashMap<MinedTransaction>,
     /// The total difficulty of the chain until this block
     pub total_difficulty: U256,
+    /// The L1 block numbers of mined blocks on Arbitrum chains (block hash -> L1 block number)
+    pub l1_block_numbers: B256HashMap<u64>,
 }
 
 impl BlockchainStorage {
@@ -311,6 +313,7 @@ pub fn new(
             genesis_hash,
             transactions: Default::default(),
             total_difficulty: Default::default(),
+            l1_block_numbers: Default::default(),
         }
     }
 
@@ -326,6 +329,7 @@ pub fn forked(block_number: u64, block_hash: B256, total_difficulty: U256) -> Se
             genesis_hash: Default::default(),
             transactions: Default::default(),
             total_difficulty,
+            l1_block_numbers: Default::default(),
         }
     }
 
@@ -345,6 +349,7 @@ pub fn unwind_to(&mut self, block_number: u64, block_hash: B256) -> Vec<Block> {
                 if let Some(block) = self.blocks.remove(&hash) {
                     removed.push(block);
                 }
+                self.l1_block_numbers.remove(&hash);
                 self.hashes.remove(&i);
             }
         }
@@ -362,6 +367,7 @@ pub fn empty() -> Self {
             genesis_hash: Default::default(),
             transactions: Default::default(),
             total_difficulty: Default::default(),
+            l1_block_numbers: Default::default(),
         }
     }
 
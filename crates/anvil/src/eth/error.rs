@@ -1,7 +1,7 @@
 //! Aggregated error type for this module
 
 use crate::eth::{overrides::StateOverrideError, pool::transactions::PoolTransaction};
-use alloy_primitives::{B256, Bytes, SignatureError};
+use alloy_primitives::{Address, B256, Bytes, SignatureError};
 use alloy_rpc_types::BlockNumberOrTag;
 use alloy_signer::Error as SignerError;
 use alloy_transport::TransportError;
@@ -101,6 +101,10 @@ pub enum BlockchainError {
     EIP7702TransactionUnsupportedAtHardfork,
     #[error("Unknown transaction type not supported")]
     UnknownTransactionType,
+    #[error(
+        "Arbitrum transactions are not signed, so they can only be sent from impersonated accounts.\n\nYou can impersonate {0} with `anvil_impersonateAccount`."
+    )]
+    UnsignedArbitrumTransaction(Address),
     #[error("Excess blob gas not set.")]
     ExcessBlobGasNotSet,
     #[error("{0}")]
@@ -527,6 +531,9 @@ fn to_rpc_result(self) -> ResponseResult {
                 err @ BlockchainError::UnknownTransactionType => {
                     RpcError::invalid_params(err.to_string())
                 }
+                err @ BlockchainError::UnsignedArbitrumTransaction(_) => {
+                    RpcError::invalid_params(err.to_string())
+                }
                 err @ BlockchainError::MissingRequiredFields => {
                     RpcError::invalid_params(err.to_string())
                 }
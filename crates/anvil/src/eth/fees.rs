@@ -307,7 +307,8 @@ fn create_cache_entry(
                                 .tx()
                                 .max_priority_fee_per_gas
                                 .min(t.tx().max_fee_per_gas.saturating_sub(base_fee)),
-                            None => 0,
+                            // Arbitrum transactions do not pay tips
+                            Some(TypedTransaction::Arbitrum(_)) | None => 0,
                         };
 
                     (gas_used, effective_reward)
@@ -251,7 +251,7 @@ pub async fn ots_get_transaction_by_sender_and_nonce(
         let to = self.backend.best_number();
 
         for n in (from..=to).rev() {
-            if let Some(txs) = self.backend.mined_transactions_by_block_number(n.into()).await {
+            if let Some(txs) = self.backend.mined_transactions_by_block_number(n.into()).await? {
                 for tx in txs {
                     if U256::from(tx.nonce()) == nonce && tx.from() == address {
                         return Ok(Some(tx.tx_hash()));
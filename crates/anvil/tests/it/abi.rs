@@ -49,6 +49,20 @@
     }
 );
 
+// The Stylus cache manager of Nitro chains, registered in `ArbWasmCache`
+sol!(
+    #[sol(rpc)]
+    interface CacheManager {
+        struct Entry {
+            bytes32 code;
+            uint64 size;
+            uint192 bid;
+        }
+
+        function getEntries() external view returns (Entry[] memory);
+    }
+);
+
 // https://docs.soliditylang.org/en/latest/control-structures.html#revert
 sol!(
 // SPDX-License-Identifier: GPL-3.0
@@ -8,7 +8,7 @@
 use alloy_consensus::{SignableTransaction, TxEip1559};
 use alloy_hardforks::EthereumHardfork;
 use alloy_network::{EthereumWallet, TransactionBuilder, TxSignerSync};
-use alloy_primitives::{Address, Bytes, TxKind, U256, address, fixed_bytes};
+use alloy_primitives::{Address, Bytes, TxKind, U64, U256, address, fixed_bytes, keccak256};
 use alloy_provider::{Provider, ext::TxPoolApi};
 use alloy_rpc_types::{
     BlockId, BlockNumberOrTag, TransactionRequest,
@@ -17,10 +17,15 @@
     },
 };
 use alloy_serde::WithOtherFields;
+use alloy_sol_types::SolValue;
 use anvil::{NodeConfig, eth::api::CLIENT_VERSION, spawn};
 use anvil_core::{
     eth::EthRequest,
-    types::{ReorgOptions, TransactionData},
+    types::{ReorgOptions, SubmitRetryableRequest, TransactionData},
+};
+use foundry_evm::core::{
+    arbos::{ARBOS_STATE_ADDRESS, ArbosParam},
+    node_interface::L1_PRICER_FUNDS_POOL_ADDRESS,
 };
 
 use revm::primitives::hardfork::SpecId;
@@ -836,6 +841,54 @@ async fn test_arb_get_block() {
     assert_eq!(block.header.number, 1);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_arb_receipt_l1_fields() {
+    let (api, handle) = spawn(NodeConfig::test().with_chain_id(Some(421611u64))).await;
+    let provider = handle.http_provider();
+    let from = handle.dev_accounts().next().unwrap();
+
+    let l1_base_fee = U256::from(10_000_000_000u64);
+    let slot = ArbosParam::L1BaseFee.storage_slot().unwrap();
+    api.anvil_set_storage_at(ARBOS_STATE_ADDRESS, slot, l1_base_fee.into()).await.unwrap();
+    let slot = ArbosParam::L1BlockNumber.storage_slot().unwrap();
+    api.anvil_set_storage_at(ARBOS_STATE_ADDRESS, slot, U256::from(1337).into()).await.unwrap();
+
+    let tx = TransactionRequest::default()
+        .with_from(from)
+        .to(Address::random())
+        .with_value(U256::from(1));
+    let tx = WithOtherFields::new(tx);
+    let receipt = provider.send_transaction(tx.clone()).await.unwrap().get_receipt().await.unwrap();
+
+    let gas_used_for_l1 = receipt.other.get_deserialized::<U64>("gasUsedForL1").unwrap().unwrap();
+    assert!(gas_used_for_l1 > U64::ZERO);
+    // the L1 component is charged on top of the execution and paid to the L1 pricer
+    let gas_used_for_l1 = gas_used_for_l1.to::<u64>();
+    assert_eq!(receipt.gas_used, 21_000 + gas_used_for_l1);
+    let pool_balance = provider.get_balance(L1_PRICER_FUNDS_POOL_ADDRESS).await.unwrap();
+    assert_eq!(pool_balance, U256::from(gas_used_for_l1) * U256::from(receipt.effective_gas_price));
+    let l1_block_number = receipt.other.get_deserialized::<U64>("l1BlockNumber").unwrap().unwrap();
+    assert_eq!(l1_block_number, U64::from(1337));
+
+    let receipts = api.block_receipts(BlockId::latest()).await.unwrap().unwrap();
+    assert_eq!(receipts[0].other, receipt.other);
+
+    let block = api.block_by_number(BlockNumberOrTag::Latest).await.unwrap().unwrap();
+    let l1_block_number = block.other.get_deserialized::<U64>("l1BlockNumber").unwrap().unwrap();
+    assert_eq!(l1_block_number, U64::from(1337));
+
+    // the estimate includes the L1 component, which a chain without ArbOS pricing leaves out
+    assert!(provider.estimate_gas(tx).await.unwrap() > 21_000);
+
+    // the L1 block numbers of the mined blocks are kept in state dumps
+    let state = api.anvil_dump_state(None).await.unwrap();
+    let (api, _handle) = spawn(NodeConfig::test().with_chain_id(Some(421611u64))).await;
+    api.anvil_load_state(state).await.unwrap();
+    let block = api.block_by_number(BlockNumberOrTag::Latest).await.unwrap().unwrap();
+    let l1_block_number = block.other.get_deserialized::<U64>("l1BlockNumber").unwrap().unwrap();
+    assert_eq!(l1_block_number, U64::from(1337));
+}
+
 // Set next_block_timestamp same as previous block
 // api.evm_set_next_block_timestamp(0).unwrap();
 #[tokio::test(flavor = "multi_thread")]
@@ -1070,3 +1123,135 @@ async fn test_anvil_reset_fork_to_non_fork() {
     let new_block = provider.get_block(BlockId::latest()).await.unwrap().unwrap();
     assert_eq!(new_block.header.number, 1);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_submit_retryable() {
+    let (api, handle) = spawn(NodeConfig::test()).await;
+    let provider = handle.http_provider();
+    api.anvil_set_auto_mine(false).await.unwrap();
+
+    let to = Address::random();
+    let value = U256::from(1337);
+    let request = SubmitRetryableRequest {
+        from: Address::random(),
+        to,
+        value,
+        gas: 100_000,
+        ..Default::default()
+    };
+
+    // the auto-redeem is executed right after the submission, in the same block
+    api.anvil_submit_retryable(request.clone()).await.unwrap();
+    api.mine_one().await;
+    let block = api.block_by_number(BlockNumberOrTag::Latest).await.unwrap().unwrap();
+    assert_eq!(block.transactions.len(), 2);
+    assert_eq!(api.balance(to, None).await.unwrap(), value);
+
+    // without auto-redeem, the ticket must be redeemed through `ArbRetryableTx`
+    let ticket_id = api
+        .anvil_submit_retryable(SubmitRetryableRequest { gas: 0, ..request.clone() })
+        .await
+        .unwrap();
+    api.mine_one().await;
+    assert_eq!(api.balance(to, None).await.unwrap(), value);
+
+    let mut input = keccak256("redeem(bytes32)")[..4].to_vec();
+    input.extend_from_slice(ticket_id.as_slice());
+    let tx = TransactionRequest::default()
+        .from(handle.dev_accounts().next().unwrap())
+        .to(address!("0x000000000000000000000000000000000000006e"))
+        .input(Bytes::from(input).into());
+    provider.send_transaction(WithOtherFields::new(tx)).await.unwrap();
+    api.mine_one().await;
+    let block = api.block_by_number(BlockNumberOrTag::Latest).await.unwrap().unwrap();
+    assert_eq!(block.transactions.len(), 2);
+    assert_eq!(api.balance(to, None).await.unwrap(), value * U256::from(2));
+
+    // tickets are kept in the ArbOS state, so they are reverted with it
+    let snapshot = api.evm_snapshot().await.unwrap();
+    let ticket_id =
+        api.anvil_submit_retryable(SubmitRetryableRequest { gas: 0, ..request }).await.unwrap();
+    api.mine_one().await;
+    let mut input = keccak256("getTimeout(bytes32)")[..4].to_vec();
+    input.extend_from_slice(ticket_id.as_slice());
+    let get_timeout = WithOtherFields::new(
+        TransactionRequest::default()
+            .to(address!("0x000000000000000000000000000000000000006e"))
+            .input(Bytes::from(input).into()),
+    );
+    assert!(provider.call(get_timeout.clone()).await.is_ok());
+    assert!(api.evm_revert(snapshot).await.unwrap());
+    assert!(provider.call(get_timeout).await.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_get_l2_to_l1_messages() {
+    let (api, handle) = spawn(NodeConfig::test()).await;
+    let provider = handle.http_provider();
+    let from = handle.dev_accounts().next().unwrap();
+    let destination = Address::random();
+
+    let mut input = keccak256("sendTxToL1(address,bytes)")[..4].to_vec();
+    input.extend_from_slice(&(destination, Bytes::from_static(b"hello")).abi_encode_params());
+    let tx = WithOtherFields::new(
+        TransactionRequest::default()
+            .from(from)
+            .to(address!("0x0000000000000000000000000000000000000064"))
+            .input(Bytes::from(input).into()),
+    );
+    provider.send_transaction(tx.clone()).await.unwrap().get_receipt().await.unwrap();
+
+    let messages = api.anvil_get_l2_to_l1_messages().await.unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].caller, from);
+    assert_eq!(messages[0].destination, destination);
+    assert_eq!(messages[0].data, Bytes::from_static(b"hello"));
+    assert_eq!(messages[0].position, U256::ZERO);
+
+    let proof = api.anvil_get_l2_to_l1_message_proof(0).await.unwrap();
+    assert_eq!(proof.root, keccak256(messages[0].item_hash()));
+    assert!(proof.proof.is_empty());
+    assert!(api.anvil_get_l2_to_l1_message_proof(1).await.is_err());
+
+    // the send Merkle tree is kept in the ArbOS state, so messages are reverted with it
+    let snapshot = api.evm_snapshot().await.unwrap();
+    provider.send_transaction(tx).await.unwrap().get_receipt().await.unwrap();
+    assert_eq!(api.anvil_get_l2_to_l1_messages().await.unwrap().len(), 2);
+    assert_eq!(api.anvil_get_l2_to_l1_message_proof(1).await.unwrap().proof.len(), 1);
+    assert!(api.evm_revert(snapshot).await.unwrap());
+    assert_eq!(api.anvil_get_l2_to_l1_messages().await.unwrap(), messages);
+    assert_eq!(api.anvil_get_l2_to_l1_message_proof(0).await.unwrap(), proof);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_call_node_interface() {
+    let (_api, handle) = spawn(NodeConfig::test()).await;
+    let provider = handle.http_provider();
+    let node_interface = address!("0x00000000000000000000000000000000000000c8");
+    let to = Address::random();
+
+    let tx = TransactionRequest::default()
+        .to(node_interface)
+        .input(Bytes::copy_from_slice(&keccak256("nitroGenesisBlock()")[..4]).into());
+    let output = provider.call(tx.into()).await.unwrap();
+    assert_eq!(U256::abi_decode(&output).unwrap(), U256::ZERO);
+
+    let mut input = keccak256("gasEstimateComponents(address,bool,bytes)")[..4].to_vec();
+    input.extend_from_slice(&(to, false, Bytes::new()).abi_encode_params());
+    let tx = TransactionRequest::default().to(node_interface).input(Bytes::from(input).into());
+    let output = provider.call(tx.into()).await.unwrap();
+    let (gas, l1_gas, _, _) = <(u64, u64, U256, U256)>::abi_decode_params(&output).unwrap();
+    assert_eq!(gas, 21_000 + l1_gas);
+
+    // the gas of `estimateRetryableTicket` is the gas of the retry, funded by the deposit
+    let mut input =
+        keccak256("estimateRetryableTicket(address,uint256,address,uint256,address,address,bytes)")
+            [..4]
+            .to_vec();
+    let value = U256::from(1337);
+    input.extend_from_slice(
+        &(Address::random(), value, to, value, to, to, Bytes::new()).abi_encode_params(),
+    );
+    let tx = TransactionRequest::default().to(node_interface).input(Bytes::from(input).into());
+    assert_eq!(provider.estimate_gas(tx.into()).await.unwrap(), 21_000);
+}
@@ -1,7 +1,7 @@
 //! various fork related test
 
 use crate::{
-    abi::{ERC721, Greeter},
+    abi::{CacheManager, ERC721, Greeter},
     utils::{http_provider, http_provider_with_signer},
 };
 use alloy_chains::NamedChain;
@@ -20,9 +20,12 @@
 };
 use alloy_serde::WithOtherFields;
 use alloy_signer_local::PrivateKeySigner;
+use alloy_sol_types::SolCall;
 use anvil::{EthereumHardfork, NodeConfig, NodeHandle, PrecompileFactory, eth::EthApi, spawn};
 use foundry_common::provider::get_http_provider;
-use foundry_config::Config;
+use foundry_config::{Config, stylus::StylusConfig};
+use foundry_evm::core::abi::arbitrum::{ArbWasm, ArbWasmCache};
+use foundry_evm_networks::arbitrum::{ARB_WASM, ARB_WASM_CACHE};
 use foundry_test_utils::rpc::{self, next_http_rpc_endpoint, next_rpc_endpoint};
 use futures::StreamExt;
 use std::{
@@ -1217,6 +1220,68 @@ async fn test_arb_fork_mining() {
     assert_eq!(mined_blk_num, init_blk_num + 1);
 }
 
+// Stylus options are applied on top of the forked chain's ArbOS state.
+#[tokio::test(flavor = "multi_thread")]
+async fn test_arb_fork_preserves_arbos_state() {
+    let fork_block_number = 394274860u64;
+    let fork_rpc = next_rpc_endpoint(NamedChain::Arbitrum);
+    let (_api, handle) = spawn(
+        fork_config()
+            .with_fork_block_number(Some(fork_block_number))
+            .with_eth_rpc_url(Some(fork_rpc.clone()))
+            .with_stylus_config(StylusConfig { ink_price: Some(20_000), ..Default::default() }),
+    )
+    .await;
+    let provider = handle.http_provider();
+    let remote = get_http_provider(&fork_rpc);
+    let call = |input: Vec<u8>| {
+        WithOtherFields::new(TransactionRequest::default().with_to(ARB_WASM).with_input(input))
+    };
+
+    // The configured parameter is overridden.
+    let output = provider.call(call(ArbWasm::inkPriceCall {}.abi_encode())).await.unwrap();
+    assert_eq!(ArbWasm::inkPriceCall::abi_decode_returns(&output).unwrap(), 20_000);
+
+    // The other parameters are the forked chain's.
+    for input in [
+        ArbWasm::stylusVersionCall {}.abi_encode(),
+        ArbWasm::pageLimitCall {}.abi_encode(),
+        ArbWasm::expiryDaysCall {}.abi_encode(),
+    ] {
+        let forked = provider.call(call(input.clone())).await.unwrap();
+        let expected =
+            remote.call(call(input)).block(BlockId::number(fork_block_number)).await.unwrap();
+        assert_eq!(forked, expected);
+    }
+
+    // The programs activated on the forked chain stay activated: the programs cached at the fork
+    // block, which are all activated, keep their versions and sizes.
+    let cache_manager = ArbWasmCache::new(ARB_WASM_CACHE, &remote)
+        .allCacheManagers()
+        .block(BlockId::number(fork_block_number))
+        .call()
+        .await
+        .unwrap()[0];
+    let entries = CacheManager::new(cache_manager, &remote)
+        .getEntries()
+        .block(BlockId::number(fork_block_number))
+        .call()
+        .await
+        .unwrap();
+    let codehash = entries.first().expect("no Stylus program cached").code;
+    for input in [
+        ArbWasm::codehashVersionCall { codehash }.abi_encode(),
+        ArbWasm::codehashAsmSizeCall { codehash }.abi_encode(),
+    ] {
+        let forked = provider.call(call(input.clone())).await.unwrap();
+        let expected =
+            remote.call(call(input)).block(BlockId::number(fork_block_number)).await.unwrap();
+        assert_eq!(forked, expected);
+    }
+    let forked = provider.call(call(ArbWasm::codehashVersionCall { codehash }.abi_encode())).await;
+    assert_ne!(ArbWasm::codehashVersionCall::abi_decode_returns(&forked.unwrap()).unwrap(), 0);
+}
+
 // <https://github.com/foundry-rs/foundry/issues/6749>
 #[tokio::test(flavor = "multi_thread")]
 async fn test_arbitrum_fork_block_number() {
@@ -1369,7 +1434,7 @@ async fn test_immutable_fork_transaction_hash() {
         (expected_transactions[1], address!("0x0a02a416f87a13626dda0ad386859497565222aa")),
         (expected_transactions[2], address!("0x4f07d669d76ed9a17799fc4c04c4005196240940")),
     ] {
-        let tx = api.backend.mined_transaction_by_hash(expected.0).unwrap();
+        let tx = api.backend.mined_transaction_by_hash(expected.0).unwrap().unwrap();
         assert_eq!(tx.inner.inner.signer(), expected.1);
     }
 
@@ -1379,13 +1444,15 @@ async fn test_immutable_fork_transaction_hash() {
         (expected_transactions[1], 1),
         (expected_transactions[2], 2),
     ] {
-        let tx = api
+        let hash = api
             .backend
             .mined_block_by_number(BlockNumberOrTag::Number(fork_block_number))
             .map(|b| b.header.hash)
-            .and_then(|hash| {
-                api.backend.mined_transaction_by_block_hash_and_index(hash, expected.1.into())
-            })
+            .unwrap();
+        let tx = api
+            .backend
+            .mined_transaction_by_block_hash_and_index(hash, expected.1.into())
+            .unwrap()
             .unwrap();
         assert_eq!(tx.tx_hash().to_string(), expected.0.to_string());
     }
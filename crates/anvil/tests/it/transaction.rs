@@ -5,7 +5,7 @@
 use alloy_consensus::Transaction;
 use alloy_hardforks::EthereumHardfork;
 use alloy_network::{EthereumWallet, TransactionBuilder, TransactionResponse};
-use alloy_primitives::{Address, Bytes, FixedBytes, U256, address, hex, map::B256HashSet};
+use alloy_primitives::{Address, Bytes, FixedBytes, TxKind, U256, address, hex, map::B256HashSet};
 use alloy_provider::{Provider, WsConnect};
 use alloy_rpc_types::{
     AccessList, AccessListItem, BlockId, BlockNumberOrTag, BlockOverrides, BlockTransactions,
@@ -16,6 +16,7 @@
 use alloy_sol_types::SolValue;
 use anvil::{NodeConfig, spawn};
 use eyre::Ok;
+use foundry_evm::core::tx::arbitrum::{ArbitrumTxEnvelope, TxArbitrumUnsigned};
 use futures::{FutureExt, StreamExt, future::join_all};
 use revm::primitives::eip7825::TX_GAS_LIMIT_CAP;
 use std::{str::FromStr, time::Duration};
@@ -1475,3 +1476,35 @@ async fn can_get_tx_by_sender_and_nonce() {
     let found_tx = result.unwrap();
     assert_eq!(found_tx.inner.nonce(), 4);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_send_unsigned_arbitrum_tx_only_when_impersonated() {
+    let (api, handle) = spawn(NodeConfig::test()).await;
+    let provider = handle.http_provider();
+
+    let from = Address::random();
+    let to = Address::random();
+    api.anvil_set_balance(from, U256::from(1e18)).await.unwrap();
+
+    let tx = ArbitrumTxEnvelope::Unsigned(TxArbitrumUnsigned {
+        chain_id: U256::from(api.chain_id()),
+        from,
+        nonce: 0,
+        gas_fee_cap: U256::from(10_000_000_000u64),
+        gas: 21_000,
+        to: TxKind::Call(to),
+        value: U256::from(1),
+        data: Bytes::new(),
+    });
+    let mut raw = Vec::new();
+    tx.encode_2718(&mut raw);
+
+    // the sender is not backed by a signature
+    let err = provider.send_raw_transaction(&raw).await.unwrap_err();
+    assert!(err.to_string().contains("impersonate"), "{err}");
+
+    api.anvil_impersonate_account(from).await.unwrap();
+    let receipt = provider.send_raw_transaction(&raw).await.unwrap().get_receipt().await.unwrap();
+    assert!(receipt.status());
+    assert_eq!(provider.get_balance(to).await.unwrap(), U256::from(1));
+}
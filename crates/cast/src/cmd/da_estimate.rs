@@ -1,27 +1,126 @@
-//! Estimates the data availability size of a block for opstack.
+//! Estimates the L1 data cost of an Arbitrum block.
 
 use alloy_consensus::BlockHeader;
+use alloy_eips::Encodable2718;
+use alloy_network::AnyTxEnvelope;
+use alloy_primitives::{B256, U256};
 use alloy_provider::Provider;
 use alloy_rpc_types::BlockId;
 use clap::Parser;
+use comfy_table::{Cell, Table, modifiers::UTF8_ROUND_CORNERS, presets::ASCII_MARKDOWN};
 use foundry_cli::{
     opts::RpcOpts,
     utils::{self, LoadConfig},
 };
+use foundry_common::shell;
+use foundry_evm::core::{
+    abi::arbitrum::{ArbGasInfo, ArbOwnerPublic},
+    node_interface::{L1_GAS_PER_BYTE, compressed_size},
+};
+use foundry_evm_networks::arbitrum::{ARB_GAS_INFO, ARB_OWNER_PUBLIC};
+use serde::Serialize;
+
+/// The brotli compression level used when the chain does not report one.
+const DEFAULT_BROTLI_LEVEL: u32 = 1;
 
 /// CLI arguments for `cast da-estimate`.
 #[derive(Debug, Parser)]
 pub struct DAEstimateArgs {
-    /// The block to estimate the data availability size for.
+    /// The block to estimate the L1 data cost for.
     pub block: BlockId,
+
+    /// The brotli compression level to price transactions with.
+    ///
+    /// Defaults to the level reported by `ArbOwnerPublic.getBrotliCompressionLevel`.
+    #[arg(long, value_name = "LEVEL")]
+    pub brotli_level: Option<u32>,
+
+    /// Also estimate the L1 fee of each transaction, using the L1 base fee estimate of
+    /// `ArbGasInfo`.
+    #[arg(long)]
+    pub l1_fee: bool,
+
+    /// The L1 base fee to price transactions with, in wei. Implies `--l1-fee`.
+    #[arg(long, value_name = "WEI")]
+    pub l1_base_fee: Option<U256>,
+
     #[command(flatten)]
     pub rpc: RpcOpts,
 }
 
+/// L1 data cost of a single transaction.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TxDataCost {
+    hash: B256,
+    /// Size of the signed transaction, in bytes.
+    size: usize,
+    /// Size of the brotli-compressed signed transaction, in bytes.
+    compressed_size: usize,
+    /// L1 gas units charged by the L1 pricer.
+    l1_gas: u64,
+    /// L1 fee in wei, if requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    l1_fee: Option<U256>,
+}
+
+/// L1 data cost of a block.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BlockDataCost {
+    block_number: u64,
+    brotli_level: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    l1_base_fee: Option<U256>,
+    size: usize,
+    compressed_size: usize,
+    l1_gas: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    l1_fee: Option<U256>,
+    transactions: Vec<TxDataCost>,
+}
+
+impl TxDataCost {
+    /// Prices posting the signed transaction `encoded` to L1.
+    fn new(hash: B256, encoded: &[u8], brotli_level: u32, l1_base_fee: Option<U256>) -> Self {
+        let compressed_size = compressed_size(encoded, brotli_level);
+        let l1_gas = compressed_size as u64 * L1_GAS_PER_BYTE;
+        Self {
+            hash,
+            size: encoded.len(),
+            compressed_size,
+            l1_gas,
+            l1_fee: l1_base_fee.map(|fee| fee * U256::from(l1_gas)),
+        }
+    }
+}
+
+impl BlockDataCost {
+    /// Sums the L1 data cost of the posted transactions of a block.
+    fn new(
+        block_number: u64,
+        brotli_level: u32,
+        l1_base_fee: Option<U256>,
+        transactions: Vec<TxDataCost>,
+    ) -> Self {
+        Self {
+            block_number,
+            brotli_level,
+            l1_base_fee,
+            size: transactions.iter().map(|tx| tx.size).sum(),
+            compressed_size: transactions.iter().map(|tx| tx.compressed_size).sum(),
+            l1_gas: transactions.iter().map(|tx| tx.l1_gas).sum(),
+            l1_fee: l1_base_fee
+                .map(|_| transactions.iter().filter_map(|tx| tx.l1_fee).sum::<U256>()),
+            transactions,
+        }
+    }
+}
+
 impl DAEstimateArgs {
     /// Load the RPC URL from the config file.
     pub async fn run(self) -> eyre::Result<()> {
-        let Self { block, rpc } = self;
+        let Self { block, brotli_level, l1_fee, l1_base_fee, rpc } = self;
         let config = rpc.load_config()?;
         let provider = utils::get_provider(&config)?;
         let block = provider
@@ -29,15 +128,156 @@ pub async fn run(self) -> eyre::Result<()> {
             .full()
             .await?
             .ok_or_else(|| eyre::eyre!("Block not found"))?;
+        let block_id = BlockId::number(block.header.number());
+
+        let brotli_level = match brotli_level {
+            Some(level) => level,
+            None => match ArbOwnerPublic::new(ARB_OWNER_PUBLIC, &provider)
+                .getBrotliCompressionLevel()
+                .block(block_id)
+                .call()
+                .await
+            {
+                Ok(level) => level as u32,
+                Err(err) => {
+                    sh_warn!(
+                        "failed to fetch the brotli compression level, using {DEFAULT_BROTLI_LEVEL}: {err}"
+                    )?;
+                    DEFAULT_BROTLI_LEVEL
+                }
+            },
+        };
+
+        let l1_base_fee = match l1_base_fee {
+            Some(fee) => Some(fee),
+            None if l1_fee => Some(
+                ArbGasInfo::new(ARB_GAS_INFO, &provider)
+                    .getL1BaseFeeEstimate()
+                    .block(block_id)
+                    .call()
+                    .await?,
+            ),
+            None => None,
+        };
+
+        let transactions = block
+            .transactions
+            .txns()
+            // Arbitrum specific transactions are created by ArbOS or from L1 messages, and are
+            // not posted by the batch poster.
+            .filter_map(|tx| match tx.inner.inner.inner() {
+                AnyTxEnvelope::Ethereum(envelope) => Some(envelope),
+                _ => None,
+            })
+            .map(|envelope| {
+                TxDataCost::new(
+                    *envelope.tx_hash(),
+                    &envelope.encoded_2718(),
+                    brotli_level,
+                    l1_base_fee,
+                )
+            })
+            .collect();
+        let estimate =
+            BlockDataCost::new(block.header.number(), brotli_level, l1_base_fee, transactions);
+
+        if shell::is_json() {
+            sh_println!("{}", serde_json::to_string_pretty(&estimate)?)?;
+            return Ok(());
+        }
+
+        print_estimate(&estimate)
+    }
+}
+
+fn print_estimate(estimate: &BlockDataCost) -> eyre::Result<()> {
+    let mut table = Table::new();
+    if shell::is_markdown() {
+        table.load_preset(ASCII_MARKDOWN);
+    } else {
+        table.apply_modifier(UTF8_ROUND_CORNERS);
+    }
+
+    let mut header = vec![
+        Cell::new("Transaction"),
+        Cell::new("Size"),
+        Cell::new("Compressed Size"),
+        Cell::new("L1 Gas"),
+    ];
+    if estimate.l1_fee.is_some() {
+        header.push(Cell::new("L1 Fee (wei)"));
+    }
+    table.set_header(header);
+
+    for tx in &estimate.transactions {
+        let mut row = vec![
+            Cell::new(tx.hash),
+            Cell::new(tx.size),
+            Cell::new(tx.compressed_size),
+            Cell::new(tx.l1_gas),
+        ];
+        if let Some(fee) = tx.l1_fee {
+            row.push(Cell::new(fee));
+        }
+        table.add_row(row);
+    }
+
+    sh_println!("{table}")?;
+    sh_println!(
+        "Estimated L1 data cost for block {} with {} posted transactions (brotli level {}):",
+        estimate.block_number,
+        estimate.transactions.len(),
+        estimate.brotli_level,
+    )?;
+    sh_println!("  size:            {} bytes", estimate.size)?;
+    sh_println!("  compressed size: {} bytes", estimate.compressed_size)?;
+    sh_println!("  L1 gas:          {}", estimate.l1_gas)?;
+    if let (Some(base_fee), Some(fee)) = (estimate.l1_base_fee, estimate.l1_fee) {
+        sh_println!("  L1 base fee:     {base_fee} wei")?;
+        sh_println!("  L1 fee:          {fee} wei")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prices_compressed_size() {
+        let tx = [0u8; 1000];
+        let cost = TxDataCost::new(B256::ZERO, &tx, DEFAULT_BROTLI_LEVEL, None);
+        assert_eq!(cost.size, 1000);
+        // Zeroes compress well.
+        assert!(cost.compressed_size < 100, "{cost:?}");
+        assert_eq!(cost.l1_gas, cost.compressed_size as u64 * L1_GAS_PER_BYTE);
+        assert_eq!(cost.l1_fee, None);
+
+        let cost = TxDataCost::new(B256::ZERO, &tx, DEFAULT_BROTLI_LEVEL, Some(U256::from(7)));
+        assert_eq!(cost.l1_fee, Some(U256::from(cost.l1_gas * 7)));
+    }
 
-        let block_number = block.header.number();
-        let tx_count = block.transactions.len();
-        let da_estimate = 0;
+    #[test]
+    fn sums_block_cost() {
+        let fee = Some(U256::from(3));
+        let transactions = vec![
+            TxDataCost::new(B256::ZERO, &[1u8; 100], DEFAULT_BROTLI_LEVEL, fee),
+            TxDataCost::new(B256::ZERO, &[2u8; 300], DEFAULT_BROTLI_LEVEL, fee),
+        ];
+        let (l1_gas, compressed_size) = (
+            transactions[0].l1_gas + transactions[1].l1_gas,
+            transactions[0].compressed_size + transactions[1].compressed_size,
+        );
 
-        sh_println!(
-            "Estimated data availability size for block {block_number} with {tx_count} transactions: {da_estimate}"
-        )?;
+        let block = BlockDataCost::new(1, DEFAULT_BROTLI_LEVEL, fee, transactions);
+        assert_eq!(block.size, 400);
+        assert_eq!(block.compressed_size, compressed_size);
+        assert_eq!(block.l1_gas, l1_gas);
+        assert_eq!(block.l1_fee, Some(U256::from(l1_gas * 3)));
 
-        Ok(())
+        let block = BlockDataCost::new(1, DEFAULT_BROTLI_LEVEL, None, Vec::new());
+        assert_eq!(block.l1_gas, 0);
+        assert_eq!(block.l1_fee, None);
     }
 }
@@ -24,5 +24,6 @@
 pub mod run;
 pub mod send;
 pub mod storage;
+pub mod stylus;
 pub mod txpool;
 pub mod wallet;
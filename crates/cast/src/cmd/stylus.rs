@@ -0,0 +1,391 @@
+//! Stylus program activation, caching and inspection.
+
+use crate::tx::signing_provider;
+use alloy_eips::BlockId;
+use alloy_ens::NameOrAddress;
+use alloy_primitives::{Address, B256, U256, keccak256};
+use alloy_provider::{Provider, WalletProvider};
+use alloy_sol_types::sol;
+use clap::Parser;
+use eyre::{Result, WrapErr};
+use foundry_cli::{
+    opts::RpcOpts,
+    utils::{LoadConfig, get_provider},
+};
+use foundry_common::{fs, shell};
+use foundry_evm::core::{
+    abi::arbitrum::{ArbWasm, ArbWasmCache},
+    stylus::{DATA_FEE_BUMP_PERCENT, bump_data_fee, is_stylus_code, stylus_wasm},
+};
+use foundry_evm_networks::arbitrum::{ARB_WASM, ARB_WASM_CACHE};
+use foundry_wallets::WalletOpts;
+use serde::Serialize;
+use std::{path::PathBuf, str::FromStr};
+
+sol! {
+    #[sol(rpc)]
+    interface CacheManager {
+        function getMinBid(address program) external view returns (uint192 min);
+        function placeBid(address program) external payable;
+    }
+}
+
+/// Seconds in a day, used to convert `ArbWasm.expiryDays`.
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Stylus program operations.
+#[derive(Debug, Parser, Clone)]
+pub enum StylusSubcommand {
+    /// Activate a deployed Stylus program with `ArbWasm.activateProgram`.
+    ///
+    /// The data fee is estimated and sent along with the transaction, unless `--value` is set.
+    #[command(visible_alias = "a")]
+    Activate {
+        /// The address of the program.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        program: NameOrAddress,
+
+        /// The value to pay the data fee with, in wei. Any excess is refunded.
+        #[arg(long, value_name = "WEI")]
+        value: Option<U256>,
+
+        /// The percentage to bump the estimated data fee by, to account for price changes
+        /// before the transaction is included.
+        #[arg(long, value_name = "PERCENT", default_value_t = DATA_FEE_BUMP_PERCENT)]
+        bump_percent: u64,
+
+        /// Only estimate the data fee, without activating the program.
+        ///
+        /// The estimate is made with `--value` attached, if set.
+        #[arg(long)]
+        estimate: bool,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+
+        #[command(flatten)]
+        wallet: WalletOpts,
+    },
+
+    /// Show the activation status of a Stylus program.
+    #[command(visible_alias = "s")]
+    Status {
+        /// The address of the program.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        program: NameOrAddress,
+
+        /// The block height to query at.
+        #[arg(long, short = 'B')]
+        block: Option<BlockId>,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+
+    /// Extend the expiry of an activated Stylus program with `ArbWasm.codehashKeepalive`.
+    ///
+    /// The data fee is estimated and sent along with the transaction, unless `--value` is set.
+    #[command(visible_alias = "k")]
+    Keepalive {
+        /// The address of the program.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        program: NameOrAddress,
+
+        /// The value to pay the data fee with, in wei. Any excess is refunded.
+        #[arg(long, value_name = "WEI")]
+        value: Option<U256>,
+
+        /// The percentage to bump the estimated data fee by.
+        #[arg(long, value_name = "PERCENT", default_value_t = DATA_FEE_BUMP_PERCENT)]
+        bump_percent: u64,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+
+        #[command(flatten)]
+        wallet: WalletOpts,
+    },
+
+    /// Bid to keep a Stylus program in the cache of the chain's cache manager.
+    #[command(visible_alias = "cb")]
+    CacheBid {
+        /// The address of the program.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        program: NameOrAddress,
+
+        /// The bid, in wei.
+        ///
+        /// Defaults to the minimum bid reported by the cache manager.
+        bid: Option<U256>,
+
+        /// The cache manager to bid with.
+        ///
+        /// Defaults to the first cache manager registered in `ArbWasmCache`.
+        #[arg(long, value_name = "ADDRESS")]
+        cache_manager: Option<Address>,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+
+        #[command(flatten)]
+        wallet: WalletOpts,
+    },
+
+    /// Fetch the code of a Stylus program and decompress it into its WASM binary.
+    ///
+    /// Deployed programs are stripped of custom sections, so this is the binary that was
+    /// deployed, not the one that was built.
+    #[command(visible_alias = "d")]
+    Decompress {
+        /// The address of the program.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        program: NameOrAddress,
+
+        /// The file to write the WASM binary to.
+        ///
+        /// If not set, the binary is printed as hex.
+        #[arg(long, short, value_name = "PATH")]
+        out: Option<PathBuf>,
+
+        /// The block height to query at.
+        #[arg(long, short = 'B')]
+        block: Option<BlockId>,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+}
+
+/// The activation status of a Stylus program.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProgramStatus {
+    address: Address,
+    codehash: B256,
+    /// The Stylus version the program was activated with.
+    version: u16,
+    /// The current Stylus version of the chain.
+    stylus_version: u16,
+    /// Seconds since the program was activated or kept alive.
+    age: u64,
+    /// Seconds until the program expires.
+    time_left: u64,
+    cached: bool,
+    init_gas: u64,
+    cached_init_gas: u64,
+    /// The memory footprint of the program, in WASM pages.
+    footprint: u16,
+}
+
+impl StylusSubcommand {
+    fn rpc(&self) -> &RpcOpts {
+        match self {
+            Self::Activate { rpc, .. } => rpc,
+            Self::Status { rpc, .. } => rpc,
+            Self::Keepalive { rpc, .. } => rpc,
+            Self::CacheBid { rpc, .. } => rpc,
+            Self::Decompress { rpc, .. } => rpc,
+        }
+    }
+
+    pub async fn run(self) -> Result<()> {
+        let config = self.rpc().load_config()?;
+        let provider = get_provider(&config)?;
+
+        match self {
+            // Read-only
+            Self::Status { program, block, .. } => {
+                let address = program.resolve(&provider).await?;
+                let block = block.unwrap_or_default();
+                let code = provider.get_code_at(address).block_id(block).await?;
+                if !is_stylus_code(&code) {
+                    eyre::bail!("{address} is not a Stylus program");
+                }
+                let codehash = keccak256(&code);
+
+                let arb_wasm = ArbWasm::new(ARB_WASM, &provider);
+                let version = arb_wasm
+                    .programVersion(address)
+                    .block(block)
+                    .call()
+                    .await
+                    .wrap_err("program is not activated, expired or needs an upgrade")?;
+                let stylus_version = arb_wasm.stylusVersion().block(block).call().await?;
+                let time_left = arb_wasm.programTimeLeft(address).block(block).call().await?;
+                let expiry_days = arb_wasm.expiryDays().block(block).call().await?;
+                let init_gas = arb_wasm.programInitGas(address).block(block).call().await?;
+                let footprint =
+                    arb_wasm.programMemoryFootprint(address).block(block).call().await?;
+                let cached = ArbWasmCache::new(ARB_WASM_CACHE, &provider)
+                    .codehashIsCached(codehash)
+                    .block(block)
+                    .call()
+                    .await?;
+
+                let status = ProgramStatus {
+                    address,
+                    codehash,
+                    version,
+                    stylus_version,
+                    age: (expiry_days as u64 * SECONDS_PER_DAY).saturating_sub(time_left),
+                    time_left,
+                    cached,
+                    init_gas: init_gas.gas,
+                    cached_init_gas: init_gas.gasWhenCached,
+                    footprint,
+                };
+                print_status(&status)?
+            }
+            Self::Decompress { program, out, block, .. } => {
+                let address = program.resolve(&provider).await?;
+                let code =
+                    provider.get_code_at(address).block_id(block.unwrap_or_default()).await?;
+                if !is_stylus_code(&code) {
+                    eyre::bail!("{address} is not a Stylus program");
+                }
+                let wasm = stylus_wasm(&code)?;
+
+                match out {
+                    Some(out) => {
+                        fs::write(&out, &wasm)?;
+                        sh_println!("Wrote {} bytes to {}", wasm.len(), out.display())?
+                    }
+                    None => sh_println!("{}", alloy_primitives::hex::encode_prefixed(&wasm))?,
+                }
+            }
+            // State-changing
+            Self::Activate { program, value, bump_percent, estimate, wallet, .. } => {
+                let address = program.resolve(&provider).await?;
+                let provider = signing_provider(wallet, &provider).await?;
+                let arb_wasm = ArbWasm::new(ARB_WASM, &provider);
+
+                let data_fee = async |value: U256| {
+                    let call = arb_wasm
+                        .activateProgram(address)
+                        .from(provider.default_signer_address())
+                        .value(value)
+                        .call()
+                        .await;
+                    match call {
+                        Ok(ret) => Ok(ret.dataFee),
+                        Err(err) => required_value(err),
+                    }
+                };
+
+                if estimate {
+                    sh_println!("{}", data_fee(value.unwrap_or_default()).await?)?;
+                    return Ok(());
+                }
+
+                let value = match value {
+                    Some(value) => value,
+                    None => bump_data_fee(data_fee(U256::ZERO).await?, bump_percent),
+                };
+
+                let tx = arb_wasm.activateProgram(address).value(value).send().await?;
+                sh_println!("{}", tx.tx_hash())?
+            }
+            Self::Keepalive { program, value, bump_percent, wallet, .. } => {
+                let address = program.resolve(&provider).await?;
+                let codehash = keccak256(provider.get_code_at(address).await?);
+                let provider = signing_provider(wallet, &provider).await?;
+                let arb_wasm = ArbWasm::new(ARB_WASM, &provider);
+
+                let value = match value {
+                    Some(value) => value,
+                    None => {
+                        let call = arb_wasm
+                            .codehashKeepalive(codehash)
+                            .from(provider.default_signer_address())
+                            .call()
+                            .await;
+                        match call {
+                            Ok(_) => U256::ZERO,
+                            Err(err) => bump_data_fee(required_value(err)?, bump_percent),
+                        }
+                    }
+                };
+
+                let tx = arb_wasm.codehashKeepalive(codehash).value(value).send().await?;
+                sh_println!("{}", tx.tx_hash())?
+            }
+            Self::CacheBid { program, bid, cache_manager, wallet, .. } => {
+                let address = program.resolve(&provider).await?;
+                let cache_manager = match cache_manager {
+                    Some(cache_manager) => cache_manager,
+                    None => ArbWasmCache::new(ARB_WASM_CACHE, &provider)
+                        .allCacheManagers()
+                        .call()
+                        .await?
+                        .first()
+                        .copied()
+                        .ok_or_else(|| {
+                            eyre::eyre!("no cache manager registered in ArbWasmCache")
+                        })?,
+                };
+                let bid = match bid {
+                    Some(bid) => bid,
+                    None => U256::from(
+                        CacheManager::new(cache_manager, &provider)
+                            .getMinBid(address)
+                            .call()
+                            .await?,
+                    ),
+                };
+
+                let provider = signing_provider(wallet, &provider).await?;
+                let tx = CacheManager::new(cache_manager, &provider)
+                    .placeBid(address)
+                    .value(bid)
+                    .send()
+                    .await?;
+                sh_println!("{}", tx.tx_hash())?
+            }
+        };
+        Ok(())
+    }
+}
+
+/// Returns the data fee an `ArbWasm` call reverted for lack of, or the error if it reverted for
+/// another reason.
+fn required_value(err: alloy_contract::Error) -> Result<U256> {
+    match err.as_decoded_error::<ArbWasm::ProgramInsufficientValue>() {
+        Some(ArbWasm::ProgramInsufficientValue { want, .. }) => Ok(want),
+        None => Err(err.into()),
+    }
+}
+
+fn print_status(status: &ProgramStatus) -> Result<()> {
+    if shell::is_json() {
+        sh_println!("{}", serde_json::to_string_pretty(status)?)?;
+        return Ok(());
+    }
+
+    let upgrade = if status.version < status.stylus_version { " (needs upgrade)" } else { "" };
+    sh_println!("address:         {}", status.address)?;
+    sh_println!("codehash:        {}", status.codehash)?;
+    sh_println!("version:         {}{upgrade}", status.version)?;
+    sh_println!("age:             {}", format_seconds(status.age))?;
+    sh_println!("expires in:      {}", format_seconds(status.time_left))?;
+    sh_println!("cached:          {}", status.cached)?;
+    sh_println!("init gas:        {}", status.init_gas)?;
+    sh_println!("cached init gas: {}", status.cached_init_gas)?;
+    sh_println!("footprint:       {} pages", status.footprint)?;
+    Ok(())
+}
+
+/// Formats a duration in seconds as days, hours, minutes and seconds, e.g. `2d 3h 0m 12s`.
+fn format_seconds(secs: u64) -> String {
+    let (days, secs) = (secs / SECONDS_PER_DAY, secs % SECONDS_PER_DAY);
+    format!("{days}d {}h {}m {}s", secs / 3600, secs % 3600 / 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_seconds() {
+        assert_eq!(format_seconds(2 * SECONDS_PER_DAY + 3 * 3600 + 12), "2d 3h 0m 12s");
+    }
+}
@@ -4,7 +4,7 @@
     creation_code::CreationCodeArgs, da_estimate::DAEstimateArgs, erc20::Erc20Subcommand,
     estimate::EstimateArgs, find_block::FindBlockArgs, interface::InterfaceArgs, logs::LogsArgs,
     mktx::MakeTxArgs, rpc::RpcArgs, run::RunArgs, send::SendTxArgs, storage::StorageArgs,
-    txpool::TxPoolSubcommands, wallet::WalletSubcommands,
+    stylus::StylusSubcommand, txpool::TxPoolSubcommands, wallet::WalletSubcommands,
 };
 use alloy_ens::NameOrAddress;
 use alloy_primitives::{Address, B256, Selector, U256};
@@ -1136,7 +1136,7 @@ pub enum CastSubcommand {
         #[command(subcommand)]
         command: TxPoolSubcommands,
     },
-    /// Estimates the data availability size of a given opstack block.
+    /// Estimates the L1 data cost of the transactions in a given Arbitrum block.
     #[command(name = "da-estimate")]
     DAEstimate(DAEstimateArgs),
 
@@ -1146,6 +1146,12 @@ pub enum CastSubcommand {
         #[command(subcommand)]
         command: Erc20Subcommand,
     },
+
+    /// Stylus program activation, caching and inspection.
+    Stylus {
+        #[command(subcommand)]
+        command: StylusSubcommand,
+    },
 }
 
 /// CLI arguments for `cast --to-base`.
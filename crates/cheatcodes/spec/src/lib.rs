@@ -98,6 +98,7 @@ pub fn new() -> Self {
                 Vm::AccountAccessKind::ENUM.clone(),
                 Vm::ForgeContext::ENUM.clone(),
                 Vm::BroadcastTxType::ENUM.clone(),
+                Vm::StylusTrapKind::ENUM.clone(),
             ]),
             errors: Vm::VM_ERRORS.iter().copied().cloned().collect(),
             events: Cow::Borrowed(&[]),
@@ -316,6 +316,15 @@ enum BroadcastTxType {
         Create2
     }
 
+    /// The reason a Stylus program halted abnormally.
+    /// Programs executing `unreachable`, e.g. on panics or past the page limit, revert without data and are not traps.
+    enum StylusTrapKind {
+        /// The program ran out of ink.
+        OutOfInk,
+        /// The program exceeded its maximum stack depth.
+        StackOverflow
+    }
+
     /// Represents a transaction's broadcast details.
     struct BroadcastTxSummary {
         /// The hash of the transaction that was broadcasted
@@ -359,6 +368,28 @@ struct PotentialRevert {
         bytes revertData;
     }
 
+    /// An Arbitrum L2 to L1 message, sent through `ArbSys`. Returned by `getL2ToL1Messages`.
+    struct L2ToL1Message {
+        /// The L2 account which sent the message.
+        address caller;
+        /// The L1 account the message is sent to.
+        address destination;
+        /// The hash of the message, as computed by `Outbox.calculateItemHash`.
+        uint256 hash;
+        /// The position of the message in the send Merkle tree.
+        uint256 position;
+        /// The L2 block number the message was sent in.
+        uint256 arbBlockNum;
+        /// The L1 block number the message was sent in.
+        uint256 ethBlockNum;
+        /// The timestamp the message was sent at.
+        uint256 timestamp;
+        /// The value sent to L1 with the message.
+        uint256 callvalue;
+        /// The calldata of the L1 call.
+        bytes data;
+    }
+
     // ======== EVM ========
 
     /// Gets the address for a given private key.
@@ -729,6 +760,12 @@ struct PotentialRevert {
     #[cheatcode(group = Evm, safety = Unsafe)]
     function startPrank(address msgSender, address txOrigin, bool delegateCall) external;
 
+    /// Sets the *next* call's `msg.sender` and `tx.origin` to the L2 alias of `l1Sender`, as for a
+    /// message sent from L1. Within the call, `ArbSys.wasMyCallersAddressAliased` returns `true`
+    /// and `ArbSys.myCallersAddressWithoutAliasing` returns `l1Sender`.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function prankFromL1(address l1Sender) external;
+
     /// Resets subsequent calls' `msg.sender` to be `address(this)`.
     #[cheatcode(group = Evm, safety = Unsafe)]
     function stopPrank() external;
@@ -974,6 +1011,60 @@ struct PotentialRevert {
     #[cheatcode(group = Evm, safety = Safe)]
     function lastCallGas() external view returns (Gas memory gas);
 
+    // -------- Arbitrum --------
+
+    /// Submits an L1 to L2 retryable ticket from `l1Sender`, whose aliased address is credited
+    /// with `deposit` and used as the sender of the retry.
+    /// The ticket is redeemed right away with `gasLimit` gas, unless it is zero. If this auto-redeem
+    /// fails, the ticket can be redeemed, kept alive or canceled through `ArbRetryableTx` until it
+    /// expires.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function submitRetryable(address l1Sender, address to, uint256 l2CallValue, uint256 deposit, uint64 gasLimit, address beneficiary, bytes calldata data) external returns (bytes32 ticketId);
+
+    /// Gets the L2 to L1 messages sent locally through `ArbSys.sendTxToL1` and `ArbSys.withdrawEth`
+    /// which have not been reverted.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function getL2ToL1Messages() external view returns (L2ToL1Message[] memory messages);
+
+    /// Gets the root of the send Merkle tree, which continues the tree of the forked chain, and the
+    /// proof of the message at `index` in `getL2ToL1Messages` against it, as passed to
+    /// `Outbox.executeTransaction` with the position of the message as the path.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function getL2ToL1MessageProof(uint256 index) external view returns (bytes32 root, bytes32[] memory proof);
+
+    /// Sets the L1 base fee estimate of ArbOS, used to price L1 calldata and returned by
+    /// `ArbGasInfo.getL1BaseFeeEstimate`.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function setL1BaseFee(uint256 newL1BaseFee) external;
+
+    /// Sets the L1 block number tracked by ArbOS.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function setL1BlockNumber(uint256 newL1BlockNumber) external;
+
+    /// Sets the L2 base fee of ArbOS, returned by `ArbGasInfo.getPricesInWei`, and `block.basefee`.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function setL2BaseFee(uint256 newL2BaseFee) external;
+
+    /// Sets the ArbOS version, which gates the available features and is returned by `ArbSys.arbOSVersion`.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function setArbOSVersion(uint64 newArbOSVersion) external;
+
+    /// Sets an ArbOS parameter by name. The supported names are `arbos_version`, `l1_base_fee`,
+    /// `l1_base_fee_inertia`, `l1_per_batch_gas_cost`, `l1_block_number`, `l2_base_fee`,
+    /// `l2_min_base_fee`, `l2_speed_limit`, `l2_per_block_gas_limit`, `l2_per_tx_gas_limit`,
+    /// `brotli_compression_level`, and the Stylus parameters, named as in the `[stylus]`
+    /// configuration: `stylus_version`, `ink_price`, `max_stack_depth`, `free_pages`, `page_gas`,
+    /// `page_ramp`, `page_limit`, `min_init_gas`, `min_cached_init_gas`, `init_cost_scalar`,
+    /// `cached_cost_scalar`, `expiry_days`, `keepalive_days`, `block_cache_size` and `max_wasm_size`.
+    /// Reverts on unknown names and out of range values.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function setArbosParam(string calldata name, uint256 value) external;
+
+    /// Sets several ArbOS parameters, named as in `setArbosParam`.
+    /// Reverts without setting any parameter on unknown names, out of range values, or arrays of different lengths.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function setArbosParams(string[] calldata names, uint256[] calldata values) external;
+
     // ======== Test Assertions and Utilities ========
 
     /// If the condition is false, discard this run's fuzz inputs and generate new ones.
@@ -1134,6 +1225,20 @@ struct PotentialRevert {
     #[cheatcode(group = Testing, safety = Unsafe)]
     function expectCreate2(bytes calldata bytecode, address deployer) external;
 
+    /// Expects an L2 to L1 message to `destination` with the specified calldata to be sent through `ArbSys`
+    /// by the end of the current test.
+    #[cheatcode(group = Testing, safety = Unsafe)]
+    function expectL2ToL1Message(address destination, bytes calldata data) external;
+
+    /// Expects an L2 to L1 message to `destination` with the specified value and calldata to be sent through
+    /// `ArbSys` by the end of the current test.
+    #[cheatcode(group = Testing, safety = Unsafe)]
+    function expectL2ToL1Message(address destination, uint256 callvalue, bytes calldata data) external;
+
+    /// Expects the next call to fail because a Stylus program halted with a trap of the given kind.
+    #[cheatcode(group = Testing, safety = Unsafe)]
+    function expectStylusTrap(StylusTrapKind kind) external;
+
     /// Expects an error on next call with any revert data.
     #[cheatcode(group = Testing, safety = Unsafe)]
     function expectRevert() external;
@@ -2919,6 +3024,14 @@ struct PotentialRevert {
     #[cheatcode(group = Utilities)]
     function computeCreate2Address(bytes32 salt, bytes32 initCodeHash) external pure returns (address);
 
+    /// Returns the L2 alias of an L1 address, used as `msg.sender` by the messages it sends to L2.
+    #[cheatcode(group = Utilities)]
+    function applyL1ToL2Alias(address l1Address) external pure returns (address l2Address);
+
+    /// Returns the L1 address of an L2 alias.
+    #[cheatcode(group = Utilities)]
+    function undoL1ToL2Alias(address l2Address) external pure returns (address l1Address);
+
     /// Encodes a `bytes` value to a base64 string.
     #[cheatcode(group = Utilities)]
     function toBase64(bytes calldata data) external pure returns (string memory);
@@ -50,10 +50,14 @@
 use record_debug_step::{convert_call_trace_ctx_to_debug_step, flatten_call_trace};
 use serde::Serialize;
 
+mod arbos;
 mod fork;
 pub(crate) mod mapping;
 pub(crate) mod mock;
+pub(crate) mod node_interface;
+pub(crate) mod outbox;
 pub(crate) mod prank;
+pub(crate) mod retryable;
 
 /// Records storage slots reads and writes.
 #[derive(Clone, Debug, Default)]
@@ -0,0 +1,123 @@
+//! Implementations of the ArbOS state cheatcodes.
+
+use crate::{Cheatcode, CheatsCtxt, Result, Vm::*};
+use alloy_primitives::U256;
+use arbos_revm::state::ArbState;
+use foundry_evm_core::{
+    ContextExt,
+    arbos::{ARBOS_STATE_ADDRESS, ArbosParam},
+};
+
+impl Cheatcode for setL1BaseFeeCall {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self { newL1BaseFee } = self;
+        set_arbos_param(ccx, ArbosParam::L1BaseFee, *newL1BaseFee)
+    }
+}
+
+impl Cheatcode for setL1BlockNumberCall {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self { newL1BlockNumber } = self;
+        set_arbos_param(ccx, ArbosParam::L1BlockNumber, *newL1BlockNumber)
+    }
+}
+
+impl Cheatcode for setL2BaseFeeCall {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self { newL2BaseFee } = self;
+        set_arbos_param(ccx, ArbosParam::L2BaseFee, *newL2BaseFee)
+    }
+}
+
+impl Cheatcode for setArbOSVersionCall {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self { newArbOSVersion } = self;
+        set_arbos_param(ccx, ArbosParam::ArbosVersion, U256::from(*newArbOSVersion))
+    }
+}
+
+impl Cheatcode for setArbosParamCall {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self { name, value } = self;
+        let param = name.parse::<ArbosParam>().map_err(|err| fmt_err!("{err}"))?;
+        set_arbos_param(ccx, param, *value)
+    }
+}
+
+impl Cheatcode for setArbosParamsCall {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self { names, values } = self;
+        ensure!(names.len() == values.len(), "names and values must have the same length");
+        let params = names
+            .iter()
+            .zip(values)
+            .map(|(name, value)| Ok((name.parse::<ArbosParam>()?, *value)))
+            .collect::<std::result::Result<Vec<_>, String>>()
+            .map_err(|err| fmt_err!("{err}"))?;
+        set_arbos_params(ccx, &params)
+    }
+}
+
+/// Sets an ArbOS parameter in the journaled state.
+fn set_arbos_param(ccx: &mut CheatsCtxt, param: ArbosParam, value: U256) -> Result {
+    set_arbos_params(ccx, &[(param, value)])
+}
+
+/// Sets ArbOS parameters in the journaled state.
+///
+/// All the values are checked before any parameter is set, so that invalid values change nothing.
+fn set_arbos_params(ccx: &mut CheatsCtxt, params: &[(ArbosParam, U256)]) -> Result {
+    let mut stylus_params = None;
+    for &(param, value) in params {
+        match param {
+            ArbosParam::ArbosVersion => {
+                ensure!(value <= U256::from(u16::MAX), "ArbOS version must be less than 2^16");
+            }
+            ArbosParam::L2BaseFee => {
+                ensure!(value <= U256::from(u64::MAX), "base fee must be less than 2^64");
+            }
+            _ if param.storage_slot().is_none() => {
+                let stylus_params = match &mut stylus_params {
+                    Some(stylus_params) => stylus_params,
+                    None => stylus_params.insert(
+                        ccx.ecx
+                            .arb_state(None, false)
+                            .get()
+                            .map_err(|e| fmt_err!("failed to load ArbOS state: {e:?}"))?,
+                    ),
+                };
+                param.set_stylus_param(stylus_params, value).map_err(|err| fmt_err!("{err}"))?;
+            }
+            _ => {}
+        }
+    }
+
+    // The Stylus parameters are stored together, before the others so that they do not
+    // overwrite them.
+    if let Some(stylus_params) = stylus_params {
+        ccx.ecx
+            .arb_state(None, false)
+            .initialize(&stylus_params)
+            .map_err(|e| fmt_err!("failed to store ArbOS Stylus parameters: {e:?}"))?;
+    }
+
+    for &(param, value) in params {
+        match param {
+            // The ArbOS version also gates the features of the EVM.
+            ArbosParam::ArbosVersion => ccx.ecx.cfg.arbos_version = value.to(),
+            // The L2 base fee is the base fee of the block.
+            ArbosParam::L2BaseFee => ccx.ecx.block.basefee = value.to(),
+            _ => {}
+        }
+
+        if let Some(slot) = param.storage_slot() {
+            super::ensure_loaded_account(ccx.ecx, ARBOS_STATE_ADDRESS)?;
+            let (db, journal, _) = ccx.ecx.as_db_env_and_journal();
+            journal
+                .sstore(db, ARBOS_STATE_ADDRESS, slot, value, false)
+                .map_err(|e| fmt_err!("failed to store ArbOS parameter `{param}`: {e:?}"))?;
+        }
+    }
+
+    Ok(Default::default())
+}
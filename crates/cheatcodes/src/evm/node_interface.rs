@@ -0,0 +1,160 @@
+//! Emulation of the Arbitrum `NodeInterface` in tests and scripts.
+
+use crate::{Cheatcodes, CheatcodesExecutor, CheatsCtxt, Result, inspector::Ecx};
+use alloy_primitives::{Bytes, TxKind, U256};
+use foundry_evm_core::{
+    InspectorExt,
+    arbos::ARBOS_STATE_ADDRESS,
+    node_interface::{ArbosPricing, NodeInterfaceCall, NodeInterfaceMessage},
+};
+use revm::{
+    context::JournalTr,
+    inspector::NoOpInspector,
+    interpreter::{
+        CallInput, CallInputs, CallOutcome, CallScheme, CallValue, CreateInputs, Gas,
+        InstructionResult, InterpreterResult,
+    },
+};
+
+/// Answers a call to `NodeInterface` like a Nitro node answers `eth_call`.
+///
+/// Messages whose gas is needed are executed in a checkpoint which is reverted afterwards, and
+/// their gas is the gas they used. The call itself is charged like a precompile, and failing calls
+/// consume all of their gas.
+pub(crate) fn call_node_interface(
+    state: &mut Cheatcodes,
+    ecx: Ecx,
+    call: &CallInputs,
+) -> Option<CallOutcome> {
+    let input = call.input.bytes(ecx);
+    let mut gas = Gas::new(call.gas_limit);
+    let (result, output) = match answer(state, ecx, call, &input) {
+        Ok(Some(output)) => {
+            if gas.record_cost(NodeInterfaceCall::gas_used(&input, &output)) {
+                (InstructionResult::Return, output)
+            } else {
+                gas.spend_all();
+                (InstructionResult::PrecompileOOG, Bytes::new())
+            }
+        }
+        Ok(None) => return None,
+        Err(err) => {
+            gas.spend_all();
+            (InstructionResult::Revert, err.abi_encode().into())
+        }
+    };
+
+    Some(CallOutcome {
+        result: InterpreterResult { result, output, gas },
+        memory_offset: call.return_memory_offset.clone(),
+        was_precompile_called: true,
+        precompile_call_logs: vec![],
+    })
+}
+
+fn answer(
+    state: &mut Cheatcodes,
+    ecx: Ecx,
+    call: &CallInputs,
+    input: &[u8],
+) -> Result<Option<Bytes>> {
+    super::ensure_loaded_account(ecx, ARBOS_STATE_ADDRESS)?;
+    let pricing = ArbosPricing::load(|slot| {
+        let (db, journal, _) = ecx.as_db_env_and_journal();
+        journal.sload(db, ARBOS_STATE_ADDRESS, slot, false).map(|value| value.data)
+    })
+    .map_err(|e| fmt_err!("failed to load ArbOS pricing state: {e:?}"))?;
+    let base_fee = U256::from(ecx.block.basefee);
+
+    let Some(node_call) = NodeInterfaceCall::decode(
+        call.caller,
+        call.call_value(),
+        input,
+        &pricing,
+        base_fee,
+        ecx.cfg.chain_id,
+    ) else {
+        return Ok(None);
+    };
+    let mut ccx = CheatsCtxt { state, ecx, gas_limit: call.gas_limit, caller: call.caller };
+    let output = match node_call {
+        NodeInterfaceCall::Return(output) => output,
+        NodeInterfaceCall::GasEstimateComponents(components) => {
+            let gas = message_gas(&mut ccx, &components.message, U256::ZERO)?;
+            components.encode_returns(gas)
+        }
+        NodeInterfaceCall::EstimateRetryableTicket { message, deposit } => {
+            message_gas(&mut ccx, &message, deposit)?;
+            Bytes::new()
+        }
+    };
+    Ok(Some(output))
+}
+
+/// Executes messages outside of the test, so that cheatcodes, e.g. pranks or expected calls, do not
+/// apply to them.
+struct MessageExecutor;
+
+impl CheatcodesExecutor for MessageExecutor {
+    fn get_inspector<'a>(&'a mut self, _cheats: &'a mut Cheatcodes) -> Box<dyn InspectorExt + 'a> {
+        Box::new(NoOpInspector)
+    }
+}
+
+/// Executes `message` after minting `deposit` to its sender, reverts its changes, and returns the
+/// gas it used, intrinsic gas included.
+fn message_gas(ccx: &mut CheatsCtxt, message: &NodeInterfaceMessage, deposit: U256) -> Result<u64> {
+    let checkpoint = ccx.ecx.journaled_state.checkpoint();
+    let result = execute_message(ccx, message, deposit);
+    ccx.ecx.journaled_state.checkpoint_revert(checkpoint);
+
+    let (result, gas, output) = result?;
+    if !result.is_ok() {
+        bail!("message failed with {result:?}: {output}");
+    }
+    Ok(message.intrinsic_gas() + gas)
+}
+
+fn execute_message(
+    ccx: &mut CheatsCtxt,
+    message: &NodeInterfaceMessage,
+    deposit: U256,
+) -> Result<(InstructionResult, u64, Bytes)> {
+    let sender = super::journaled_account(ccx.ecx, message.from)?;
+    sender.info.balance = sender.info.balance.saturating_add(deposit);
+    let gas_limit = ccx.ecx.block.gas_limit;
+
+    Ok(match message.to {
+        TxKind::Call(to) => {
+            let outcome = MessageExecutor.exec_call(
+                CallInputs {
+                    input: CallInput::Bytes(message.data.clone()),
+                    return_memory_offset: 0..0,
+                    gas_limit,
+                    bytecode_address: to,
+                    target_address: to,
+                    caller: message.from,
+                    value: CallValue::Transfer(message.value),
+                    scheme: CallScheme::Call,
+                    is_static: false,
+                    known_bytecode: None,
+                },
+                ccx,
+            )?;
+            (outcome.result.result, outcome.result.gas.spent(), outcome.result.output)
+        }
+        TxKind::Create => {
+            let outcome = MessageExecutor.exec_create(
+                CreateInputs {
+                    caller: message.from,
+                    scheme: revm::context::CreateScheme::Create,
+                    value: message.value,
+                    init_code: message.data.clone(),
+                    gas_limit,
+                },
+                ccx,
+            )?;
+            (outcome.result.result, outcome.result.gas.spent(), outcome.result.output)
+        }
+    })
+}
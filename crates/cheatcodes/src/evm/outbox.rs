@@ -0,0 +1,80 @@
+//! Implementations of the Arbitrum L2→L1 message cheatcodes.
+
+use crate::{Cheatcode, Cheatcodes, CheatsCtxt, Result, Vm::*, inspector::Ecx};
+use alloy_sol_types::SolValue;
+use foundry_evm_core::{
+    arbos::JournaledArbosStorage,
+    outbox::{self, L2ToL1Outbox},
+};
+
+impl Cheatcode for getL2ToL1MessagesCall {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self {} = self;
+        let mut storage = JournaledArbosStorage::new(&mut ccx.ecx.journaled_state)?;
+        let messages = ccx.state.l2_to_l1_outbox.messages(&mut storage)?;
+        Ok(messages.into_iter().map(vm_message).collect::<Vec<_>>().abi_encode())
+    }
+}
+
+impl Cheatcode for getL2ToL1MessageProofCall {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self { index } = self;
+        let mut storage = JournaledArbosStorage::new(&mut ccx.ecx.journaled_state)?;
+        let proof = match usize::try_from(*index) {
+            Ok(index) => L2ToL1Outbox::proof(&mut storage, index)?,
+            Err(_) => None,
+        };
+        let Some((root, proof)) = proof else {
+            bail!(
+                "no L2 to L1 message at index {index}, only {} were sent",
+                ccx.state.l2_to_l1_outbox.messages(&mut storage)?.len()
+            );
+        };
+        Ok((root, proof).abi_encode_params())
+    }
+}
+
+/// Appends the L2 to L1 messages emitted in the journal from `logs_start`, by a call to `ArbSys`
+/// which just succeeded, to the send Merkle tree in the ArbOS state.
+pub(crate) fn append_l2_to_l1_messages(
+    state: &mut Cheatcodes,
+    ecx: Ecx,
+    logs_start: usize,
+) -> Result<()> {
+    let logs = ecx.journaled_state.logs.get(logs_start..).unwrap_or_default().to_vec();
+    if logs.is_empty() {
+        return Ok(());
+    }
+    let mut storage = JournaledArbosStorage::new(&mut ecx.journaled_state)?;
+    for log in &logs {
+        if let Some(message) = outbox::append_l2_to_l1_message(&mut storage, log)? {
+            state.l2_to_l1_outbox.insert(message);
+        }
+    }
+    Ok(())
+}
+
+fn vm_message(message: outbox::L2ToL1Message) -> L2ToL1Message {
+    let outbox::L2ToL1Message {
+        caller,
+        destination,
+        hash,
+        position,
+        arb_block_num,
+        eth_block_num,
+        timestamp,
+        callvalue,
+        data,
+    } = message;
+    L2ToL1Message {
+        caller,
+        destination,
+        hash,
+        position,
+        arbBlockNum: arb_block_num,
+        ethBlockNum: eth_block_num,
+        timestamp,
+        callvalue,
+        data,
+    }
+}
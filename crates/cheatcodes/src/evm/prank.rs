@@ -1,6 +1,14 @@
-use crate::{Cheatcode, CheatsCtxt, Result, Vm::*, evm::journaled_account};
+use crate::{
+    Cheatcode, Cheatcodes, CheatsCtxt, Result, Vm::*, evm::journaled_account, inspector::Ecx,
+};
 use alloy_primitives::Address;
-use revm::context::JournalTr;
+use alloy_sol_types::{SolInterface, SolValue};
+use foundry_evm_core::abi::arbitrum::ArbSys::ArbSysCalls;
+use foundry_evm_networks::arbitrum::apply_l1_to_l2_alias;
+use revm::{
+    context::JournalTr,
+    interpreter::{CallInputs, CallOutcome, Gas, InstructionResult, InterpreterResult},
+};
 
 /// Prank information.
 #[derive(Clone, Copy, Debug, Default)]
@@ -21,6 +29,8 @@ pub struct Prank {
     pub delegate_call: bool,
     /// Whether the prank has been used yet (false if unused)
     pub used: bool,
+    /// The L1 sender whose alias is `new_caller`, for pranks set with `prankFromL1`
+    pub l1_sender: Option<Address>,
 }
 
 impl Prank {
@@ -43,6 +53,7 @@ pub fn new(
             single_call,
             delegate_call,
             used: false,
+            l1_sender: None,
         }
     }
 
@@ -109,6 +120,17 @@ fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
     }
 }
 
+impl Cheatcode for prankFromL1Call {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self { l1Sender } = self;
+        let alias = apply_l1_to_l2_alias(*l1Sender);
+        let mut prank = new_prank(ccx, &alias, Some(&alias), true, false)?;
+        prank.l1_sender = Some(*l1Sender);
+        ccx.state.pranks.insert(prank.depth, prank);
+        Ok(Default::default())
+    }
+}
+
 impl Cheatcode for stopPrankCall {
     fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
         let Self {} = self;
@@ -124,6 +146,19 @@ fn prank(
     single_call: bool,
     delegate_call: bool,
 ) -> Result {
+    let prank = new_prank(ccx, new_caller, new_origin, single_call, delegate_call)?;
+    ccx.state.pranks.insert(prank.depth, prank);
+    Ok(Default::default())
+}
+
+/// Checks that a prank can be set at the current depth, and returns it.
+fn new_prank(
+    ccx: &mut CheatsCtxt,
+    new_caller: &Address,
+    new_origin: Option<&Address>,
+    single_call: bool,
+    delegate_call: bool,
+) -> Result<Prank> {
     // Ensure that we load the account of the pranked address and mark it as touched.
     // This is necessary to ensure that account state changes (such as the account's `nonce`) are
     // properly tracked.
@@ -165,6 +200,31 @@ fn prank(
          pass the desired `tx.origin` into the `broadcast` cheatcode call"
     );
 
-    ccx.state.pranks.insert(prank.depth, prank);
-    Ok(Default::default())
+    Ok(prank)
+}
+
+/// Answers the `ArbSys` aliasing queries of a contract called with `prankFromL1`, like Nitro
+/// answers them for the top level call of a message sent from L1.
+pub(crate) fn call_arb_sys(state: &Cheatcodes, ecx: Ecx, call: &CallInputs) -> Option<CallOutcome> {
+    // The queries concern the caller of the contract calling `ArbSys`, which was pranked one
+    // call above.
+    let depth = ecx.journaled_state.depth().checked_sub(1)?;
+    let prank = state.get_prank(depth).filter(|prank| prank.depth == depth && prank.used)?;
+    let l1_sender = prank.l1_sender?;
+
+    let output = match ArbSysCalls::abi_decode(&call.input.bytes(ecx)).ok()? {
+        ArbSysCalls::wasMyCallersAddressAliased(_) => true.abi_encode(),
+        ArbSysCalls::myCallersAddressWithoutAliasing(_) => l1_sender.abi_encode(),
+        _ => return None,
+    };
+    Some(CallOutcome {
+        result: InterpreterResult {
+            result: InstructionResult::Return,
+            output: output.into(),
+            gas: Gas::new(call.gas_limit),
+        },
+        memory_offset: call.return_memory_offset.clone(),
+        was_precompile_called: true,
+        precompile_call_logs: vec![],
+    })
 }
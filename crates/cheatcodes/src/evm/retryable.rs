@@ -0,0 +1,186 @@
+//! Implementations of the Arbitrum retryable ticket cheatcodes.
+
+use crate::{Cheatcode, Cheatcodes, CheatcodesExecutor, CheatsCtxt, Result, Vm::*, inspector::Ecx};
+use alloy_primitives::{Bytes, TxKind, U256};
+use alloy_sol_types::SolValue;
+use foundry_evm_core::{
+    arbos::JournaledArbosStorage,
+    retryables::{RetryableCall, RetryableCallEnv, RetryableState, retryable_escrow_address},
+    tx::arbitrum::{TxArbitrumRetry, TxArbitrumSubmitRetryable},
+};
+use foundry_evm_networks::arbitrum::apply_l1_to_l2_alias;
+use revm::{
+    context::JournalTr,
+    interpreter::{
+        CallInput, CallInputs, CallOutcome, CallScheme, CallValue, CreateInputs, Gas,
+        InstructionResult, InterpreterResult,
+    },
+};
+
+impl Cheatcode for submitRetryableCall {
+    fn apply_full(&self, ccx: &mut CheatsCtxt, executor: &mut dyn CheatcodesExecutor) -> Result {
+        let Self { l1Sender, to, l2CallValue, deposit, gasLimit, beneficiary, data } = self;
+        ensure!(deposit >= l2CallValue, "deposit must cover the L2 call value");
+
+        let tx = TxArbitrumSubmitRetryable {
+            chain_id: U256::from(ccx.ecx.cfg.chain_id),
+            request_id: ccx.state.retryables.next_request_id(),
+            from: apply_l1_to_l2_alias(*l1Sender),
+            l1_base_fee: U256::ZERO,
+            deposit_value: *deposit,
+            gas_fee_cap: U256::from(ccx.ecx.block.basefee),
+            gas: *gasLimit,
+            retry_to: TxKind::Call(*to),
+            retry_value: *l2CallValue,
+            beneficiary: *beneficiary,
+            max_submission_fee: U256::ZERO,
+            fee_refund_addr: *beneficiary,
+            retry_data: data.clone(),
+        };
+        let timestamp = ccx.ecx.block.timestamp.saturating_to();
+        let mut retryables =
+            RetryableState::new(JournaledArbosStorage::new(&mut ccx.ecx.journaled_state)?);
+        let (ticket, created) = retryables.create(&tx, timestamp)?;
+        let redeem = if *gasLimit != 0 {
+            Some(retryables.schedule_redeem(
+                &ticket,
+                tx.chain_id,
+                tx.gas_fee_cap,
+                *gasLimit,
+                tx.from,
+            )?)
+        } else {
+            None
+        };
+
+        // ArbOS mints the deposit to the aliased sender, and moves the call value to the escrow.
+        let sender = super::journaled_account(ccx.ecx, tx.from)?;
+        sender.info.balance = sender.info.balance.saturating_add(*deposit);
+        super::ensure_loaded_account(ccx.ecx, ticket.escrow())?;
+        if let Some(err) =
+            ccx.ecx.journaled_state.transfer(tx.from, ticket.escrow(), ticket.callvalue)?
+        {
+            bail!("failed to escrow the retryable call value: {err:?}");
+        }
+        ccx.ecx.journaled_state.log(created);
+
+        if let Some((redeem, scheduled)) = redeem {
+            ccx.ecx.journaled_state.log(scheduled);
+            redeem_retryable(ccx, executor, &redeem.retry)?;
+        }
+
+        Ok(ticket.id.abi_encode())
+    }
+}
+
+/// Handles a call to `ArbRetryableTx` concerning a retryable ticket.
+///
+/// Redeems are executed right away, as part of the call which scheduled them, with the gas the
+/// call donated to them.
+pub(crate) fn call_retryable_tx(
+    state: &mut Cheatcodes,
+    ecx: Ecx,
+    call: &CallInputs,
+    executor: &mut dyn CheatcodesExecutor,
+) -> Option<CallOutcome> {
+    let env = RetryableCallEnv {
+        chain_id: U256::from(ecx.cfg.chain_id),
+        timestamp: ecx.block.timestamp.saturating_to(),
+        basefee: U256::from(ecx.block.basefee),
+        gas_limit: call.gas_limit,
+    };
+    let input = call.input.bytes(ecx);
+    let outcome = state.retryables.call(&mut ecx.journaled_state, call.caller, &input, env);
+
+    let mut gas = Gas::new(call.gas_limit);
+    let (result, output) = match outcome {
+        Ok(None) => return None,
+        Ok(Some((outcome, gas_used))) => {
+            gas.record_cost(gas_used);
+            match outcome {
+                RetryableCall::Return(output) => (InstructionResult::Return, output),
+                RetryableCall::Revert(output) => (InstructionResult::Revert, output),
+                RetryableCall::OutOfGas => (InstructionResult::PrecompileOOG, Bytes::new()),
+                RetryableCall::Redeem(redeem) => {
+                    let mut ccx =
+                        CheatsCtxt { state, ecx, gas_limit: call.gas_limit, caller: call.caller };
+                    match redeem_retryable(&mut ccx, executor, &redeem.retry) {
+                        Ok(_) => {
+                            (InstructionResult::Return, redeem.retry_tx_hash().abi_encode().into())
+                        }
+                        Err(err) => (InstructionResult::Revert, err.abi_encode().into()),
+                    }
+                }
+            }
+        }
+        Err(err) => {
+            gas.spend_all();
+            (InstructionResult::Revert, crate::Error::from(err).abi_encode().into())
+        }
+    };
+
+    Some(CallOutcome {
+        result: InterpreterResult { result, output, gas },
+        memory_offset: call.return_memory_offset.clone(),
+        was_precompile_called: true,
+        precompile_call_logs: vec![],
+    })
+}
+
+/// Executes a redeem attempt of a ticket, and returns whether it succeeded.
+///
+/// The call value is taken out of the escrow for the attempt, and put back if it fails. The
+/// ticket is deleted once redeemed.
+fn redeem_retryable(
+    ccx: &mut CheatsCtxt,
+    executor: &mut dyn CheatcodesExecutor,
+    retry: &TxArbitrumRetry,
+) -> Result<bool> {
+    ccx.state.retryables.start_redeem(retry);
+    let escrow = retryable_escrow_address(retry.ticket_id);
+    super::ensure_loaded_account(ccx.ecx, retry.from)?;
+    let _ = ccx.ecx.journaled_state.transfer(escrow, retry.from, retry.value)?;
+
+    let success = match retry.to {
+        TxKind::Call(to) => executor
+            .exec_call(
+                CallInputs {
+                    input: CallInput::Bytes(retry.data.clone()),
+                    return_memory_offset: 0..0,
+                    gas_limit: retry.gas,
+                    bytecode_address: to,
+                    target_address: to,
+                    caller: retry.from,
+                    value: CallValue::Transfer(retry.value),
+                    scheme: CallScheme::Call,
+                    is_static: false,
+                    known_bytecode: None,
+                },
+                ccx,
+            )?
+            .result
+            .is_ok(),
+        TxKind::Create => executor
+            .exec_create(
+                CreateInputs {
+                    caller: retry.from,
+                    scheme: revm::context::CreateScheme::Create,
+                    value: retry.value,
+                    init_code: retry.data.clone(),
+                    gas_limit: retry.gas,
+                },
+                ccx,
+            )?
+            .result
+            .is_ok(),
+    };
+
+    ccx.state.retryables.end_redeem();
+    if success {
+        RetryableState::new(JournaledArbosStorage::new(&mut ccx.ecx.journaled_state)?)
+            .delete(retry.ticket_id)?;
+    } else {
+        let _ = ccx.ecx.journaled_state.transfer(retry.from, escrow, retry.value)?;
+    }
+    Ok(success)
+}
@@ -6,7 +6,9 @@
     evm::{
         DealRecord, GasRecord, RecordAccess, journaled_account,
         mock::{MockCallDataContext, MockCallReturnData},
-        prank::Prank,
+        node_interface, outbox,
+        prank::{self, Prank},
+        retryable,
     },
     inspector::utils::CommonCreateInput,
     script::{Broadcast, Wallets},
@@ -14,7 +16,8 @@
         assume::AssumeNoRevert,
         expect::{
             self, ExpectedCallData, ExpectedCallTracker, ExpectedCallType, ExpectedCreate,
-            ExpectedEmitTracker, ExpectedRevert, ExpectedRevertKind,
+            ExpectedEmitTracker, ExpectedL2ToL1Message, ExpectedRevert, ExpectedRevertKind,
+            ExpectedStylusTrap,
         },
         revert_handlers,
     },
@@ -41,7 +44,11 @@
     backend::{DatabaseError, DatabaseExt, RevertDiagnostic},
     constants::{CHEATCODE_ADDRESS, HARDHAT_CONSOLE_ADDRESS, MAGIC_ASSUME},
     evm::{FoundryEvm, new_evm_with_existing_context},
+    outbox::{L2ToL1Message, L2ToL1Outbox},
+    retryables::RetryableContext,
+    stylus::call_stylus_trap,
 };
+use foundry_evm_networks::arbitrum::{ARB_RETRYABLE_TX, ARB_SYS, NODE_INTERFACE};
 use foundry_evm_traces::{
     TracingInspector, TracingInspectorConfig, identifier::SignaturesIdentifier,
 };
@@ -468,6 +475,10 @@ pub struct Cheatcodes {
     pub expected_emits: ExpectedEmitTracker,
     /// Expected creates
     pub expected_creates: Vec<ExpectedCreate>,
+    /// Expected L2 to L1 messages
+    pub expected_l2_to_l1_messages: Vec<ExpectedL2ToL1Message>,
+    /// Expected Stylus trap
+    pub expected_stylus_trap: Option<ExpectedStylusTrap>,
 
     /// Map of context depths to memory offset ranges that may be written to within the call depth.
     pub allowed_mem_writes: HashMap<u64, Vec<Range<u64>>>,
@@ -498,6 +509,18 @@ pub struct Cheatcodes {
     /// All recorded ETH `deal`s.
     pub eth_deals: Vec<DealRecord>,
 
+    /// The retryable processing state which is not kept in the ArbOS state, like the ticket
+    /// being redeemed.
+    pub retryables: RetryableContext,
+
+    /// The contents of the L2 to L1 messages sent through `ArbSys`, whose hashes are appended
+    /// to the send Merkle tree in the ArbOS state.
+    pub l2_to_l1_outbox: L2ToL1Outbox,
+
+    /// The depth and the number of logs in the journal at the start of each pending call to
+    /// `ArbSys`.
+    pub arb_sys_calls: Vec<(usize, usize)>,
+
     /// Gas metering state.
     pub gas_metering: GasMetering,
 
@@ -574,6 +597,8 @@ pub fn new(config: Arc<CheatsConfig>) -> Self {
             expected_calls: Default::default(),
             expected_emits: Default::default(),
             expected_creates: Default::default(),
+            expected_l2_to_l1_messages: Default::default(),
+            expected_stylus_trap: Default::default(),
             allowed_mem_writes: Default::default(),
             broadcast: Default::default(),
             broadcastable_transactions: Default::default(),
@@ -581,6 +606,9 @@ pub fn new(config: Arc<CheatsConfig>) -> Self {
             test_context: Default::default(),
             serialized_jsons: Default::default(),
             eth_deals: Default::default(),
+            retryables: Default::default(),
+            l2_to_l1_outbox: Default::default(),
+            arb_sys_calls: Default::default(),
             gas_metering: Default::default(),
             gas_snapshots: Default::default(),
             mapping_slots: Default::default(),
@@ -886,6 +914,30 @@ pub fn call_with_executor(
             }
         }
 
+        // Answer calls to `ArbRetryableTx` concerning retryable tickets like ArbOS
+        if call.target_address == ARB_RETRYABLE_TX
+            && self.broadcast.is_none()
+            && let Some(outcome) = retryable::call_retryable_tx(self, ecx, call, executor)
+        {
+            return Some(outcome);
+        }
+
+        // Answer calls to the virtual `NodeInterface` contract like a Nitro node
+        if call.target_address == NODE_INTERFACE
+            && self.broadcast.is_none()
+            && let Some(outcome) = node_interface::call_node_interface(self, ecx, call)
+        {
+            return Some(outcome);
+        }
+
+        // Answer the `ArbSys` aliasing queries of calls pranked with `prankFromL1`
+        if call.target_address == ARB_SYS {
+            if let Some(outcome) = prank::call_arb_sys(self, ecx, call) {
+                return Some(outcome);
+            }
+            self.arb_sys_calls.push((curr_depth, ecx.journaled_state.logs.len()));
+        }
+
         // Apply EIP-2930 access list
         self.apply_accesslist(ecx);
 
@@ -1241,6 +1293,21 @@ fn call_end(&mut self, ecx: Ecx, call: &CallInputs, outcome: &mut CallOutcome) {
         let cheatcode_call = call.target_address == CHEATCODE_ADDRESS
             || call.target_address == HARDHAT_CONSOLE_ADDRESS;
 
+        // Append the L2 to L1 messages sent through `ArbSys` to the send Merkle tree, so they
+        // are journaled with the rest of the state.
+        if call.target_address == ARB_SYS
+            && let Some(&(depth, logs_start)) = self.arb_sys_calls.last()
+            && depth == ecx.journaled_state.depth()
+        {
+            self.arb_sys_calls.pop();
+            if outcome.result.is_ok()
+                && let Err(err) = outbox::append_l2_to_l1_messages(self, ecx, logs_start)
+            {
+                outcome.result.result = InstructionResult::Revert;
+                outcome.result.output = err.abi_encode().into();
+            }
+        }
+
         // Clean up pranks/broadcasts if it's not a cheatcode call end. We shouldn't do
         // it for cheatcode calls because they are not applied for cheatcodes in the `call` hook.
         // This should be placed before the revert handling, because we might exit early there
@@ -1381,6 +1448,39 @@ fn call_end(&mut self, ecx: Ecx, call: &CallInputs, outcome: &mut CallOutcome) {
             return;
         }
 
+        // Handle expected Stylus traps.
+        if let Some(expected) = &mut self.expected_stylus_trap
+            && let Some(trap) = call_stylus_trap(&mut ecx.journaled_state, call, &outcome.result)
+        {
+            expected.trap = Some(trap);
+        }
+        if let Some(expected) = &self.expected_stylus_trap
+            && ecx.journaled_state.depth() <= expected.depth
+        {
+            let expected = self.expected_stylus_trap.take().unwrap();
+            let error = match &expected.trap {
+                _ if outcome.result.is_ok() => {
+                    Some(format!("next call did not trap with `{}` as expected", expected.kind))
+                }
+                Some(trap) if trap.kind == expected.kind => None,
+                Some(trap) => Some(format!("expected Stylus trap `{}`, got {trap}", expected.kind)),
+                None => Some(format!(
+                    "next call failed without a Stylus trap, expected `{}`",
+                    expected.kind
+                )),
+            };
+            match error {
+                Some(msg) => {
+                    outcome.result.result = InstructionResult::Revert;
+                    outcome.result.output = Error::encode(msg);
+                }
+                None => {
+                    outcome.result.result = InstructionResult::Return;
+                    outcome.result.output = Bytes::new();
+                }
+            }
+        }
+
         // Record the gas usage of the call, this allows the `lastCallGas` cheatcode to
         // retrieve the gas usage of the last call.
         let gas = outcome.result.gas;
@@ -1555,6 +1655,26 @@ fn call_end(&mut self, ecx: Ecx, call: &CallInputs, outcome: &mut CallOutcome) {
             // If there's not a revert, we can continue on to run the last logic for expect*
             // cheatcodes.
 
+            // Match the expected L2 to L1 messages of the transaction. Logs of reverted calls have
+            // already been discarded from the journal.
+            for (index, log) in ecx.journaled_state.logs.iter().enumerate() {
+                let Some(message) = L2ToL1Message::from_log(log) else { continue };
+                if let Some(position) = self
+                    .expected_l2_to_l1_messages
+                    .iter()
+                    .position(|expected| index >= expected.log_index && expected.matches(&message))
+                {
+                    self.expected_l2_to_l1_messages.remove(position);
+                }
+            }
+            if let Some(expected) = self.expected_l2_to_l1_messages.first() {
+                let msg = format!("expected {expected}, but none was sent");
+                self.expected_l2_to_l1_messages.clear();
+                outcome.result.result = InstructionResult::Revert;
+                outcome.result.output = Error::encode(msg);
+                return;
+            }
+
             // Match expected calls
             for (address, calldatas) in &self.expected_calls {
                 // Loop over each address, and for each address, loop over each calldata it expects.
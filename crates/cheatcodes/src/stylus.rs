@@ -1,20 +1,33 @@
 use std::{fs, path::PathBuf};
 
-use alloy_primitives::{Address, Bytes, U256, address, hex};
-use alloy_sol_types::SolValue;
+use alloy_primitives::{Address, Bytes, TxKind, U256, address};
+use alloy_rpc_types::request::{TransactionInput, TransactionRequest};
+use alloy_sol_types::{SolCall, SolValue};
 use arbos_revm::{
     state::program::activate_program,
     stylus_executor::stylus_code,
-    utils::{Dictionary, brotli_compress, brotli_decompress, strip_wasm_for_stylus},
+    utils::{Dictionary, brotli_compress, brotli_decompress},
 };
+use foundry_common::stylus::stylus_artifact_path;
 use foundry_config::fs_permissions::FsAccessKind;
+use foundry_evm_core::{
+    abi::arbitrum::ArbWasm,
+    stylus::{
+        DATA_FEE_BUMP_PERCENT, bump_data_fee, stylus_constructor_calldata, stylus_init_code,
+        stylus_runtime_code,
+    },
+};
+use foundry_evm_networks::arbitrum::ARB_WASM;
 use revm::{
     context::{ContextTr, CreateScheme, JournalTr},
     interpreter::{CallInputs, CallScheme, CreateInputs},
 };
 use spec::Vm::*;
 
-use crate::{Cheatcode, Cheatcodes, CheatcodesExecutor, CheatsCtxt, Result};
+use crate::{
+    BroadcastableTransaction, Cheatcode, Cheatcodes, CheatcodesExecutor, CheatsCtxt, Result,
+    evm::journaled_account,
+};
 
 /// Default address of the StylusDeployer contract.
 const DEFAULT_STYLUS_DEPLOYER_ADDRESS: Address =
@@ -107,6 +120,9 @@ fn apply(&self, _state: &mut Cheatcodes) -> Result {
 /// Helper function to deploy stylus contract from artifact code.
 /// Matches StylusDeployer.sol behavior: deploys, activates via ARB_WASM precompile, then
 /// initializes. Uses CREATE2 scheme if salt specified.
+///
+/// While broadcasting, each step is recorded as a transaction from the broadcaster instead: the
+/// deployment, the `ArbWasm.activateProgram` call paying the data fee, and the constructor call.
 fn deploy_stylus_code(
     ccx: &mut CheatsCtxt,
     executor: &mut dyn CheatcodesExecutor,
@@ -116,7 +132,7 @@ fn deploy_stylus_code(
     salt: Option<U256>,
 ) -> Result {
     let compressed_bytecode = get_stylus_bytecode(ccx.state, path)?;
-    let init_code = get_init_code_of_empty_constructor(compressed_bytecode.to_vec());
+    let init_code = stylus_init_code(&compressed_bytecode);
 
     let scheme =
         if let Some(salt) = salt { CreateScheme::Create2 { salt } } else { CreateScheme::Create };
@@ -125,23 +141,30 @@ fn deploy_stylus_code(
     let create_value =
         if constructor_args.is_some() { U256::ZERO } else { value.unwrap_or(U256::ZERO) };
 
-    // Use the configured deployer address as the CREATE caller (matching StylusDeployer.sol)
-    let deployer_address = ccx
-        .state
-        .config
-        .evm_opts
-        .stylus_config
-        .deployer_address
-        .unwrap_or(DEFAULT_STYLUS_DEPLOYER_ADDRESS);
+    // If broadcasting from the cheatcode caller, deploy from it so the creation is broadcast.
+    let broadcaster = match &mut ccx.state.broadcast {
+        Some(broadcast) if ccx.caller == broadcast.original_caller => {
+            broadcast.deploy_from_code = true;
+            Some(broadcast.new_origin)
+        }
+        _ => None,
+    };
+
+    // Otherwise use the configured deployer address as the CREATE caller (matching
+    // StylusDeployer.sol)
+    let caller = if broadcaster.is_some() {
+        ccx.caller
+    } else {
+        ccx.state
+            .config
+            .evm_opts
+            .stylus_config
+            .deployer_address
+            .unwrap_or(DEFAULT_STYLUS_DEPLOYER_ADDRESS)
+    };
 
     let outcome = executor.exec_create(
-        CreateInputs {
-            caller: deployer_address,
-            scheme,
-            value: create_value,
-            init_code: init_code.into(),
-            gas_limit: ccx.gas_limit,
-        },
+        CreateInputs { caller, scheme, value: create_value, init_code, gas_limit: ccx.gas_limit },
         ccx,
     )?;
 
@@ -152,22 +175,33 @@ fn deploy_stylus_code(
     let address = outcome.address.ok_or_else(|| fmt_err!("contract creation failed"))?;
 
     // Activate the program
-    activate_stylus_program(ccx, address)?;
+    match broadcaster {
+        Some(broadcaster) => broadcast_stylus_activation(
+            ccx,
+            executor,
+            broadcaster,
+            address,
+            outcome.gas().remaining(),
+        )?,
+        None => activate_stylus_program(ccx, address)?,
+    }
 
     if let Some(constructor_args) = constructor_args {
-        // cast sig 'stylus_constructor()' => 0x5585258d
-        let mut calldata = vec![0x55, 0x85, 0x25, 0x8d];
-        calldata.extend_from_slice(constructor_args);
+        let calldata = stylus_constructor_calldata(constructor_args);
+        let value = value.unwrap_or(U256::ZERO);
+        if let Some(broadcaster) = broadcaster {
+            record_broadcast_call(ccx, broadcaster, address, calldata.clone(), value)?;
+        }
 
         let outcome = executor.exec_call(
             CallInputs {
-                input: revm::interpreter::CallInput::Bytes(calldata.into()),
+                input: revm::interpreter::CallInput::Bytes(calldata),
                 return_memory_offset: 0..0,
                 gas_limit: outcome.gas().remaining(),
                 bytecode_address: address,
                 target_address: address,
-                caller: ccx.caller,
-                value: revm::interpreter::CallValue::Transfer(value.unwrap_or(U256::ZERO)),
+                caller: broadcaster.unwrap_or(ccx.caller),
+                value: revm::interpreter::CallValue::Transfer(value),
                 scheme: CallScheme::Call,
                 is_static: false,
                 known_bytecode: None,
@@ -183,6 +217,88 @@ fn deploy_stylus_code(
     Ok(address.abi_encode())
 }
 
+/// Activates a Stylus program through `ArbWasm.activateProgram`, paying the data fee from the
+/// broadcaster, and records the call as a transaction.
+///
+/// The broadcaster's balance is sent along and the excess refunded, so the simulation charges the
+/// exact data fee while the transaction pays a bumped fee, in case it changes before inclusion.
+fn broadcast_stylus_activation(
+    ccx: &mut CheatsCtxt,
+    executor: &mut dyn CheatcodesExecutor,
+    broadcaster: Address,
+    program: Address,
+    gas_limit: u64,
+) -> Result<()> {
+    let calldata: Bytes = ArbWasm::activateProgramCall { program }.abi_encode().into();
+    let balance = journaled_account(ccx.ecx, broadcaster)?.info.balance;
+
+    let outcome = executor.exec_call(
+        CallInputs {
+            input: revm::interpreter::CallInput::Bytes(calldata.clone()),
+            return_memory_offset: 0..0,
+            gas_limit,
+            bytecode_address: ARB_WASM,
+            target_address: ARB_WASM,
+            caller: broadcaster,
+            value: revm::interpreter::CallValue::Transfer(balance),
+            scheme: CallScheme::Call,
+            is_static: false,
+            known_bytecode: None,
+        },
+        ccx,
+    )?;
+
+    if !outcome.result.result.is_ok() {
+        return Err(crate::Error::from(outcome.result.output));
+    }
+    let data_fee = ArbWasm::activateProgramCall::abi_decode_returns(&outcome.result.output)
+        .map_err(|e| fmt_err!("failed to decode activation result: {e}"))?
+        .dataFee;
+
+    record_broadcast_call(
+        ccx,
+        broadcaster,
+        ARB_WASM,
+        calldata,
+        bump_data_fee(data_fee, DATA_FEE_BUMP_PERCENT),
+    )
+}
+
+/// Records a call from the broadcaster as a transaction, like the `call` hook does for calls
+/// made by the script.
+fn record_broadcast_call(
+    ccx: &mut CheatsCtxt,
+    broadcaster: Address,
+    to: Address,
+    input: Bytes,
+    value: U256,
+) -> Result<()> {
+    let rpc = ccx.ecx.journaled_state.database.active_fork_url();
+    let chain_id = ccx.ecx.cfg.chain_id;
+    let account = journaled_account(ccx.ecx, broadcaster)?;
+    let nonce = account.info.nonce;
+
+    // Explicitly increment nonce if calls are not isolated.
+    if !ccx.state.config.evm_opts.isolate {
+        account.info.nonce += 1;
+    }
+
+    ccx.state.broadcastable_transactions.push_back(BroadcastableTransaction {
+        rpc,
+        transaction: TransactionRequest {
+            from: Some(broadcaster),
+            to: Some(TxKind::Call(to)),
+            value: Some(value),
+            input: TransactionInput::new(input),
+            nonce: Some(nonce),
+            chain_id: Some(chain_id),
+            ..Default::default()
+        }
+        .into(),
+    });
+    Ok(())
+}
+
 /// Activates a Stylus program by compiling and storing it directly.
 fn activate_stylus_program(ccx: &mut CheatsCtxt, program_address: Address) -> Result<()> {
     let code_hash = ccx
@@ -216,70 +332,41 @@ fn activate_stylus_program(ccx: &mut CheatsCtxt, program_address: Address) -> Re
 /// Can parse following input formats:
 /// - `path/to/artifact.wasm` - uncompressed WASM
 /// - `path/to/artifact.wasm.br` - pre-compressed WASM
+/// - `Name` - a program configured in `[stylus.programs]` and built by `forge build`
 ///
 /// This function returns raw bytecode suitable for use with external deployment contracts like
 /// StylusDeployer, or for wrapping in init code for direct CREATE/CREATE2 deployment.
 fn get_stylus_bytecode(state: &Cheatcodes, path: &str) -> Result<Bytes> {
     let path = if path.ends_with(".wasm") || path.ends_with(".wasm.br") {
-        PathBuf::from(path)
+        state.config.ensure_path_allowed(path, FsAccessKind::Read)?
     } else {
-        bail!("invalid artifact path")
+        get_stylus_program_path(state, path)?
     };
 
-    let path = state.config.ensure_path_allowed(path, FsAccessKind::Read)?;
     let artifact = fs::read(path)?;
-
-    // if it starts with WASM magic number, we assume it's a Stylus artifact and we first
-    // need to compress it and then prefix it with Stylus discriminant
-    let artifact = if artifact.starts_with(&[0x00, 0x61, 0x73, 0x6d]) {
-        // Strip user metadata and dangling reference types
-        let artifact = strip_wasm_for_stylus(&artifact)
-            .map_err(|e| fmt_err!("failed to strip WASM for Stylus: {}", e))?;
-
-        // Compress the artifact
-        brotli_compress(&artifact, 11, 22, Dictionary::Empty)
-            .map_err(|_| fmt_err!("failed to compress stylus artifact"))?
-    } else {
-        artifact
-    };
-
-    // If the artifact is already prefixed with Stylus discriminant, we can return it as is.
-    // Otherwise, we need to prefix it with Stylus discriminant and compression level.
-    let artifact = if artifact.starts_with(arbos_revm::constants::STYLUS_DISCRIMINANT) {
-        // If the artifact is already prefixed with Stylus discriminant, we can return it as is.
-        artifact
-    } else {
-        // If not, we need to prefix it with Stylus discriminant and compression level.
-        [arbos_revm::constants::STYLUS_DISCRIMINANT, &[0], artifact.as_ref()].concat()
-    };
-
-    Ok(Bytes::from(artifact))
+    Ok(stylus_runtime_code(artifact)?)
 }
 
-fn get_init_code_of_empty_constructor(bytecode: Vec<u8>) -> Vec<u8> {
-    let mut init = Vec::with_capacity(2 + bytecode.len() + 4);
-
-    // step 1: fixed header
-    init.extend_from_slice(hex!("608060405234801561001057600080fd5b50").as_slice());
-
-    // step 2: push the bytecode length (always 2 bytes)
-    init.extend_from_slice(&[0x61]);
-
-    let bytecode_len = bytecode.len();
-
-    // always returns at least 32 bytes
-    let bytecode_len_bytes = bytecode_len.to_be_bytes();
-
-    // can safely ignore the first 30 bytes as length is always less than 2^16
-    init.extend_from_slice(&bytecode_len_bytes[bytecode_len_bytes.len() - 2..]);
-
-    // step 3: fixed footer
-    init.extend_from_slice(hex!("806100206000396000f3fe").as_slice());
-
-    // push the bytecode itself
-    init.extend_from_slice(&bytecode);
+/// Returns the path of the compiled WASM binary of the Stylus program with the given name.
+///
+/// The program must be configured in `[stylus.programs]`; its artifact is produced by
+/// `forge build` or `forge test` in the Stylus artifacts directory.
+fn get_stylus_program_path(state: &Cheatcodes, name: &str) -> Result<PathBuf> {
+    if !state.config.evm_opts.stylus_config.programs.contains_key(name) {
+        bail!(
+            "invalid artifact path: expected a `.wasm`/`.wasm.br` file or a Stylus program \
+             configured in `[stylus.programs]`, found `{name}`"
+        );
+    }
 
-    init
+    let path = state.config.ensure_path_allowed(
+        stylus_artifact_path(&state.config.paths.artifacts, name),
+        FsAccessKind::Read,
+    )?;
+    if !path.exists() {
+        bail!("Stylus program `{name}` has not been built; run `forge build` first");
+    }
+    Ok(path)
 }
 
 /// Returns the compressed and prefixed Stylus bytecode (runtime code) for a contract.
@@ -291,11 +378,10 @@ fn get_stylus_code(state: &Cheatcodes, path: &str) -> Result {
 }
 
 /// Returns init code for a Stylus contract suitable for CREATE/CREATE2 or the StylusDeployer.
-/// Wraps the compressed bytecode in EVM init code using `get_init_code_of_empty_constructor`.
+/// Wraps the compressed bytecode in EVM init code using `stylus_init_code`.
 fn get_stylus_init_code(state: &Cheatcodes, path: &str) -> Result {
     let bytecode = get_stylus_bytecode(state, path)?;
-    let init_code = get_init_code_of_empty_constructor(bytecode.to_vec());
-    Ok(Bytes::from(init_code).abi_encode())
+    Ok(stylus_init_code(&bytecode).abi_encode())
 }
 
 /// Compresses the given data using Brotli compression.
@@ -11,6 +11,10 @@
     map::{AddressHashMap, HashMap, hash_map::Entry},
 };
 use foundry_common::{abi::get_indexed_event, fmt::format_token};
+use foundry_evm_core::{
+    outbox,
+    stylus::{self, StylusTrap},
+};
 use foundry_evm_traces::DecodedCallLog;
 use revm::{
     context::JournalTr,
@@ -128,6 +132,46 @@ pub struct ExpectedCreate {
     pub create_scheme: CreateScheme,
 }
 
+#[derive(Clone, Debug)]
+pub struct ExpectedL2ToL1Message {
+    /// The L1 account the message is sent to
+    pub destination: Address,
+    /// The value sent with the message, if checked
+    pub callvalue: Option<U256>,
+    /// The calldata of the L1 call
+    pub data: Bytes,
+    /// The number of logs emitted when the expectation was set. Only later messages match.
+    pub log_index: usize,
+}
+
+impl ExpectedL2ToL1Message {
+    pub fn matches(&self, message: &outbox::L2ToL1Message) -> bool {
+        self.destination == message.destination
+            && self.callvalue.is_none_or(|callvalue| callvalue == message.callvalue)
+            && self.data == message.data
+    }
+}
+
+impl Display for ExpectedL2ToL1Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        write!(f, "L2 to L1 message to {} with data {}", self.destination, self.data)?;
+        if let Some(callvalue) = self.callvalue {
+            write!(f, " and value {callvalue}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ExpectedStylusTrap {
+    /// The kind of trap expected
+    pub kind: stylus::StylusTrapKind,
+    /// The depth at which the trap is expected
+    pub depth: usize,
+    /// The last trap seen during the next call, if any
+    pub trap: Option<StylusTrap>,
+}
+
 #[derive(Clone, Debug)]
 pub enum CreateScheme {
     Create,
@@ -410,6 +454,38 @@ fn apply(&self, state: &mut Cheatcodes) -> Result {
     }
 }
 
+impl Cheatcode for expectL2ToL1Message_0Call {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self { destination, data } = self;
+        expect_l2_to_l1_message(ccx, *destination, None, data.clone())
+    }
+}
+
+impl Cheatcode for expectL2ToL1Message_1Call {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self { destination, callvalue, data } = self;
+        expect_l2_to_l1_message(ccx, *destination, Some(*callvalue), data.clone())
+    }
+}
+
+impl Cheatcode for expectStylusTrapCall {
+    fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
+        let Self { kind } = self;
+        let kind = match kind {
+            StylusTrapKind::OutOfInk => stylus::StylusTrapKind::OutOfInk,
+            StylusTrapKind::StackOverflow => stylus::StylusTrapKind::StackOverflow,
+            _ => bail!("invalid Stylus trap kind"),
+        };
+        ensure!(
+            ccx.state.expected_stylus_trap.is_none(),
+            "you must call another function prior to expecting a second Stylus trap"
+        );
+        let depth = ccx.ecx.journaled_state.depth();
+        ccx.state.expected_stylus_trap = Some(ExpectedStylusTrap { kind, depth, trap: None });
+        Ok(Default::default())
+    }
+}
+
 impl Cheatcode for expectRevert_0Call {
     fn apply_stateful(&self, ccx: &mut CheatsCtxt) -> Result {
         let Self {} = self;
@@ -1030,6 +1106,19 @@ fn expect_create(
     Ok(Default::default())
 }
 
+fn expect_l2_to_l1_message(
+    ccx: &mut CheatsCtxt,
+    destination: Address,
+    callvalue: Option<U256>,
+    data: Bytes,
+) -> Result {
+    let log_index = ccx.ecx.journaled_state.logs.len();
+    let expected = ExpectedL2ToL1Message { destination, callvalue, data, log_index };
+    ccx.state.expected_l2_to_l1_messages.push(expected);
+
+    Ok(Default::default())
+}
+
 fn expect_revert(
     state: &mut Cheatcodes,
     reason: Option<&[u8]>,
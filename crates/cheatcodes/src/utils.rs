@@ -10,6 +10,7 @@
 use foundry_config::fs_permissions::FsAccessKind;
 use foundry_evm_core::constants::DEFAULT_CREATE2_DEPLOYER;
 use foundry_evm_fuzz::strategies::BoundMutator;
+use foundry_evm_networks::arbitrum::{apply_l1_to_l2_alias, undo_l1_to_l2_alias};
 use proptest::prelude::Strategy;
 use rand::{Rng, RngCore, seq::SliceRandom};
 use revm::context::JournalTr;
@@ -69,6 +70,20 @@ fn apply(&self, _state: &mut Cheatcodes) -> Result {
     }
 }
 
+impl Cheatcode for applyL1ToL2AliasCall {
+    fn apply(&self, _state: &mut Cheatcodes) -> Result {
+        let Self { l1Address } = self;
+        Ok(apply_l1_to_l2_alias(*l1Address).abi_encode())
+    }
+}
+
+impl Cheatcode for undoL1ToL2AliasCall {
+    fn apply(&self, _state: &mut Cheatcodes) -> Result {
+        let Self { l2Address } = self;
+        Ok(undo_l1_to_l2_alias(*l2Address).abi_encode())
+    }
+}
+
 impl Cheatcode for ensNamehashCall {
     fn apply(&self, _state: &mut Cheatcodes) -> Result {
         let Self { name } = self;
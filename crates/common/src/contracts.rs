@@ -129,6 +129,15 @@ pub fn with_output(self, output: &ProjectCompileOutput, base: &Path) -> Self {
         }))
     }
 
+    /// Add artifacts which were not produced by the project compiler, e.g. Stylus programs.
+    pub fn with_artifacts(
+        mut self,
+        artifacts: impl IntoIterator<Item = (ArtifactId, CompactContractBytecodeCow<'a>)>,
+    ) -> Self {
+        self.artifacts.extend(artifacts);
+        self
+    }
+
     /// Add storage layouts.
     pub fn with_storage_layouts(
         mut self,
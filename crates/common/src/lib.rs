@@ -32,6 +32,7 @@
 pub mod selectors;
 pub mod serde_helpers;
 pub mod slot_identifier;
+pub mod stylus;
 pub mod term;
 pub mod traits;
 pub mod transactions;
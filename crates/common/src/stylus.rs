@@ -0,0 +1,527 @@
+//! Support for building Stylus programs from Rust crates.
+//!
+//! Programs are configured in the `[stylus.programs]` table of `foundry.toml` and compiled to
+//! `wasm32-unknown-unknown` with `cargo`. The resulting WASM binaries are written to
+//! `<out>/stylus/<name>.wasm` together with a small cache file keyed by a hash of the crate's
+//! sources, so unchanged programs are not rebuilt.
+//!
+//! The ABI of a program is read from an `abi.json` file in the crate root if present, otherwise it
+//! is exported with `cargo stylus export-abi --json`. It is stored next to the WASM binary as
+//! `<name>.abi.json` and used to decode calls to the program in traces.
+
+use crate::{fs, shell};
+use alloy_json_abi::JsonAbi;
+use alloy_primitives::{B256, Keccak256};
+use eyre::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::Command,
+    time::Instant,
+};
+
+/// Name of the directory, relative to the artifacts directory, where Stylus programs are written.
+pub const STYLUS_ARTIFACTS_DIR: &str = "stylus";
+
+/// Name of the cache file inside [`STYLUS_ARTIFACTS_DIR`].
+pub const STYLUS_CACHE_FILE: &str = "cache.json";
+
+/// Name of the optional ABI file in a Stylus crate's root directory.
+pub const STYLUS_CRATE_ABI_FILE: &str = "abi.json";
+
+/// The target triple Stylus programs are compiled for.
+pub const STYLUS_WASM_TARGET: &str = "wasm32-unknown-unknown";
+
+/// The name of the file cargo resolves dependencies with.
+const CARGO_LOCKFILE: &str = "Cargo.lock";
+
+/// Directories which are never part of a crate's source hash.
+const IGNORED_DIRS: &[&str] = &["target", ".git"];
+
+/// Directories which are never searched for prebuilt Stylus programs.
+const PREBUILT_IGNORED_DIRS: &[&str] =
+    &["target", ".git", "lib", "node_modules", "cache", STYLUS_ARTIFACTS_DIR];
+
+/// A Stylus program compiled from a Rust crate.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StylusArtifact {
+    /// The program name, as configured in `[stylus.programs]`.
+    pub name: String,
+    /// Path to the crate the program was built from.
+    pub crate_path: PathBuf,
+    /// Hash of the crate's sources at the time the program was built.
+    pub source_hash: B256,
+    /// Path to the compiled WASM binary.
+    pub wasm: PathBuf,
+    /// Path to the program's ABI, if one could be exported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abi: Option<PathBuf>,
+}
+
+impl StylusArtifact {
+    /// Reads the program's ABI, if any.
+    pub fn read_abi(&self) -> Result<Option<JsonAbi>> {
+        let Some(path) = &self.abi else { return Ok(None) };
+        Ok(Some(fs::read_json_file(path)?))
+    }
+}
+
+/// The cache of compiled Stylus programs, keyed by program name.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StylusArtifacts {
+    /// All known artifacts.
+    pub artifacts: BTreeMap<String, StylusArtifact>,
+}
+
+impl StylusArtifacts {
+    /// Reads the Stylus artifacts cache from the given artifacts directory.
+    ///
+    /// Returns an empty cache if no Stylus program has been built yet.
+    pub fn read(out: &Path) -> Result<Self> {
+        let path = stylus_cache_path(out);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(fs::read_json_file(&path)?)
+    }
+
+    /// Writes the Stylus artifacts cache to the given artifacts directory.
+    pub fn write(&self, out: &Path) -> Result<()> {
+        let path = stylus_cache_path(out);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write_pretty_json_file(&path, self)?;
+        Ok(())
+    }
+
+    /// Returns the artifact with the given program name.
+    pub fn get(&self, name: &str) -> Option<&StylusArtifact> {
+        self.artifacts.get(name)
+    }
+}
+
+/// Returns the path of the compiled WASM binary for the program with the given name.
+pub fn stylus_artifact_path(out: &Path, name: &str) -> PathBuf {
+    out.join(STYLUS_ARTIFACTS_DIR).join(format!("{name}.wasm"))
+}
+
+/// Returns the path of the ABI sidecar file for the given WASM binary, i.e. `<name>.abi.json`
+/// next to `<name>.wasm`.
+pub fn stylus_abi_path(wasm: &Path) -> PathBuf {
+    let name = wasm.file_name().unwrap_or_default().to_string_lossy();
+    let stem = name.strip_suffix(".br").unwrap_or(&name);
+    let stem = stem.strip_suffix(".wasm").unwrap_or(stem);
+    wasm.with_file_name(format!("{stem}.abi.json"))
+}
+
+/// Returns the path of the Stylus artifacts cache file.
+pub fn stylus_cache_path(out: &Path) -> PathBuf {
+    out.join(STYLUS_ARTIFACTS_DIR).join(STYLUS_CACHE_FILE)
+}
+
+/// Computes the hash of all source files in the crate at `crate_path`, and of the lockfile its
+/// dependencies are resolved with.
+///
+/// Files are hashed in a stable order together with their relative paths, ignoring build output
+/// and VCS directories.
+pub fn stylus_source_hash(crate_path: &Path) -> Result<B256> {
+    let mut hasher = Keccak256::new();
+    for path in stylus_source_files(crate_path) {
+        let relative = path.strip_prefix(crate_path).unwrap_or(&path);
+        hasher.update(relative.to_string_lossy().as_bytes());
+        hasher.update(fs::read(&path)?);
+    }
+    // The lockfile of a workspace member lives in the workspace root, outside of the crate.
+    if let Some(lockfile) = stylus_lockfile(crate_path)
+        && !lockfile.starts_with(crate_path)
+    {
+        hasher.update(CARGO_LOCKFILE.as_bytes());
+        hasher.update(fs::read(&lockfile)?);
+    }
+    Ok(hasher.finalize())
+}
+
+/// Returns the `Cargo.lock` used to build the crate at `crate_path`, i.e. the closest one in the
+/// crate's directory or its ancestors, which is the workspace root's for workspace members.
+pub fn stylus_lockfile(crate_path: &Path) -> Option<PathBuf> {
+    crate_path.ancestors().map(|dir| dir.join(CARGO_LOCKFILE)).find(|path| path.is_file())
+}
+
+/// Returns all source files in the crate at `crate_path`, sorted by path, ignoring build output
+/// and VCS directories.
+fn stylus_source_files(crate_path: &Path) -> Vec<PathBuf> {
+    walkdir::WalkDir::new(crate_path)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| {
+            !(e.file_type().is_dir()
+                && e.file_name().to_str().is_some_and(|name| IGNORED_DIRS.contains(&name)))
+        })
+        .filter_map(walkdir::Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .map(walkdir::DirEntry::into_path)
+        .collect()
+}
+
+/// Returns the prebuilt Stylus programs under `dirs`, i.e. all `.wasm` and `.wasm.br` files which
+/// have an ABI sidecar file next to them, see [`stylus_abi_path`].
+///
+/// Dependency, build output and VCS directories are not searched.
+pub fn prebuilt_stylus_programs<'a>(dirs: impl IntoIterator<Item = &'a Path>) -> Vec<PathBuf> {
+    let mut programs = Vec::new();
+    for dir in dirs {
+        if !dir.is_dir() {
+            continue;
+        }
+        let files = walkdir::WalkDir::new(dir)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|e| {
+                !(e.file_type().is_dir()
+                    && e.file_name()
+                        .to_str()
+                        .is_some_and(|name| PREBUILT_IGNORED_DIRS.contains(&name)))
+            })
+            .filter_map(walkdir::Result::ok)
+            .filter(|e| e.file_type().is_file())
+            .map(walkdir::DirEntry::into_path);
+        for path in files {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            if (name.ends_with(".wasm") || name.ends_with(".wasm.br"))
+                && stylus_abi_path(&path).is_file()
+                && !programs.contains(&path)
+            {
+                programs.push(path);
+            }
+        }
+    }
+    programs
+}
+
+/// Builder type to configure how to compile Stylus programs.
+#[derive(Clone, Debug, Default)]
+#[must_use = "StylusCompiler does nothing unless you call `compile`"]
+pub struct StylusCompiler {
+    /// Whether to print anything at all.
+    quiet: Option<bool>,
+    /// Whether to ignore the cache and always rebuild.
+    force: bool,
+}
+
+impl StylusCompiler {
+    /// Creates a new builder with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether to print anything at all.
+    #[inline]
+    pub fn quiet(mut self, yes: bool) -> Self {
+        self.quiet = Some(yes);
+        self
+    }
+
+    /// Sets whether to ignore the cache and always rebuild.
+    #[inline]
+    pub fn force(mut self, yes: bool) -> Self {
+        self.force = yes;
+        self
+    }
+
+    /// Compiles all `programs`, resolving crate paths relative to `root` and writing artifacts to
+    /// `out`.
+    ///
+    /// Programs whose sources did not change since the last build are not recompiled.
+    pub fn compile(
+        self,
+        root: &Path,
+        out: &Path,
+        programs: &BTreeMap<String, PathBuf>,
+    ) -> Result<StylusArtifacts> {
+        let quiet = self.quiet.unwrap_or_else(shell::is_quiet);
+        let mut cache = StylusArtifacts::read(out).unwrap_or_default();
+        cache.artifacts.retain(|name, _| programs.contains_key(name));
+
+        let mut compiled = 0;
+        let now = Instant::now();
+        for (name, crate_path) in programs {
+            let crate_path = root.join(crate_path);
+            let source_hash = stylus_source_hash(&crate_path)
+                .wrap_err_with(|| format!("failed to hash Stylus program `{name}`"))?;
+            let wasm = stylus_artifact_path(out, name);
+
+            let is_fresh = cache
+                .get(name)
+                .is_some_and(|cached| cached.source_hash == source_hash && cached.wasm.exists());
+            if is_fresh && !self.force {
+                trace!(%name, "Stylus program is up to date");
+                continue;
+            }
+
+            if !quiet {
+                sh_println!("Compiling Stylus program {name}")?;
+            }
+            let built = cargo_build_wasm(&crate_path)
+                .wrap_err_with(|| format!("failed to compile Stylus program `{name}`"))?;
+            fs::create_dir_all(wasm.parent().unwrap())?;
+            std::fs::copy(&built, &wasm).wrap_err_with(|| {
+                format!("failed to copy {} to {}", built.display(), wasm.display())
+            })?;
+
+            let abi = match export_abi(&crate_path) {
+                Ok(abi) => {
+                    let path = stylus_abi_path(&wasm);
+                    fs::write_pretty_json_file(&path, &abi)?;
+                    Some(path)
+                }
+                Err(err) => {
+                    if !quiet {
+                        sh_warn!("could not export ABI of Stylus program `{name}`: {err}")?;
+                    }
+                    None
+                }
+            };
+
+            cache.artifacts.insert(
+                name.clone(),
+                StylusArtifact { name: name.clone(), crate_path, source_hash, wasm, abi },
+            );
+            compiled += 1;
+        }
+
+        if !quiet && !programs.is_empty() {
+            if compiled == 0 {
+                sh_println!("No Stylus files changed, compilation skipped")?;
+            } else {
+                sh_println!(
+                    "Compiled {compiled} Stylus program{} in {:.2?}",
+                    if compiled == 1 { "" } else { "s" },
+                    now.elapsed()
+                )?;
+            }
+        }
+
+        cache.write(out)?;
+        Ok(cache)
+    }
+}
+
+/// Builds the crate at `crate_path` for [`STYLUS_WASM_TARGET`] and returns the path of the
+/// resulting WASM binary.
+fn cargo_build_wasm(crate_path: &Path) -> Result<PathBuf> {
+    let manifest = crate_path.join("Cargo.toml");
+    if !manifest.exists() {
+        eyre::bail!("no Cargo.toml found in {}", crate_path.display());
+    }
+
+    let output = Command::new("cargo")
+        .arg("build")
+        .args(["--release", "--lib", "--target", STYLUS_WASM_TARGET, "--manifest-path"])
+        .arg(&manifest)
+        .output()
+        .wrap_err("failed to run `cargo build`")?;
+    if !output.status.success() {
+        eyre::bail!("`cargo build` failed:\n{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+
+    let (target_dir, lib_name) = cargo_metadata(&manifest)?;
+    let wasm = target_dir.join(STYLUS_WASM_TARGET).join("release").join(format!("{lib_name}.wasm"));
+    if !wasm.exists() {
+        eyre::bail!(
+            "`cargo build` did not produce {}; make sure the crate has `crate-type = [\"cdylib\"]`",
+            wasm.display()
+        );
+    }
+    Ok(wasm)
+}
+
+/// Returns the ABI of the crate at `crate_path`.
+///
+/// Prefers an [`STYLUS_CRATE_ABI_FILE`] in the crate root and falls back to
+/// `cargo stylus export-abi --json`, which prints one JSON ABI per exported interface.
+fn export_abi(crate_path: &Path) -> Result<JsonAbi> {
+    let sidecar = crate_path.join(STYLUS_CRATE_ABI_FILE);
+    if sidecar.exists() {
+        return Ok(fs::read_json_file(&sidecar)?);
+    }
+
+    let output = Command::new("cargo")
+        .args(["stylus", "export-abi", "--json"])
+        .current_dir(crate_path)
+        .output()
+        .wrap_err("failed to run `cargo stylus export-abi`")?;
+    if !output.status.success() {
+        eyre::bail!(
+            "`cargo stylus export-abi` failed:\n{}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    parse_exported_abi(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parses the output of `cargo stylus export-abi --json`, merging all printed ABIs.
+fn parse_exported_abi(output: &str) -> Result<JsonAbi> {
+    let abis = output
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with('['))
+        .map(serde_json::from_str::<JsonAbi>)
+        .collect::<Result<Vec<_>, _>>()?;
+    if abis.is_empty() {
+        eyre::bail!("no ABI found in `cargo stylus export-abi` output");
+    }
+    Ok(abis.into_iter().flat_map(JsonAbi::into_items).collect())
+}
+
+/// Returns the target directory and the library target name of the crate with the given manifest.
+fn cargo_metadata(manifest: &Path) -> Result<(PathBuf, String)> {
+    let output = Command::new("cargo")
+        .args(["metadata", "--no-deps", "--format-version", "1", "--manifest-path"])
+        .arg(manifest)
+        .output()
+        .wrap_err("failed to run `cargo metadata`")?;
+    if !output.status.success() {
+        eyre::bail!("`cargo metadata` failed:\n{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+
+    let metadata: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let target_dir = metadata["target_directory"]
+        .as_str()
+        .map(PathBuf::from)
+        .ok_or_else(|| eyre::eyre!("missing `target_directory` in cargo metadata"))?;
+
+    let manifest = dunce::canonicalize(manifest).unwrap_or_else(|_| manifest.to_path_buf());
+    let lib_name = metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|package| {
+            package["manifest_path"]
+                .as_str()
+                .is_some_and(|path| dunce::canonicalize(path).ok().as_ref() == Some(&manifest))
+        })
+        .flat_map(|package| package["targets"].as_array().into_iter().flatten())
+        .find(|target| {
+            target["kind"]
+                .as_array()
+                .is_some_and(|kinds| kinds.iter().any(|k| k == "cdylib" || k == "lib"))
+        })
+        .and_then(|target| target["name"].as_str())
+        .map(|name| name.replace('-', "_"))
+        .ok_or_else(|| eyre::eyre!("no library target found in {}", manifest.display()))?;
+
+    Ok((target_dir, lib_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_hash_ignores_target_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("Cargo.toml"), "[package]\nname = \"counter\"").unwrap();
+        std::fs::write(root.join("src/lib.rs"), "pub fn number() {}").unwrap();
+
+        let hash = stylus_source_hash(root).unwrap();
+
+        std::fs::create_dir_all(root.join("target/release")).unwrap();
+        std::fs::write(root.join("target/release/counter.wasm"), [0u8; 4]).unwrap();
+        assert_eq!(stylus_source_hash(root).unwrap(), hash);
+
+        std::fs::write(root.join("src/lib.rs"), "pub fn increment() {}").unwrap();
+        assert_ne!(stylus_source_hash(root).unwrap(), hash);
+    }
+
+    #[test]
+    fn source_hash_includes_workspace_lockfile() {
+        let dir = tempfile::tempdir().unwrap();
+        let workspace = dir.path();
+        let root = workspace.join("counter");
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("Cargo.toml"), "[package]\nname = \"counter\"").unwrap();
+        std::fs::write(root.join("src/lib.rs"), "pub fn number() {}").unwrap();
+        std::fs::write(workspace.join("Cargo.lock"), "version = 4").unwrap();
+        assert_eq!(stylus_lockfile(&root), Some(workspace.join("Cargo.lock")));
+
+        let hash = stylus_source_hash(&root).unwrap();
+        std::fs::write(workspace.join("Cargo.lock"), "version = 4\n[[package]]").unwrap();
+        assert_ne!(stylus_source_hash(&root).unwrap(), hash);
+    }
+
+    #[test]
+    fn parses_exported_abi() {
+        let output = r#"
+======= <stdin>:ICounter =======
+Contract JSON ABI
+[{"type":"function","name":"number","inputs":[],"outputs":[{"name":"","type":"uint256","internalType":"uint256"}],"stateMutability":"view"}]
+
+======= <stdin>:IErrors =======
+Contract JSON ABI
+[{"type":"error","name":"Unauthorized","inputs":[]}]
+"#;
+        let abi = parse_exported_abi(output).unwrap();
+        assert!(abi.function("number").is_some());
+        assert!(abi.errors.contains_key("Unauthorized"));
+
+        assert!(parse_exported_abi("interface ICounter {}").is_err());
+    }
+
+    #[test]
+    fn abi_path_next_to_wasm() {
+        assert_eq!(
+            stylus_abi_path(Path::new("out/stylus/Counter.wasm")),
+            PathBuf::from("out/stylus/Counter.abi.json")
+        );
+        assert_eq!(
+            stylus_abi_path(Path::new("fixtures/counter.wasm.br")),
+            PathBuf::from("fixtures/counter.abi.json")
+        );
+    }
+
+    #[test]
+    fn finds_prebuilt_programs_with_abi() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("fixtures")).unwrap();
+        std::fs::create_dir_all(root.join("lib/dep")).unwrap();
+        std::fs::write(root.join("fixtures/counter.wasm.br"), [0u8; 4]).unwrap();
+        std::fs::write(root.join("fixtures/counter.abi.json"), "[]").unwrap();
+        std::fs::write(root.join("fixtures/no_abi.wasm"), [0u8; 4]).unwrap();
+        std::fs::write(root.join("lib/dep/dep.wasm"), [0u8; 4]).unwrap();
+        std::fs::write(root.join("lib/dep/dep.abi.json"), "[]").unwrap();
+
+        let fixtures = root.join("fixtures");
+        assert_eq!(
+            prebuilt_stylus_programs([root, fixtures.as_path()]),
+            vec![root.join("fixtures/counter.wasm.br")]
+        );
+    }
+
+    #[test]
+    fn artifacts_cache_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path();
+        assert_eq!(StylusArtifacts::read(out).unwrap(), StylusArtifacts::default());
+
+        let mut artifacts = StylusArtifacts::default();
+        artifacts.artifacts.insert(
+            "Counter".to_string(),
+            StylusArtifact {
+                name: "Counter".to_string(),
+                crate_path: PathBuf::from("stylus/counter"),
+                source_hash: B256::repeat_byte(1),
+                wasm: stylus_artifact_path(out, "Counter"),
+                abi: None,
+            },
+        );
+        artifacts.write(out).unwrap();
+        assert_eq!(StylusArtifacts::read(out).unwrap(), artifacts);
+    }
+}
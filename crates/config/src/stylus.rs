@@ -1,6 +1,7 @@
 use alloy_primitives::Address;
 use clap::Parser;
 use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, path::PathBuf};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Parser)]
 #[command(next_help_heading = "Stylus options")]
@@ -10,6 +11,14 @@ pub struct StylusConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub arbos_version: Option<u16>,
 
+    /// ArbOS versions to run every test suite against, overriding `arbos_version`.
+    ///
+    /// The results are reported once per version, and the tests whose outcome or gas differ
+    /// between versions are highlighted.
+    #[arg(long = "arbos-versions", value_name = "ARBOS_VERSIONS", value_delimiter = ',')]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arbos_versions: Vec<u16>,
+
     /// Stylus version to use for stylus programs.
     #[arg(long = "stylus-version", value_name = "STYLUS_VERSION")]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -110,6 +119,15 @@ pub struct StylusConfig {
     #[arg(long = "stylus-disable-deployment")]
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub disable_stylus_deployment: bool,
+
+    /// Stylus programs built from Rust crates, keyed by program name.
+    ///
+    /// Each entry points to a crate directory, relative to the project root, which is compiled to
+    /// `wasm32-unknown-unknown` by `forge build` and `forge test`. Built programs can be referenced
+    /// by name in `vm.deployStylusCode`, e.g. `vm.deployStylusCode("Counter")`.
+    #[arg(skip)]
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub programs: BTreeMap<String, PathBuf>,
 }
 
 impl StylusConfig {
@@ -0,0 +1,344 @@
+//! ArbOS state parameters.
+//!
+//! ArbOS keeps its state in the storage of [`ARBOS_STATE_ADDRESS`], laid out as in Nitro: nested
+//! storage spaces are derived by hashing their parent's key with their id, and each value lives at
+//! a fixed offset of its space. Stylus parameters are packed together, and are changed through
+//! [`ArbosStateParams`] instead.
+
+use alloy_primitives::{Address, B256, U256, address, keccak256};
+use arbos_revm::state::ArbosStateParams;
+use revm::{Database, context::JournalTr};
+use std::{fmt, str::FromStr};
+
+/// The account holding the ArbOS state.
+pub const ARBOS_STATE_ADDRESS: Address = address!("0xA4B05FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+
+/// The storage space of the L1 pricing state.
+const L1_PRICING_SPACE: u8 = 0;
+/// The storage space of the L2 pricing state.
+const L2_PRICING_SPACE: u8 = 1;
+/// The storage space of the recent block hashes, and of the L1 block number.
+const BLOCKHASHES_SPACE: u8 = 6;
+
+/// The gas ArbOS charges to read a storage value.
+pub const STORAGE_READ_COST: u64 = 800;
+/// The gas ArbOS charges to write a non-zero storage value.
+pub const STORAGE_WRITE_COST: u64 = 20_000;
+/// The gas ArbOS charges to clear a storage value.
+pub const STORAGE_WRITE_ZERO_COST: u64 = 5_000;
+
+/// Returns the storage slot of the value at `offset` in the storage space at `path`.
+///
+/// The root space has an empty path.
+pub fn arbos_storage_slot(path: &[u8], offset: u64) -> U256 {
+    ArbosSpace::new(path).slot(offset)
+}
+
+/// A storage space of the ArbOS state.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ArbosSpace {
+    key: Vec<u8>,
+}
+
+impl ArbosSpace {
+    /// Returns the space at `path`, where each id opens a subspace of the previous space.
+    pub fn new(path: &[u8]) -> Self {
+        path.iter().fold(Self::default(), |space, id| space.subspace(&[*id]))
+    }
+
+    /// Returns the subspace with the given id, which may be any byte string, e.g. a hash.
+    pub fn subspace(&self, id: &[u8]) -> Self {
+        Self { key: keccak256([self.key.as_slice(), id].concat()).to_vec() }
+    }
+
+    /// Returns the storage slot of the value at `offset`.
+    pub fn slot(&self, offset: u64) -> U256 {
+        let offset = B256::from(U256::from(offset));
+        let mut slot = keccak256([self.key.as_slice(), &offset[..31]].concat());
+        slot[31] = offset[31];
+        slot.into()
+    }
+}
+
+/// Access to the storage of [`ARBOS_STATE_ADDRESS`].
+pub trait ArbosStorage {
+    type Error;
+
+    /// Reads the value at `slot`.
+    fn sload(&mut self, slot: U256) -> Result<U256, Self::Error>;
+
+    /// Writes `value` at `slot`.
+    fn sstore(&mut self, slot: U256, value: U256) -> Result<(), Self::Error>;
+}
+
+/// [`ArbosStorage`] in the journaled state, which keeps track of the gas ArbOS charges for each
+/// access.
+#[derive(Debug)]
+pub struct JournaledArbosStorage<'a, J> {
+    pub journal: &'a mut J,
+    /// The gas charged for the accesses so far.
+    pub gas_used: u64,
+}
+
+impl<'a, J: JournalTr> JournaledArbosStorage<'a, J> {
+    /// Loads the ArbOS state account in `journal`.
+    pub fn new(journal: &'a mut J) -> Result<Self, <J::Database as Database>::Error> {
+        journal.load_account(ARBOS_STATE_ADDRESS)?;
+        journal.touch_account(ARBOS_STATE_ADDRESS);
+        Ok(Self { journal, gas_used: 0 })
+    }
+}
+
+impl<J: JournalTr> ArbosStorage for JournaledArbosStorage<'_, J> {
+    type Error = <J::Database as Database>::Error;
+
+    fn sload(&mut self, slot: U256) -> Result<U256, Self::Error> {
+        self.gas_used += STORAGE_READ_COST;
+        Ok(self.journal.sload(ARBOS_STATE_ADDRESS, slot)?.data)
+    }
+
+    fn sstore(&mut self, slot: U256, value: U256) -> Result<(), Self::Error> {
+        self.gas_used += if value.is_zero() { STORAGE_WRITE_ZERO_COST } else { STORAGE_WRITE_COST };
+        self.journal.sstore(ARBOS_STATE_ADDRESS, slot, value)?;
+        Ok(())
+    }
+}
+
+/// [`ArbosStorage`] in memory, for tests.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct MemoryStorage(alloy_primitives::map::HashMap<U256, U256>);
+
+#[cfg(test)]
+impl ArbosStorage for MemoryStorage {
+    type Error = std::convert::Infallible;
+
+    fn sload(&mut self, slot: U256) -> Result<U256, Self::Error> {
+        Ok(self.0.get(&slot).copied().unwrap_or_default())
+    }
+
+    fn sstore(&mut self, slot: U256, value: U256) -> Result<(), Self::Error> {
+        self.0.insert(slot, value);
+        Ok(())
+    }
+}
+
+/// An ArbOS parameter which can be changed during execution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArbosParam {
+    /// The ArbOS version.
+    ArbosVersion,
+    /// The L1 base fee estimate, in wei, used to price L1 calldata.
+    L1BaseFee,
+    /// The inertia of the L1 base fee estimate.
+    L1BaseFeeInertia,
+    /// The L1 gas charged for each posted batch.
+    L1PerBatchGasCost,
+    /// The L1 block number tracked by ArbOS.
+    L1BlockNumber,
+    /// The L2 base fee, in wei.
+    L2BaseFee,
+    /// The minimum L2 base fee, in wei.
+    L2MinBaseFee,
+    /// The L2 gas per second the chain targets.
+    L2SpeedLimit,
+    /// The L2 gas limit of a block.
+    L2PerBlockGasLimit,
+    /// The L2 gas limit of a transaction.
+    L2PerTxGasLimit,
+    /// The brotli compression level used to price L1 calldata.
+    BrotliCompressionLevel,
+    /// The Stylus version.
+    StylusVersion,
+    /// The amount of ink 1 gas buys.
+    InkPrice,
+    /// The maximum depth of a Stylus program's stack.
+    MaxStackDepth,
+    /// The number of free WASM pages a Stylus program gets.
+    FreePages,
+    /// The base gas cost of a WASM page.
+    PageGas,
+    /// The ramp of the WASM page gas cost.
+    PageRamp,
+    /// The maximum number of WASM pages a Stylus program may allocate.
+    PageLimit,
+    /// The minimum gas to initialize a Stylus program.
+    MinInitGas,
+    /// The minimum gas to initialize a cached Stylus program.
+    MinCachedInitGas,
+    /// The scalar of the gas to initialize a Stylus program.
+    InitCostScalar,
+    /// The scalar of the gas to initialize a cached Stylus program.
+    CachedCostScalar,
+    /// The number of days after which a Stylus program expires.
+    ExpiryDays,
+    /// The number of days a Stylus program must be kept alive for.
+    KeepaliveDays,
+    /// The number of Stylus programs cached per block.
+    BlockCacheSize,
+    /// The maximum size of an uncompressed WASM module.
+    MaxWasmSize,
+}
+
+impl ArbosParam {
+    /// All the parameters.
+    pub const ALL: &[Self] = &[
+        Self::ArbosVersion,
+        Self::L1BaseFee,
+        Self::L1BaseFeeInertia,
+        Self::L1PerBatchGasCost,
+        Self::L1BlockNumber,
+        Self::L2BaseFee,
+        Self::L2MinBaseFee,
+        Self::L2SpeedLimit,
+        Self::L2PerBlockGasLimit,
+        Self::L2PerTxGasLimit,
+        Self::BrotliCompressionLevel,
+        Self::StylusVersion,
+        Self::InkPrice,
+        Self::MaxStackDepth,
+        Self::FreePages,
+        Self::PageGas,
+        Self::PageRamp,
+        Self::PageLimit,
+        Self::MinInitGas,
+        Self::MinCachedInitGas,
+        Self::InitCostScalar,
+        Self::CachedCostScalar,
+        Self::ExpiryDays,
+        Self::KeepaliveDays,
+        Self::BlockCacheSize,
+        Self::MaxWasmSize,
+    ];
+
+    /// Returns the name of the parameter.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::ArbosVersion => "arbos_version",
+            Self::L1BaseFee => "l1_base_fee",
+            Self::L1BaseFeeInertia => "l1_base_fee_inertia",
+            Self::L1PerBatchGasCost => "l1_per_batch_gas_cost",
+            Self::L1BlockNumber => "l1_block_number",
+            Self::L2BaseFee => "l2_base_fee",
+            Self::L2MinBaseFee => "l2_min_base_fee",
+            Self::L2SpeedLimit => "l2_speed_limit",
+            Self::L2PerBlockGasLimit => "l2_per_block_gas_limit",
+            Self::L2PerTxGasLimit => "l2_per_tx_gas_limit",
+            Self::BrotliCompressionLevel => "brotli_compression_level",
+            Self::StylusVersion => "stylus_version",
+            Self::InkPrice => "ink_price",
+            Self::MaxStackDepth => "max_stack_depth",
+            Self::FreePages => "free_pages",
+            Self::PageGas => "page_gas",
+            Self::PageRamp => "page_ramp",
+            Self::PageLimit => "page_limit",
+            Self::MinInitGas => "min_init_gas",
+            Self::MinCachedInitGas => "min_cached_init_gas",
+            Self::InitCostScalar => "init_cost_scalar",
+            Self::CachedCostScalar => "cached_cost_scalar",
+            Self::ExpiryDays => "expiry_days",
+            Self::KeepaliveDays => "keepalive_days",
+            Self::BlockCacheSize => "block_cache_size",
+            Self::MaxWasmSize => "max_wasm_size",
+        }
+    }
+
+    /// Returns the storage slot of the parameter in [`ARBOS_STATE_ADDRESS`], or `None` for Stylus
+    /// parameters, which are set with [`set_stylus_param`](Self::set_stylus_param).
+    pub fn storage_slot(self) -> Option<U256> {
+        let (path, offset): (&[u8], u64) = match self {
+            Self::ArbosVersion => (&[], 0),
+            Self::L1BaseFee => (&[L1_PRICING_SPACE], 7),
+            Self::L1BaseFeeInertia => (&[L1_PRICING_SPACE], 2),
+            Self::L1PerBatchGasCost => (&[L1_PRICING_SPACE], 9),
+            Self::L1BlockNumber => (&[BLOCKHASHES_SPACE], 0),
+            Self::L2BaseFee => (&[L2_PRICING_SPACE], 2),
+            Self::L2MinBaseFee => (&[L2_PRICING_SPACE], 3),
+            Self::L2SpeedLimit => (&[L2_PRICING_SPACE], 0),
+            Self::L2PerBlockGasLimit => (&[L2_PRICING_SPACE], 1),
+            Self::L2PerTxGasLimit => (&[L2_PRICING_SPACE], 7),
+            Self::BrotliCompressionLevel => (&[], 7),
+            _ => return None,
+        };
+        Some(arbos_storage_slot(path, offset))
+    }
+
+    /// Sets a Stylus parameter in `params`.
+    ///
+    /// Returns an error if the value does not fit the parameter, or if this is not a Stylus
+    /// parameter.
+    pub fn set_stylus_param(
+        self,
+        params: &mut ArbosStateParams,
+        value: U256,
+    ) -> Result<(), String> {
+        fn convert<T: TryFrom<U256>>(param: ArbosParam, value: U256) -> Result<T, String> {
+            T::try_from(value).map_err(|_| format!("{value} is out of range for `{param}`"))
+        }
+
+        let stylus = &mut params.stylus_params;
+        match self {
+            Self::StylusVersion => stylus.version = convert(self, value)?,
+            Self::InkPrice => stylus.ink_price = convert(self, value)?,
+            Self::MaxStackDepth => stylus.max_stack_depth = convert(self, value)?,
+            Self::FreePages => stylus.free_pages = convert(self, value)?,
+            Self::PageGas => stylus.page_gas = convert(self, value)?,
+            Self::PageRamp => stylus.page_ramp = convert(self, value)?,
+            Self::PageLimit => stylus.page_limit = convert(self, value)?,
+            Self::MinInitGas => stylus.min_init_gas = convert(self, value)?,
+            Self::MinCachedInitGas => stylus.min_cached_init_gas = convert(self, value)?,
+            Self::InitCostScalar => stylus.init_cost_scalar = convert(self, value)?,
+            Self::CachedCostScalar => stylus.cached_cost_scalar = convert(self, value)?,
+            Self::ExpiryDays => stylus.expiry_days = convert(self, value)?,
+            Self::KeepaliveDays => stylus.keepalive_days = convert(self, value)?,
+            Self::BlockCacheSize => stylus.block_cache_size = convert(self, value)?,
+            Self::MaxWasmSize => stylus.max_wasm_size = convert(self, value)?,
+            _ => return Err(format!("`{self}` is not a Stylus parameter")),
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ArbosParam {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ArbosParam {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.iter().copied().find(|param| param.name() == s).ok_or_else(|| {
+            let names = Self::ALL.iter().map(|param| param.name()).collect::<Vec<_>>();
+            format!("unknown ArbOS parameter `{s}`, expected one of: {}", names.join(", "))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arbos_storage_slots() {
+        // The ArbOS version lives at offset 0 of the root space.
+        let mut expected = keccak256([0u8; 31]);
+        expected[31] = 0;
+        assert_eq!(ArbosParam::ArbosVersion.storage_slot(), Some(expected.into()));
+
+        let space = keccak256([L2_PRICING_SPACE]);
+        let mut expected = keccak256([space.as_slice(), &[0u8; 31]].concat());
+        expected[31] = 2;
+        assert_eq!(ArbosParam::L2BaseFee.storage_slot(), Some(expected.into()));
+        assert_eq!(ArbosParam::InkPrice.storage_slot(), None);
+    }
+
+    #[test]
+    fn parse_arbos_params() {
+        for param in ArbosParam::ALL {
+            assert_eq!(param.name().parse::<ArbosParam>(), Ok(*param));
+        }
+        assert!("l1BaseFee".parse::<ArbosParam>().is_err());
+    }
+}
@@ -37,6 +37,7 @@ pub mod abi {
 
 use foundry_evm_networks::NetworkConfigs;
 
+pub mod arbos;
 pub mod backend;
 pub mod buffer;
 pub mod bytecode;
@@ -46,9 +47,13 @@ pub mod abi {
 pub mod evm;
 pub mod fork;
 pub mod ic;
+pub mod node_interface;
 pub mod opts;
+pub mod outbox;
 pub mod precompiles;
+pub mod retryables;
 pub mod state_snapshot;
+pub mod stylus;
 pub mod utils;
 
 /// An extension trait that allows us to add additional hooks to Inspector for later use in
@@ -0,0 +1,365 @@
+//! Arbitrum `NodeInterface` emulation.
+//!
+//! `NodeInterface` is a virtual contract at [`NODE_INTERFACE`]: Nitro nodes answer calls to it in
+//! `eth_call` and `eth_estimateGas` instead of executing them. Calls are decoded with
+//! [`NodeInterfaceCall::decode`] and priced with the ArbOS state in [`ArbosPricing`]. Methods
+//! which need the gas of another message are handed back to the caller, which owns the executor.
+
+use crate::{
+    arbos::{ArbosParam, STORAGE_READ_COST},
+    retryables::copy_gas,
+};
+use alloy_chains::NamedChain;
+use alloy_consensus::{SignableTransaction, TxEip1559};
+use alloy_eips::Encodable2718;
+use alloy_primitives::{Address, Bytes, Signature, TxKind, U256, address, keccak256};
+use alloy_sol_types::{SolCall, SolInterface};
+use arbos_revm::utils::{Dictionary, brotli_compress};
+use foundry_evm_abi::arbitrum::NodeInterface::{self, NodeInterfaceCalls};
+use foundry_evm_networks::arbitrum::apply_l1_to_l2_alias;
+
+/// L1 gas charged per byte of compressed transaction data, as in EIP-2028.
+pub const L1_GAS_PER_BYTE: u64 = 16;
+
+/// The bytes added to the compressed size of every posted transaction, for its signature and
+/// other fields which do not compress, as in Nitro's `TxFixedCost`.
+pub const TX_FIXED_COST: u64 = 140;
+
+/// The padding applied to the L1 cost of gas estimates, in basis points, as in Nitro's
+/// `GasEstimationL1PricePadding`.
+pub const GAS_ESTIMATION_L1_PRICE_PADDING_BIPS: u64 = 11_000;
+
+/// The account collecting the fees paid for L1 calldata, as in Nitro's `L1PricerFundsPoolAddress`.
+pub const L1_PRICER_FUNDS_POOL_ADDRESS: Address =
+    address!("0xA4B00000000000000000000000000000000000f6");
+
+/// The brotli window size used by Nitro's L1 pricer.
+const BROTLI_WINDOW_SIZE: u32 = 22;
+
+/// The intrinsic gas of a transaction.
+const TX_GAS: u64 = 21_000;
+
+/// The additional intrinsic gas of a contract creation.
+const TX_CREATE_GAS: u64 = 32_000;
+
+/// The first block of Arbitrum One produced by Nitro. Earlier blocks were produced by Arbitrum
+/// Classic.
+pub const ARBITRUM_ONE_NITRO_GENESIS_BLOCK: u64 = 22_207_817;
+
+/// Returns the first block of the chain with `chain_id` produced by Nitro, which is the genesis
+/// block of any chain but Arbitrum One.
+pub fn nitro_genesis_block(chain_id: u64) -> u64 {
+    if chain_id == NamedChain::Arbitrum as u64 { ARBITRUM_ONE_NITRO_GENESIS_BLOCK } else { 0 }
+}
+
+/// Returns the first bytes of the hash of `name`, which Nitro uses for the fields of messages it
+/// prices without knowing them.
+fn random_value<const N: usize>(name: &str) -> [u8; N] {
+    keccak256(name)[..N].try_into().unwrap()
+}
+
+/// Returns the size of `tx` once compressed by the L1 pricer at the given brotli level.
+///
+/// Falls back to the uncompressed size if compression fails.
+pub fn compressed_size(tx: &[u8], brotli_level: u32) -> usize {
+    brotli_compress(tx, brotli_level, BROTLI_WINDOW_SIZE, Dictionary::Empty)
+        .map_or(tx.len(), |compressed| compressed.len())
+}
+
+/// The ArbOS pricing state used to charge for L1 calldata.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ArbosPricing {
+    /// The L1 base fee estimate, in wei per unit of L1 calldata.
+    pub l1_base_fee: U256,
+    /// The brotli compression level used to price L1 calldata.
+    pub brotli_level: u32,
+}
+
+impl ArbosPricing {
+    /// Reads the pricing state from the ArbOS state storage, with `sload` loading a slot of
+    /// [`ARBOS_STATE_ADDRESS`](crate::arbos::ARBOS_STATE_ADDRESS).
+    pub fn load<E>(mut sload: impl FnMut(U256) -> Result<U256, E>) -> Result<Self, E> {
+        let mut load = |param: ArbosParam| sload(param.storage_slot().expect("stored parameter"));
+        Ok(Self {
+            l1_base_fee: load(ArbosParam::L1BaseFee)?,
+            brotli_level: load(ArbosParam::BrotliCompressionLevel)?.saturating_to(),
+        })
+    }
+
+    /// Returns the units of L1 calldata charged for posting `tx`.
+    pub fn poster_units(&self, tx: &[u8]) -> u64 {
+        (compressed_size(tx, self.brotli_level) as u64 + TX_FIXED_COST) * L1_GAS_PER_BYTE
+    }
+
+    /// Returns the fee paid to the batch poster for posting `tx`, in wei.
+    pub fn poster_cost(&self, tx: &[u8]) -> U256 {
+        self.l1_base_fee * U256::from(self.poster_units(tx))
+    }
+
+    /// Returns the L2 gas paying for the L1 cost of `tx` at `base_fee`, as reported in the
+    /// `gasUsedForL1` field of its receipt.
+    pub fn poster_gas(&self, tx: &[u8], base_fee: U256) -> u64 {
+        if base_fee.is_zero() {
+            return 0;
+        }
+        (self.poster_cost(tx) / base_fee).saturating_to()
+    }
+
+    /// Returns the L2 gas paying for the L1 cost of `tx` at `base_fee`, padded like Nitro's gas
+    /// estimates.
+    pub fn estimate_l1_gas(&self, tx: &[u8], base_fee: U256) -> u64 {
+        if base_fee.is_zero() {
+            return 0;
+        }
+        let padded = self.poster_cost(tx) * U256::from(GAS_ESTIMATION_L1_PRICE_PADDING_BIPS)
+            / U256::from(10_000);
+        (padded / base_fee).saturating_to()
+    }
+}
+
+/// A message whose gas is needed to answer a `NodeInterface` call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeInterfaceMessage {
+    /// The sender of the message.
+    pub from: Address,
+    /// The target of the message.
+    pub to: TxKind,
+    /// The value sent with the message.
+    pub value: U256,
+    /// The calldata, or init code, of the message.
+    pub data: Bytes,
+}
+
+impl NodeInterfaceMessage {
+    /// Returns the intrinsic gas of the message, which is not spent by its execution.
+    pub fn intrinsic_gas(&self) -> u64 {
+        let create = if self.to.is_create() { TX_CREATE_GAS } else { 0 };
+        let data: u64 = self.data.iter().map(|byte| if *byte == 0 { 4 } else { 16 }).sum();
+        TX_GAS + create + data
+    }
+
+    /// Returns the transaction the L1 cost of the message is priced with, as Nitro's
+    /// `makeFakeTxForMessage` does for gas estimates: the fields which are unknown, or which vary
+    /// during the estimation like the gas limit, are fixed.
+    pub fn poster_tx(&self) -> Vec<u8> {
+        let tx = TxEip1559 {
+            chain_id: 0,
+            nonce: u64::from_be_bytes(random_value("Nonce")),
+            gas_limit: u32::from_be_bytes(random_value("Gas")).into(),
+            max_fee_per_gas: u32::from_be_bytes(random_value("GasFeeCap")).into(),
+            max_priority_fee_per_gas: u32::from_be_bytes(random_value("GasTipCap")).into(),
+            to: self.to,
+            value: self.value,
+            access_list: Default::default(),
+            input: self.data.clone(),
+        };
+        tx.into_signed(Signature::new(U256::ZERO, U256::ZERO, false)).encoded_2718()
+    }
+}
+
+/// A call to `gasEstimateComponents`, which returns the gas of its message together with the L1
+/// component of it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasEstimateComponents {
+    /// The message to estimate.
+    pub message: NodeInterfaceMessage,
+    /// The L2 gas paying for the L1 cost of the message.
+    pub l1_gas: u64,
+    /// The L2 base fee.
+    pub base_fee: U256,
+    /// The L1 base fee estimate.
+    pub l1_base_fee: U256,
+}
+
+impl GasEstimateComponents {
+    /// Returns the output of the call, given the L2 gas estimate of the message.
+    pub fn encode_returns(&self, gas: u64) -> Bytes {
+        NodeInterface::gasEstimateComponentsCall::abi_encode_returns(
+            &NodeInterface::gasEstimateComponentsReturn {
+                gasEstimate: gas.saturating_add(self.l1_gas),
+                gasEstimateForL1: self.l1_gas,
+                baseFee: self.base_fee,
+                l1BaseFeeEstimate: self.l1_base_fee,
+            },
+        )
+        .into()
+    }
+}
+
+/// A call to `NodeInterface`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NodeInterfaceCall {
+    /// The call returns the given data.
+    Return(Bytes),
+    /// `gasEstimateComponents`, which needs the L2 gas estimate of its message.
+    GasEstimateComponents(GasEstimateComponents),
+    /// `estimateRetryableTicket`, which executes the retry `message` of a ticket. The deposit is
+    /// minted to the aliased L1 sender first. Estimating the gas of the call estimates the gas of
+    /// the retry.
+    EstimateRetryableTicket { message: NodeInterfaceMessage, deposit: U256 },
+}
+
+impl NodeInterfaceCall {
+    /// Decodes a call to `NodeInterface` from `caller`, sending `value`, in a block with
+    /// `base_fee` of the chain with `chain_id`.
+    ///
+    /// Returns `None` if `input` is not a call to a supported method.
+    pub fn decode(
+        caller: Address,
+        value: U256,
+        input: &[u8],
+        pricing: &ArbosPricing,
+        base_fee: U256,
+        chain_id: u64,
+    ) -> Option<Self> {
+        let message = |to: Address, contract_creation: bool, data: Bytes| NodeInterfaceMessage {
+            from: caller,
+            to: if contract_creation { TxKind::Create } else { TxKind::Call(to) },
+            value,
+            data,
+        };
+
+        let call = match NodeInterfaceCalls::abi_decode(input).ok()? {
+            NodeInterfaceCalls::gasEstimateL1Component(call) => {
+                let message = message(call.to, call.contractCreation, call.data);
+                let gas = pricing.estimate_l1_gas(&message.poster_tx(), base_fee);
+                Self::Return(
+                    NodeInterface::gasEstimateL1ComponentCall::abi_encode_returns(
+                        &NodeInterface::gasEstimateL1ComponentReturn {
+                            gasEstimateForL1: gas,
+                            baseFee: base_fee,
+                            l1BaseFeeEstimate: pricing.l1_base_fee,
+                        },
+                    )
+                    .into(),
+                )
+            }
+            NodeInterfaceCalls::gasEstimateComponents(call) => {
+                let message = message(call.to, call.contractCreation, call.data);
+                Self::GasEstimateComponents(GasEstimateComponents {
+                    l1_gas: pricing.estimate_l1_gas(&message.poster_tx(), base_fee),
+                    message,
+                    base_fee,
+                    l1_base_fee: pricing.l1_base_fee,
+                })
+            }
+            NodeInterfaceCalls::estimateRetryableTicket(call) => Self::EstimateRetryableTicket {
+                message: NodeInterfaceMessage {
+                    from: apply_l1_to_l2_alias(call.sender),
+                    to: if call.to.is_zero() { TxKind::Create } else { TxKind::Call(call.to) },
+                    value: call.l2CallValue,
+                    data: call.data,
+                },
+                deposit: call.deposit,
+            },
+            NodeInterfaceCalls::nitroGenesisBlock(_) => Self::Return(
+                NodeInterface::nitroGenesisBlockCall::abi_encode_returns(&U256::from(
+                    nitro_genesis_block(chain_id),
+                ))
+                .into(),
+            ),
+        };
+        Some(call)
+    }
+
+    /// Returns the gas charged for a call to `NodeInterface` with `input` returning `output`.
+    ///
+    /// Like the precompiles, the call reads the ArbOS version and the [`ArbosPricing`] state, and
+    /// copies its arguments in and its results out. The messages it estimates are not charged.
+    pub fn gas_used(input: &[u8], output: &[u8]) -> u64 {
+        3 * STORAGE_READ_COST + copy_gas(input.len().saturating_sub(4)) + copy_gas(output.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimates_l1_gas() {
+        let pricing = ArbosPricing { l1_base_fee: U256::from(50), brotli_level: 1 };
+        let tx = [0u8; 1000];
+        let units = pricing.poster_units(&tx);
+        // Zeroes compress well, so the cost is dominated by the fixed cost.
+        assert!(units < 1000 * L1_GAS_PER_BYTE);
+        assert!(units >= TX_FIXED_COST * L1_GAS_PER_BYTE);
+
+        assert_eq!(pricing.poster_gas(&tx, U256::from(10)), units * 5);
+        let gas = pricing.estimate_l1_gas(&tx, U256::from(10));
+        assert_eq!(gas, units * 50 * 11 / 100);
+        assert_eq!(pricing.poster_gas(&tx, U256::ZERO), 0);
+        assert_eq!(pricing.estimate_l1_gas(&tx, U256::ZERO), 0);
+    }
+
+    #[test]
+    fn decodes_node_interface_calls() {
+        let pricing = ArbosPricing::default();
+        let caller = Address::repeat_byte(0x11);
+
+        let input = NodeInterface::nitroGenesisBlockCall {}.abi_encode();
+        assert_eq!(
+            NodeInterfaceCall::decode(caller, U256::ZERO, &input, &pricing, U256::ZERO, 42161),
+            Some(NodeInterfaceCall::Return(
+                U256::from(ARBITRUM_ONE_NITRO_GENESIS_BLOCK).to_be_bytes_vec().into()
+            ))
+        );
+        assert_eq!(
+            NodeInterfaceCall::decode(caller, U256::ZERO, &input, &pricing, U256::ZERO, 421614),
+            Some(NodeInterfaceCall::Return(U256::ZERO.to_be_bytes_vec().into()))
+        );
+
+        // The whole message is priced, not only its calldata.
+        let pricing = ArbosPricing { l1_base_fee: U256::from(50), brotli_level: 1 };
+        let estimate = |to: Address, value: U256| {
+            let input = NodeInterface::gasEstimateL1ComponentCall {
+                to,
+                contractCreation: false,
+                data: Bytes::new(),
+            }
+            .abi_encode();
+            let Some(NodeInterfaceCall::Return(output)) =
+                NodeInterfaceCall::decode(caller, value, &input, &pricing, U256::from(10), 1)
+            else {
+                panic!("expected gasEstimateL1Component");
+            };
+            NodeInterface::gasEstimateL1ComponentCall::abi_decode_returns(&output)
+                .unwrap()
+                .gasEstimateForL1
+        };
+        assert!(estimate(Address::ZERO, U256::ZERO) > pricing.estimate_l1_gas(&[], U256::from(10)));
+        let value = U256::from_be_bytes(keccak256("value").0);
+        assert!(estimate(Address::ZERO, value) > estimate(Address::ZERO, U256::ZERO));
+
+        let input = NodeInterface::estimateRetryableTicketCall {
+            sender: caller,
+            deposit: U256::from(2),
+            to: Address::ZERO,
+            l2CallValue: U256::from(1),
+            excessFeeRefundAddress: caller,
+            callValueRefundAddress: caller,
+            data: Bytes::from_static(&[1, 2]),
+        }
+        .abi_encode();
+        let Some(NodeInterfaceCall::EstimateRetryableTicket { message, deposit }) =
+            NodeInterfaceCall::decode(caller, U256::ZERO, &input, &pricing, U256::ZERO, 1)
+        else {
+            panic!("expected estimateRetryableTicket");
+        };
+        assert_eq!(message.from, apply_l1_to_l2_alias(caller));
+        assert_eq!(message.to, TxKind::Create);
+        assert_eq!(deposit, U256::from(2));
+        assert_eq!(message.intrinsic_gas(), TX_GAS + TX_CREATE_GAS + 32);
+
+        assert_eq!(
+            NodeInterfaceCall::decode(caller, U256::ZERO, &[], &pricing, U256::ZERO, 1),
+            None
+        );
+    }
+
+    #[test]
+    fn charges_node_interface_calls() {
+        let input = NodeInterface::nitroGenesisBlockCall {}.abi_encode();
+        assert_eq!(NodeInterfaceCall::gas_used(&input, &[0; 32]), 3 * STORAGE_READ_COST + 3);
+        assert_eq!(NodeInterfaceCall::gas_used(&[0; 37], &[0; 33]), 3 * STORAGE_READ_COST + 12);
+    }
+}
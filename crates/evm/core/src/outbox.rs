@@ -0,0 +1,366 @@
+//! Arbitrum L2→L1 messages.
+//!
+//! Calls to `ArbSys.sendTxToL1` and `ArbSys.withdrawEth` emit an `L2ToL1Tx` event, and append the
+//! hash of the message to the send Merkle tree of ArbOS. Once the tree root is posted to L1, a
+//! message can be executed through the `Outbox` contract with a proof of inclusion in that tree.
+//!
+//! The send Merkle tree is accumulated in the ArbOS state like Nitro does with
+//! [`append_l2_to_l1_message`], so it is journaled like any other storage and continues the tree
+//! of a forked chain. Nitro only keeps the partial roots of the tree, so the leaves appended
+//! locally and the accumulator they were appended to are kept next to it, to build proofs.
+//! [`L2ToL1Outbox`] keeps the contents of the messages, which are not part of the state.
+
+use crate::arbos::{ArbosSpace, ArbosStorage};
+use alloy_primitives::{Address, B256, Bytes, Log, U256, keccak256, map::B256HashMap};
+use alloy_sol_types::SolEvent;
+use foundry_evm_abi::arbitrum::ArbSys;
+use foundry_evm_networks::arbitrum::ARB_SYS;
+use serde::{Deserialize, Serialize};
+
+/// An L2→L1 message, as emitted in an `L2ToL1Tx` event.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L2ToL1Message {
+    /// The L2 account which sent the message.
+    pub caller: Address,
+    /// The L1 account which the message is sent to.
+    pub destination: Address,
+    /// The hash of the message, which is the leaf of the send Merkle tree.
+    pub hash: U256,
+    /// The position of the message in the send Merkle tree.
+    pub position: U256,
+    /// The L2 block number the message was sent in.
+    pub arb_block_num: U256,
+    /// The L1 block number the message was sent in.
+    pub eth_block_num: U256,
+    /// The timestamp the message was sent at.
+    pub timestamp: U256,
+    /// The value sent to L1 with the message.
+    pub callvalue: U256,
+    /// The calldata of the L1 call.
+    pub data: Bytes,
+}
+
+impl L2ToL1Message {
+    /// Decodes a message from an `L2ToL1Tx` log emitted by `ArbSys`.
+    pub fn from_log(log: &Log) -> Option<Self> {
+        if log.address != ARB_SYS {
+            return None;
+        }
+        let event = ArbSys::L2ToL1Tx::decode_log_data(&log.data).ok()?;
+        Some(Self {
+            caller: event.caller,
+            destination: event.destination,
+            hash: event.hash,
+            position: event.position,
+            arb_block_num: event.arbBlockNum,
+            eth_block_num: event.ethBlockNum,
+            timestamp: event.timestamp,
+            callvalue: event.callvalue,
+            data: event.data,
+        })
+    }
+
+    /// Returns the hash of the message, as computed by `Outbox.calculateItemHash`.
+    pub fn item_hash(&self) -> B256 {
+        self.hash.into()
+    }
+}
+
+/// The storage space of the send Merkle accumulator.
+const SEND_MERKLE_SPACE: u8 = 5;
+/// The storage space of the leaves appended locally, which is not part of Nitro's layout. It
+/// holds the size of the accumulator before the first local leaf plus one at offset 0, the
+/// partials of that accumulator in the [`BASE_PARTIALS_KEY`] subspace, and the leaves in the
+/// [`LEAVES_KEY`] subspace.
+const LOCAL_LEAVES_KEY: &[u8] = b"foundry send merkle leaves";
+const BASE_PARTIALS_KEY: &[u8] = &[0];
+const LEAVES_KEY: &[u8] = &[1];
+
+/// Appends the message emitted by `log`, if any, to the send Merkle accumulator in `storage`,
+/// and returns it with its position in the tree.
+pub fn append_l2_to_l1_message<S: ArbosStorage>(
+    storage: &mut S,
+    log: &Log,
+) -> Result<Option<L2ToL1Message>, S::Error> {
+    let Some(mut message) = L2ToL1Message::from_log(log) else { return Ok(None) };
+    let accumulator = ArbosSpace::new(&[SEND_MERKLE_SPACE]);
+    let local = ArbosSpace::default().subspace(LOCAL_LEAVES_KEY);
+    let size = storage.sload(accumulator.slot(0))?.saturating_to::<u64>();
+    let num_partials = storage.sload(accumulator.slot(1))?.saturating_to::<u64>();
+
+    // keep the accumulator the first local leaf is appended to, which covers the leaves before
+    let base = match storage.sload(local.slot(0))?.saturating_to::<u64>() {
+        0 => {
+            for level in 0..num_partials {
+                let partial = storage.sload(accumulator.slot(2 + level))?;
+                storage.sstore(local.subspace(BASE_PARTIALS_KEY).slot(level), partial)?;
+            }
+            storage.sstore(local.slot(0), U256::from(size + 1))?;
+            size
+        }
+        base => base - 1,
+    };
+    storage.sstore(local.subspace(LEAVES_KEY).slot(size - base), message.hash)?;
+    accumulate(storage, message.item_hash())?;
+
+    message.position = U256::from(size);
+    Ok(Some(message))
+}
+
+/// Appends `leaf` to the send Merkle accumulator, as `MerkleAccumulator.Append` in Nitro.
+fn accumulate<S: ArbosStorage>(storage: &mut S, leaf: B256) -> Result<(), S::Error> {
+    let accumulator = ArbosSpace::new(&[SEND_MERKLE_SPACE]);
+    let size = storage.sload(accumulator.slot(0))?;
+    storage.sstore(accumulator.slot(0), size + U256::from(1))?;
+    let mut num_partials = storage.sload(accumulator.slot(1))?.saturating_to::<u64>();
+    let mut so_far = keccak256(leaf);
+    let mut level = 0;
+    loop {
+        if level == num_partials {
+            num_partials += 1;
+            storage.sstore(accumulator.slot(1), U256::from(num_partials))?;
+            break;
+        }
+        let partial = storage.sload(accumulator.slot(2 + level))?;
+        if partial.is_zero() {
+            break;
+        }
+        so_far = hash_pair(partial.into(), so_far);
+        storage.sstore(accumulator.slot(2 + level), U256::ZERO)?;
+        level += 1;
+    }
+    storage.sstore(accumulator.slot(2 + level), so_far.into())
+}
+
+/// Returns the root of the send Merkle tree in `storage`, as `MerkleAccumulator.Root` in Nitro.
+pub fn send_merkle_root<S: ArbosStorage>(storage: &mut S) -> Result<B256, S::Error> {
+    let accumulator = ArbosSpace::new(&[SEND_MERKLE_SPACE]);
+    let num_partials = storage.sload(accumulator.slot(1))?.saturating_to::<u64>();
+    let mut root: Option<B256> = None;
+    let mut capacity_in_root = 0u64;
+    for level in 0..num_partials {
+        let partial = B256::from(storage.sload(accumulator.slot(2 + level))?);
+        if partial.is_zero() {
+            continue;
+        }
+        let capacity = 1u64 << level;
+        root = Some(match root {
+            None => partial,
+            Some(mut hash) => {
+                while capacity_in_root < capacity {
+                    hash = hash_pair(hash, B256::ZERO);
+                    capacity_in_root *= 2;
+                }
+                hash_pair(partial, hash)
+            }
+        });
+        capacity_in_root = if capacity_in_root == 0 { capacity } else { 2 * capacity };
+    }
+    Ok(root.unwrap_or_default())
+}
+
+/// The contents of the L2→L1 messages sent locally, by hash.
+///
+/// The messages themselves are listed from the leaves in the state, so messages which were
+/// reverted are left out.
+#[derive(Clone, Debug, Default)]
+pub struct L2ToL1Outbox {
+    messages: B256HashMap<L2ToL1Message>,
+}
+
+impl L2ToL1Outbox {
+    /// Records the contents of a message appended to the send Merkle tree.
+    pub fn insert(&mut self, message: L2ToL1Message) {
+        self.messages.insert(message.item_hash(), message);
+    }
+
+    /// Returns the messages sent locally in `storage`, in order.
+    ///
+    /// Messages whose contents were not recorded, e.g. after loading a state dump, only have
+    /// their hash and position set.
+    pub fn messages<S: ArbosStorage>(
+        &self,
+        storage: &mut S,
+    ) -> Result<Vec<L2ToL1Message>, S::Error> {
+        let tree = LocalSendMerkleTree::load(storage)?;
+        let messages = tree.leaves.iter().enumerate().map(|(index, leaf)| {
+            let message = self
+                .messages
+                .get(leaf)
+                .cloned()
+                .unwrap_or_else(|| L2ToL1Message { hash: (*leaf).into(), ..Default::default() });
+            L2ToL1Message { position: U256::from(tree.base + index as u64), ..message }
+        });
+        Ok(messages.collect())
+    }
+
+    /// Returns the root of the send Merkle tree in `storage`, and the proof of the message at
+    /// `index` in [`messages`](Self::messages) against it, to be passed to
+    /// `Outbox.executeTransaction` together with the position of the message as the path.
+    pub fn proof<S: ArbosStorage>(
+        storage: &mut S,
+        index: usize,
+    ) -> Result<Option<(B256, Vec<B256>)>, S::Error> {
+        let tree = LocalSendMerkleTree::load(storage)?;
+        if index >= tree.leaves.len() {
+            return Ok(None);
+        }
+        let position = tree.base + index as u64;
+        let proof = (0..tree_depth(tree.size()))
+            .map(|level| tree.node(level, (position >> level) ^ 1))
+            .collect();
+        Ok(Some((send_merkle_root(storage)?, proof)))
+    }
+}
+
+/// The part of the send Merkle tree which is known locally: the partials of the accumulator
+/// before the first local leaf, and the leaves appended since.
+struct LocalSendMerkleTree {
+    base: u64,
+    base_partials: Vec<B256>,
+    leaves: Vec<B256>,
+}
+
+impl LocalSendMerkleTree {
+    fn load<S: ArbosStorage>(storage: &mut S) -> Result<Self, S::Error> {
+        let accumulator = ArbosSpace::new(&[SEND_MERKLE_SPACE]);
+        let local = ArbosSpace::default().subspace(LOCAL_LEAVES_KEY);
+        let mut tree = Self { base: 0, base_partials: Vec::new(), leaves: Vec::new() };
+        let base = storage.sload(local.slot(0))?.saturating_to::<u64>();
+        if base == 0 {
+            return Ok(tree);
+        }
+        tree.base = base - 1;
+        let size = storage.sload(accumulator.slot(0))?.saturating_to::<u64>();
+        for level in 0..u64::BITS - tree.base.leading_zeros() {
+            let partial = storage.sload(local.subspace(BASE_PARTIALS_KEY).slot(level.into()))?;
+            tree.base_partials.push(partial.into());
+        }
+        for offset in 0..size.saturating_sub(tree.base) {
+            tree.leaves.push(storage.sload(local.subspace(LEAVES_KEY).slot(offset))?.into());
+        }
+        Ok(tree)
+    }
+
+    fn size(&self) -> u64 {
+        self.base + self.leaves.len() as u64
+    }
+
+    /// Returns the node at `level` and `index`.
+    ///
+    /// Leaves are hashed once more before being inserted, and nodes covering no leaves are zero.
+    /// Nodes covering only leaves before the local ones are partials of the base accumulator,
+    /// since their parent covers local leaves.
+    fn node(&self, level: u32, index: u64) -> B256 {
+        if index << level >= self.size() {
+            return B256::ZERO;
+        }
+        if (index + 1) << level <= self.base {
+            return self.base_partials.get(level as usize).copied().unwrap_or_default();
+        }
+        if level == 0 {
+            return keccak256(self.leaves[(index - self.base) as usize]);
+        }
+        hash_pair(self.node(level - 1, 2 * index), self.node(level - 1, 2 * index + 1))
+    }
+}
+
+/// Returns the number of levels of a send Merkle tree with `size` leaves.
+fn tree_depth(size: u64) -> u32 {
+    size.next_power_of_two().trailing_zeros()
+}
+
+fn hash_pair(left: B256, right: B256) -> B256 {
+    keccak256([left.as_slice(), right.as_slice()].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbos::MemoryStorage;
+
+    // Mirrors `MerkleLib.calculateRoot`, as called by `Outbox.calculateMerkleRoot`.
+    fn calculate_root(proof: &[B256], path: u64, item: B256) -> B256 {
+        let mut hash = keccak256(item);
+        for (i, node) in proof.iter().enumerate() {
+            hash =
+                if path & (1 << i) == 0 { hash_pair(hash, *node) } else { hash_pair(*node, hash) };
+        }
+        hash
+    }
+
+    // The root of the tree over all `leaves`, padded with zero nodes.
+    fn full_root(leaves: &[B256]) -> B256 {
+        let mut nodes: Vec<_> = leaves.iter().map(keccak256).collect();
+        while nodes.len() > 1 {
+            nodes = nodes
+                .chunks(2)
+                .map(|pair| hash_pair(pair[0], pair.get(1).copied().unwrap_or_default()))
+                .collect();
+        }
+        nodes.first().copied().unwrap_or_default()
+    }
+
+    fn message_log(hash: B256) -> Log {
+        let event = ArbSys::L2ToL1Tx {
+            caller: Address::ZERO,
+            destination: Address::ZERO,
+            hash: hash.into(),
+            position: U256::ZERO,
+            arbBlockNum: U256::ZERO,
+            ethBlockNum: U256::ZERO,
+            timestamp: U256::ZERO,
+            callvalue: U256::ZERO,
+            data: Bytes::new(),
+        };
+        Log { address: ARB_SYS, data: event.encode_log_data() }
+    }
+
+    #[test]
+    fn send_merkle_proofs() {
+        // leaves appended before the fork are only known through the accumulator
+        for forked in [0u64, 1, 4, 5, 6] {
+            let mut storage = MemoryStorage::default();
+            let mut leaves = Vec::new();
+            for i in 0..forked {
+                leaves.push(B256::from(U256::from(1000 + i)));
+                accumulate(&mut storage, *leaves.last().unwrap()).unwrap();
+            }
+
+            let mut outbox = L2ToL1Outbox::default();
+            assert!(outbox.messages(&mut storage).unwrap().is_empty());
+            assert!(L2ToL1Outbox::proof(&mut storage, 0).unwrap().is_none());
+
+            for i in 0..9u64 {
+                leaves.push(B256::from(U256::from(i + 1)));
+                let log = message_log(*leaves.last().unwrap());
+                let message = append_l2_to_l1_message(&mut storage, &log).unwrap().unwrap();
+                assert_eq!(message.position, U256::from(forked + i));
+                outbox.insert(message);
+
+                let root = send_merkle_root(&mut storage).unwrap();
+                assert_eq!(root, full_root(&leaves));
+                let messages = outbox.messages(&mut storage).unwrap();
+                assert_eq!(messages.len() as u64, i + 1);
+                for (index, message) in messages.iter().enumerate() {
+                    let (proof_root, proof) =
+                        L2ToL1Outbox::proof(&mut storage, index).unwrap().unwrap();
+                    assert_eq!(proof_root, root);
+                    let path = message.position.to::<u64>();
+                    assert_eq!(calculate_root(&proof, path, message.item_hash()), root);
+                }
+            }
+        }
+
+        let [a, b, c] = [1u64, 2, 3].map(|i| keccak256(B256::from(U256::from(i))));
+        let mut storage = MemoryStorage::default();
+        for i in 1..=3u64 {
+            append_l2_to_l1_message(&mut storage, &message_log(U256::from(i).into())).unwrap();
+        }
+        assert_eq!(
+            send_merkle_root(&mut storage).unwrap(),
+            hash_pair(hash_pair(a, b), hash_pair(c, B256::ZERO))
+        );
+    }
+}
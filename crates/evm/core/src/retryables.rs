@@ -0,0 +1,623 @@
+//! Arbitrum retryable tickets.
+//!
+//! A retryable ticket is created by an L1→L2 message submitted through the delayed inbox. ArbOS
+//! tries to redeem it right away, and if that "auto-redeem" fails the ticket can be redeemed
+//! manually through `ArbRetryableTx` until it expires.
+//!
+//! Tickets are kept in the ArbOS state with the same layout as Nitro, so they are journaled,
+//! snapshotted and forked like any other storage. Calls to `ArbRetryableTx` which concern a
+//! ticket are answered by [`RetryableContext::call`] instead of the precompile.
+
+use crate::{
+    arbos::{
+        ArbosSpace, ArbosStorage, JournaledArbosStorage, STORAGE_READ_COST, STORAGE_WRITE_COST,
+    },
+    tx::arbitrum::{ArbitrumTxEnvelope, TxArbitrumRetry, TxArbitrumSubmitRetryable},
+};
+use alloy_primitives::{Address, B256, Bytes, Log, TxKind, U256, keccak256};
+use alloy_sol_types::{SolCall, SolError, SolEvent, SolInterface};
+use foundry_evm_abi::arbitrum::ArbRetryableTx::{self, ArbRetryableTxCalls};
+use foundry_evm_networks::arbitrum::ARB_RETRYABLE_TX;
+use revm::{Database, context::JournalTr};
+
+/// The lifetime of a retryable ticket, and the extension granted by `keepalive`.
+pub const RETRYABLE_LIFETIME_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// The gas `keepalive` charges in advance for reaping the ticket's extra timeout queue entry.
+const RETRYABLE_REAP_PRICE: u64 = 58_000;
+/// The gas charged per word copied in or out of a precompile.
+const COPY_GAS: u64 = 3;
+/// The minimum gas a redeem must donate to the retry.
+const TX_GAS: u64 = 21_000;
+
+/// The storage space of the retryable tickets.
+const RETRYABLES_SPACE: u8 = 2;
+/// The subspace of the queue of tickets to check for expiry.
+const TIMEOUT_QUEUE_KEY: &[u8] = &[0];
+/// The subspace of a ticket's calldata, in the ticket's space.
+const CALLDATA_KEY: &[u8] = &[1];
+
+/// The offsets of a ticket's fields in its space.
+const NUM_TRIES_OFFSET: u64 = 0;
+const FROM_OFFSET: u64 = 1;
+const TO_OFFSET: u64 = 2;
+const CALLVALUE_OFFSET: u64 = 3;
+const BENEFICIARY_OFFSET: u64 = 4;
+const TIMEOUT_OFFSET: u64 = 5;
+const TIMEOUT_WINDOWS_LEFT_OFFSET: u64 = 6;
+
+/// Returns the account which holds the call value of a ticket until it is redeemed or canceled.
+pub fn retryable_escrow_address(ticket_id: B256) -> Address {
+    let mut preimage = b"retryable escrow".to_vec();
+    preimage.extend_from_slice(ticket_id.as_slice());
+    Address::from_word(keccak256(preimage))
+}
+
+/// A retryable ticket.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryableTicket {
+    /// The ticket id, which is the hash of the submission transaction.
+    pub id: B256,
+    /// The aliased L1 sender, which the retry is executed from.
+    pub from: Address,
+    /// The target of the retry.
+    pub to: TxKind,
+    /// The value sent with the retry, held in escrow until then.
+    pub callvalue: U256,
+    /// The account which receives the call value if the ticket is canceled.
+    pub beneficiary: Address,
+    /// The calldata of the retry.
+    pub data: Bytes,
+    /// The timestamp at which the ticket expires, before any `keepalive`.
+    pub timeout: u64,
+    /// The number of lifetimes the ticket was extended by with `keepalive`.
+    pub timeout_windows_left: u64,
+    /// The number of redeem attempts.
+    pub num_tries: u64,
+}
+
+impl RetryableTicket {
+    /// Returns the account holding the call value of the ticket.
+    pub fn escrow(&self) -> Address {
+        retryable_escrow_address(self.id)
+    }
+
+    /// Returns the timestamp after which the ticket can no longer be redeemed.
+    pub fn expires_at(&self) -> u64 {
+        expires_at(self.timeout, self.timeout_windows_left)
+    }
+
+    /// Returns the transaction redeeming the ticket for the next attempt.
+    pub fn retry_tx(&self, chain_id: U256, gas_fee_cap: U256, gas: u64) -> TxArbitrumRetry {
+        TxArbitrumRetry {
+            chain_id,
+            nonce: self.num_tries,
+            from: self.from,
+            gas_fee_cap,
+            gas,
+            to: self.to,
+            value: self.callvalue,
+            data: self.data.clone(),
+            ticket_id: self.id,
+            refund_to: self.beneficiary,
+            max_refund: U256::from(gas) * gas_fee_cap,
+            submission_fee_refund: U256::ZERO,
+        }
+    }
+}
+
+fn expires_at(timeout: u64, timeout_windows_left: u64) -> u64 {
+    timeout.saturating_add(timeout_windows_left.saturating_mul(RETRYABLE_LIFETIME_SECONDS))
+}
+
+/// The retryable tickets in the ArbOS state.
+#[derive(Debug)]
+pub struct RetryableState<S> {
+    storage: S,
+    space: ArbosSpace,
+}
+
+impl<S: ArbosStorage> RetryableState<S> {
+    /// Opens the retryable tickets in `storage`.
+    pub fn new(storage: S) -> Self {
+        Self { storage, space: ArbosSpace::new(&[RETRYABLES_SPACE]) }
+    }
+
+    /// Returns the underlying storage.
+    pub fn storage_mut(&mut self) -> &mut S {
+        &mut self.storage
+    }
+
+    /// Creates the ticket submitted by `tx` at `timestamp`, and returns it together with the
+    /// `TicketCreated` log.
+    ///
+    /// The caller is responsible for minting the deposit to the sender and moving the call
+    /// value to the ticket's escrow.
+    pub fn create(
+        &mut self,
+        tx: &TxArbitrumSubmitRetryable,
+        timestamp: u64,
+    ) -> Result<(RetryableTicket, Log), S::Error> {
+        let id = ArbitrumTxEnvelope::SubmitRetryable(tx.clone()).hash();
+        let ticket = RetryableTicket {
+            id,
+            from: tx.from,
+            to: tx.retry_to,
+            callvalue: tx.retry_value,
+            beneficiary: tx.beneficiary,
+            data: tx.retry_data.clone(),
+            timeout: timestamp.saturating_add(RETRYABLE_LIFETIME_SECONDS),
+            timeout_windows_left: 0,
+            num_tries: 0,
+        };
+
+        let space = self.ticket_space(id);
+        let to = match ticket.to {
+            TxKind::Call(to) => to.into_word().into(),
+            TxKind::Create => nil_address(),
+        };
+        self.storage.sstore(space.slot(NUM_TRIES_OFFSET), U256::ZERO)?;
+        self.storage.sstore(space.slot(FROM_OFFSET), ticket.from.into_word().into())?;
+        self.storage.sstore(space.slot(TO_OFFSET), to)?;
+        self.storage.sstore(space.slot(CALLVALUE_OFFSET), ticket.callvalue)?;
+        self.storage
+            .sstore(space.slot(BENEFICIARY_OFFSET), ticket.beneficiary.into_word().into())?;
+        self.storage.sstore(space.slot(TIMEOUT_OFFSET), U256::from(ticket.timeout))?;
+        self.storage.sstore(space.slot(TIMEOUT_WINDOWS_LEFT_OFFSET), U256::ZERO)?;
+        self.store_bytes(&space.subspace(CALLDATA_KEY), &ticket.data)?;
+        self.push_timeout_queue(id)?;
+
+        Ok((ticket, precompile_log(ArbRetryableTx::TicketCreated { ticketId: id })))
+    }
+
+    /// Returns the ticket with the given id, unless it does not exist or has expired at
+    /// `timestamp`.
+    pub fn get(&mut self, id: B256, timestamp: u64) -> Result<Option<RetryableTicket>, S::Error> {
+        let Some((timeout, timeout_windows_left)) = self.open(id, timestamp)? else {
+            return Ok(None);
+        };
+        let space = self.ticket_space(id);
+        let to = self.storage.sload(space.slot(TO_OFFSET))?;
+        Ok(Some(RetryableTicket {
+            id,
+            from: self.load_address(space.slot(FROM_OFFSET))?,
+            to: if to == nil_address() {
+                TxKind::Create
+            } else {
+                TxKind::Call(Address::from_word(to.into()))
+            },
+            callvalue: self.storage.sload(space.slot(CALLVALUE_OFFSET))?,
+            beneficiary: self.load_address(space.slot(BENEFICIARY_OFFSET))?,
+            data: self.load_bytes(&space.subspace(CALLDATA_KEY))?,
+            timeout,
+            timeout_windows_left,
+            num_tries: self.storage.sload(space.slot(NUM_TRIES_OFFSET))?.saturating_to(),
+        }))
+    }
+
+    /// Schedules the next redeem attempt of `ticket` with the gas donated by `gas_donor`, and
+    /// returns it together with the `RedeemScheduled` log.
+    pub fn schedule_redeem(
+        &mut self,
+        ticket: &RetryableTicket,
+        chain_id: U256,
+        gas_fee_cap: U256,
+        gas: u64,
+        gas_donor: Address,
+    ) -> Result<(ScheduledRedeem, Log), S::Error> {
+        let slot = self.ticket_space(ticket.id).slot(NUM_TRIES_OFFSET);
+        let num_tries = self.storage.sload(slot)?;
+        self.storage.sstore(slot, num_tries.saturating_add(U256::from(1)))?;
+
+        let retry = RetryableTicket { num_tries: num_tries.saturating_to(), ..ticket.clone() }
+            .retry_tx(chain_id, gas_fee_cap, gas);
+        let log = precompile_log(ArbRetryableTx::RedeemScheduled {
+            ticketId: ticket.id,
+            retryTxHash: ArbitrumTxEnvelope::Retry(retry.clone()).hash(),
+            sequenceNum: retry.nonce,
+            donatedGas: gas,
+            gasDonor: gas_donor,
+            maxRefund: retry.max_refund,
+            submissionFeeRefund: retry.submission_fee_refund,
+        });
+        Ok((ScheduledRedeem { retry, gas_donor }, log))
+    }
+
+    /// Deletes the ticket with the given id, e.g. once it was redeemed successfully.
+    pub fn delete(&mut self, id: B256) -> Result<(), S::Error> {
+        let space = self.ticket_space(id);
+        for offset in NUM_TRIES_OFFSET..=TIMEOUT_WINDOWS_LEFT_OFFSET {
+            self.storage.sstore(space.slot(offset), U256::ZERO)?;
+        }
+        self.clear_bytes(&space.subspace(CALLDATA_KEY))
+    }
+
+    /// Returns the timeout and the number of extensions of the ticket with the given id, unless
+    /// it does not exist or has expired at `timestamp`.
+    fn open(&mut self, id: B256, timestamp: u64) -> Result<Option<(u64, u64)>, S::Error> {
+        let space = self.ticket_space(id);
+        let timeout = self.storage.sload(space.slot(TIMEOUT_OFFSET))?.saturating_to();
+        if timeout == 0 {
+            return Ok(None);
+        }
+        let windows = self.storage.sload(space.slot(TIMEOUT_WINDOWS_LEFT_OFFSET))?.saturating_to();
+        Ok((expires_at(timeout, windows) >= timestamp).then_some((timeout, windows)))
+    }
+
+    /// Extends the lifetime of the ticket with the given id, and returns its new timeout.
+    ///
+    /// Tickets cannot be extended once their timeout is more than a lifetime away.
+    fn keepalive(
+        &mut self,
+        id: B256,
+        timestamp: u64,
+    ) -> Result<Result<Option<u64>, &'static str>, S::Error> {
+        let Some((timeout, windows)) = self.open(id, timestamp)? else { return Ok(Ok(None)) };
+        if expires_at(timeout, windows) > timestamp.saturating_add(RETRYABLE_LIFETIME_SECONDS) {
+            return Ok(Err("timeout too far into the future"));
+        }
+        // ArbOS queues the ticket again, the last entry being the one which deletes it
+        self.push_timeout_queue(id)?;
+        let slot = self.ticket_space(id).slot(TIMEOUT_WINDOWS_LEFT_OFFSET);
+        self.storage.sstore(slot, U256::from(windows + 1))?;
+        Ok(Ok(Some(expires_at(timeout, windows + 1))))
+    }
+
+    fn ticket_space(&self, id: B256) -> ArbosSpace {
+        self.space.subspace(id.as_slice())
+    }
+
+    fn load_address(&mut self, slot: U256) -> Result<Address, S::Error> {
+        Ok(Address::from_word(self.storage.sload(slot)?.into()))
+    }
+
+    /// Appends `id` to the timeout queue, which starts at offset 2 of its space after the
+    /// offsets of its next put and get positions.
+    fn push_timeout_queue(&mut self, id: B256) -> Result<(), S::Error> {
+        let queue = self.space.subspace(TIMEOUT_QUEUE_KEY);
+        let mut next_put = self.storage.sload(queue.slot(0))?.saturating_to::<u64>();
+        if next_put < 2 {
+            next_put = 2;
+            self.storage.sstore(queue.slot(1), U256::from(next_put))?;
+        }
+        self.storage.sstore(queue.slot(next_put), id.into())?;
+        self.storage.sstore(queue.slot(0), U256::from(next_put + 1))
+    }
+
+    /// Stores `bytes` as their length followed by their 32-byte words, the last one being
+    /// left-padded.
+    fn store_bytes(&mut self, space: &ArbosSpace, bytes: &[u8]) -> Result<(), S::Error> {
+        self.storage.sstore(space.slot(0), U256::from(bytes.len()))?;
+        let mut offset = 1;
+        let mut chunks = bytes.chunks_exact(32);
+        for chunk in chunks.by_ref() {
+            self.storage.sstore(space.slot(offset), U256::from_be_slice(chunk))?;
+            offset += 1;
+        }
+        self.storage.sstore(space.slot(offset), U256::from_be_slice(chunks.remainder()))
+    }
+
+    fn load_bytes(&mut self, space: &ArbosSpace) -> Result<Bytes, S::Error> {
+        let mut len = self.storage.sload(space.slot(0))?.saturating_to::<usize>();
+        let mut bytes = Vec::with_capacity(len);
+        let mut offset = 1;
+        while len > 0 {
+            let word = B256::from(self.storage.sload(space.slot(offset))?);
+            let n = len.min(32);
+            bytes.extend_from_slice(&word[32 - n..]);
+            len -= n;
+            offset += 1;
+        }
+        Ok(bytes.into())
+    }
+
+    fn clear_bytes(&mut self, space: &ArbosSpace) -> Result<(), S::Error> {
+        let len = self.storage.sload(space.slot(0))?.saturating_to::<u64>();
+        for offset in 0..=len.div_ceil(32) {
+            self.storage.sstore(space.slot(offset), U256::ZERO)?;
+        }
+        Ok(())
+    }
+}
+
+/// A redeem scheduled through `ArbRetryableTx.redeem`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScheduledRedeem {
+    /// The transaction redeeming the ticket.
+    pub retry: TxArbitrumRetry,
+    /// The account which donated the gas of the retry.
+    pub gas_donor: Address,
+}
+
+impl ScheduledRedeem {
+    /// Returns the hash of the retry transaction.
+    pub fn retry_tx_hash(&self) -> B256 {
+        ArbitrumTxEnvelope::Retry(self.retry.clone()).hash()
+    }
+}
+
+/// The outcome of a call to `ArbRetryableTx` handled by [`RetryableContext::call`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RetryableCall {
+    /// The call returned the given data.
+    Return(Bytes),
+    /// The call reverted with the given data.
+    Revert(Bytes),
+    /// The call ran out of gas.
+    OutOfGas,
+    /// A redeem was scheduled. The call returns the hash of the retry transaction, which must
+    /// be executed after the current call.
+    Redeem(ScheduledRedeem),
+}
+
+/// The environment a call to `ArbRetryableTx` is executed in.
+#[derive(Clone, Copy, Debug)]
+pub struct RetryableCallEnv {
+    pub chain_id: U256,
+    pub timestamp: u64,
+    pub basefee: U256,
+    /// The gas available to the call, what is left of which is donated to the retry on redeem.
+    pub gas_limit: u64,
+}
+
+/// The parts of retryable processing which are not kept in the ArbOS state.
+#[derive(Clone, Debug, Default)]
+pub struct RetryableContext {
+    /// The beneficiary of the ticket currently being redeemed, if any.
+    current_redeemer: Option<Address>,
+    /// The number of L1 messages submitted locally, used to derive their request ids.
+    request_count: u64,
+}
+
+impl RetryableContext {
+    /// Returns a new request id for a locally submitted L1 message.
+    pub fn next_request_id(&mut self) -> B256 {
+        self.request_count += 1;
+        B256::from(U256::from(self.request_count))
+    }
+
+    /// Marks the beneficiary of the ticket redeemed by `retry` as the current redeemer.
+    pub fn start_redeem(&mut self, retry: &TxArbitrumRetry) {
+        self.current_redeemer = Some(retry.refund_to);
+    }
+
+    /// Clears the current redeemer once a redeem attempt is over.
+    pub fn end_redeem(&mut self) {
+        self.current_redeemer = None;
+    }
+
+    /// Handles a call to `ArbRetryableTx` from `caller`, and returns its outcome together with
+    /// the gas it used.
+    ///
+    /// Returns `None` if the call does not concern a ticket, in which case it should be executed
+    /// by the precompile.
+    pub fn call<J: JournalTr>(
+        &self,
+        journal: &mut J,
+        caller: Address,
+        input: &[u8],
+        env: RetryableCallEnv,
+    ) -> Result<Option<(RetryableCall, u64)>, <J::Database as Database>::Error> {
+        let Ok(call) = ArbRetryableTxCalls::abi_decode(input) else { return Ok(None) };
+        if !matches!(
+            call,
+            ArbRetryableTxCalls::getCurrentRedeemer(_)
+                | ArbRetryableTxCalls::redeem(_)
+                | ArbRetryableTxCalls::getTimeout(_)
+                | ArbRetryableTxCalls::keepalive(_)
+                | ArbRetryableTxCalls::getBeneficiary(_)
+                | ArbRetryableTxCalls::cancel(_)
+        ) {
+            return Ok(None);
+        }
+
+        // opening the ArbOS state reads its version, and the arguments are copied in
+        let mut gas_used = STORAGE_READ_COST + copy_gas(input.len().saturating_sub(4));
+        let mut logs = Vec::new();
+        let mut retryables = RetryableState::new(JournaledArbosStorage::new(journal)?);
+        let outcome = match call {
+            ArbRetryableTxCalls::getCurrentRedeemer(_) => RetryableCall::Return(
+                ArbRetryableTx::getCurrentRedeemerCall::abi_encode_returns(
+                    &self.current_redeemer.unwrap_or_default(),
+                )
+                .into(),
+            ),
+            ArbRetryableTxCalls::getTimeout(call) => {
+                match retryables.open(call.ticketId, env.timestamp)? {
+                    Some((timeout, windows)) => RetryableCall::Return(
+                        ArbRetryableTx::getTimeoutCall::abi_encode_returns(&U256::from(
+                            expires_at(timeout, windows),
+                        ))
+                        .into(),
+                    ),
+                    None => no_ticket(),
+                }
+            }
+            ArbRetryableTxCalls::getBeneficiary(call) => {
+                match retryables.open(call.ticketId, env.timestamp)? {
+                    Some(_) => {
+                        let slot = retryables.ticket_space(call.ticketId).slot(BENEFICIARY_OFFSET);
+                        let beneficiary = retryables.load_address(slot)?;
+                        RetryableCall::Return(
+                            ArbRetryableTx::getBeneficiaryCall::abi_encode_returns(&beneficiary)
+                                .into(),
+                        )
+                    }
+                    None => no_ticket(),
+                }
+            }
+            ArbRetryableTxCalls::keepalive(call) => {
+                match retryables.keepalive(call.ticketId, env.timestamp)? {
+                    Ok(Some(new_timeout)) => {
+                        gas_used += RETRYABLE_REAP_PRICE;
+                        logs.push(precompile_log(ArbRetryableTx::LifetimeExtended {
+                            ticketId: call.ticketId,
+                            newTimeout: U256::from(new_timeout),
+                        }));
+                        RetryableCall::Return(
+                            ArbRetryableTx::keepaliveCall::abi_encode_returns(&U256::from(
+                                new_timeout,
+                            ))
+                            .into(),
+                        )
+                    }
+                    Ok(None) => no_ticket(),
+                    Err(err) => revert(err),
+                }
+            }
+            ArbRetryableTxCalls::cancel(call) => {
+                match retryables.get(call.ticketId, env.timestamp)? {
+                    Some(ticket) if ticket.beneficiary != caller => {
+                        revert("only the beneficiary may cancel a retryable")
+                    }
+                    Some(ticket) => {
+                        // the refund goes first: a failed transfer changes nothing, so the ticket
+                        // is kept when the cancel reverts
+                        let journal = &mut *retryables.storage_mut().journal;
+                        if let Some(err) = journal.transfer(
+                            ticket.escrow(),
+                            ticket.beneficiary,
+                            ticket.callvalue,
+                        )? {
+                            revert(&format!("failed to refund the retryable call value: {err:?}"))
+                        } else {
+                            retryables.delete(ticket.id)?;
+                            logs.push(precompile_log(ArbRetryableTx::Canceled {
+                                ticketId: ticket.id,
+                            }));
+                            RetryableCall::Return(Bytes::new())
+                        }
+                    }
+                    None => no_ticket(),
+                }
+            }
+            ArbRetryableTxCalls::redeem(call) => {
+                match retryables.get(call.ticketId, env.timestamp)? {
+                    Some(ticket) => {
+                        // what is left after the costs of the remainder of the call is donated
+                        let future_gas =
+                            log_gas(4, 4 * 32) + COPY_GAS + STORAGE_READ_COST + STORAGE_WRITE_COST;
+                        let used = gas_used + retryables.storage_mut().gas_used + future_gas;
+                        match env.gas_limit.checked_sub(used) {
+                            Some(donated) if donated >= TX_GAS => {
+                                let (redeem, log) = retryables.schedule_redeem(
+                                    &ticket,
+                                    env.chain_id,
+                                    env.basefee,
+                                    donated,
+                                    caller,
+                                )?;
+                                journal_logs(&mut *retryables.storage_mut().journal, [log]);
+                                return Ok(Some((RetryableCall::Redeem(redeem), env.gas_limit)));
+                            }
+                            Some(_) => revert("not enough gas to run redeem attempt"),
+                            None => RetryableCall::OutOfGas,
+                        }
+                    }
+                    None => no_ticket(),
+                }
+            }
+            _ => unreachable!(),
+        };
+
+        let storage = retryables.storage_mut();
+        gas_used += storage.gas_used
+            + logs.iter().map(|log| log_gas(log.topics().len(), log.data.data.len())).sum::<u64>();
+        if let RetryableCall::Return(output) | RetryableCall::Revert(output) = &outcome {
+            gas_used += copy_gas(output.len());
+        }
+        if matches!(outcome, RetryableCall::OutOfGas) || gas_used > env.gas_limit {
+            return Ok(Some((RetryableCall::OutOfGas, env.gas_limit)));
+        }
+        // a reverted call does not emit its logs
+        if !matches!(outcome, RetryableCall::Revert(_)) {
+            journal_logs(&mut *storage.journal, logs);
+        }
+        Ok(Some((outcome, gas_used)))
+    }
+}
+
+/// Returns the `nil` address as ArbOS stores it, e.g. for the target of contract creations.
+fn nil_address() -> U256 {
+    U256::from(1) << 255
+}
+
+/// Returns the gas charged for copying `len` bytes of arguments or results of a precompile.
+pub(crate) fn copy_gas(len: usize) -> u64 {
+    COPY_GAS * (len as u64).div_ceil(32)
+}
+
+fn log_gas(topics: usize, data_len: usize) -> u64 {
+    375 + 375 * topics as u64 + 8 * data_len as u64
+}
+
+fn journal_logs<J: JournalTr>(journal: &mut J, logs: impl IntoIterator<Item = Log>) {
+    for log in logs {
+        journal.log(log);
+    }
+}
+
+fn no_ticket() -> RetryableCall {
+    RetryableCall::Revert(ArbRetryableTx::NoTicketWithID {}.abi_encode().into())
+}
+
+fn revert(reason: &str) -> RetryableCall {
+    RetryableCall::Revert(alloy_sol_types::Revert::from(reason).abi_encode().into())
+}
+
+fn precompile_log(event: impl SolEvent) -> Log {
+    Log { address: ARB_RETRYABLE_TX, data: event.encode_log_data() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arbos::MemoryStorage;
+
+    #[test]
+    fn ticket_lifecycle() {
+        let mut retryables = RetryableState::new(MemoryStorage::default());
+        let tx = TxArbitrumSubmitRetryable {
+            from: Address::repeat_byte(0x11),
+            retry_to: TxKind::Call(Address::repeat_byte(0x22)),
+            retry_value: U256::from(1),
+            beneficiary: Address::repeat_byte(0x33),
+            retry_data: Bytes::from(vec![0xab; 40]),
+            ..Default::default()
+        };
+        let (ticket, log) = retryables.create(&tx, 100).unwrap();
+        assert_eq!(log.topics()[1], ticket.id);
+        assert_eq!(ticket.expires_at(), 100 + RETRYABLE_LIFETIME_SECONDS);
+        assert_eq!(retryables.get(ticket.id, ticket.expires_at()).unwrap(), Some(ticket.clone()));
+        assert_eq!(retryables.get(ticket.id, ticket.expires_at() + 1).unwrap(), None);
+
+        // a ticket can be extended once its timeout is at most a lifetime away
+        let extended = ticket.expires_at() + RETRYABLE_LIFETIME_SECONDS;
+        assert_eq!(retryables.keepalive(ticket.id, 100).unwrap(), Ok(Some(extended)));
+        assert!(retryables.keepalive(ticket.id, 100).unwrap().is_err());
+        assert_eq!(
+            retryables.keepalive(ticket.id, extended).unwrap(),
+            Ok(Some(extended + RETRYABLE_LIFETIME_SECONDS))
+        );
+
+        let (redeem, _) = retryables
+            .schedule_redeem(&ticket, U256::from(1), U256::ZERO, 100_000, tx.from)
+            .unwrap();
+        assert_eq!(redeem.retry.nonce, 0);
+        assert_eq!(redeem.retry.data, tx.retry_data);
+        assert_eq!(retryables.get(ticket.id, 100).unwrap().unwrap().num_tries, 1);
+
+        retryables.delete(ticket.id).unwrap();
+        assert_eq!(retryables.get(ticket.id, 100).unwrap(), None);
+        // only the timeout queue is left
+        let queue = retryables.space.subspace(TIMEOUT_QUEUE_KEY);
+        let queue_slots = (0..5).map(|offset| queue.slot(offset)).collect::<Vec<_>>();
+        assert!(
+            retryables
+                .storage
+                .0
+                .iter()
+                .all(|(slot, value)| value.is_zero() || queue_slots.contains(slot))
+        );
+    }
+}
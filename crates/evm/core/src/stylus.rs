@@ -0,0 +1,183 @@
+//! Stylus program helpers.
+//!
+//! Shared by the cheatcodes, the trace identifiers and the CLI so that every component converts
+//! WASM artifacts into deployed Stylus code the same way `cargo stylus deploy` does.
+
+use alloy_primitives::{Address, Bytes, U256, hex};
+use arbos_revm::{
+    constants::STYLUS_DISCRIMINANT,
+    utils::{Dictionary, brotli_compress, brotli_decompress, strip_wasm_for_stylus},
+};
+use eyre::Result;
+use revm::{
+    context::JournalTr,
+    interpreter::{CallInputs, InstructionResult, InterpreterResult},
+};
+use std::fmt;
+
+/// The magic number every WASM binary starts with.
+pub const WASM_MAGIC: [u8; 4] = [0x00, 0x61, 0x73, 0x6d];
+
+/// The selector of `stylus_constructor()`, which Stylus programs are initialized through after
+/// activation.
+pub const STYLUS_CONSTRUCTOR_SELECTOR: [u8; 4] = [0x55, 0x85, 0x25, 0x8d];
+
+/// The percentage activation data fees are bumped by when broadcasting, to account for price
+/// changes before the transaction is included. The excess is refunded.
+pub const DATA_FEE_BUMP_PERCENT: u64 = 20;
+
+/// Returns `true` if the given code is a Stylus program, i.e. starts with the Stylus
+/// discriminant.
+pub fn is_stylus_code(code: &[u8]) -> bool {
+    code.starts_with(STYLUS_DISCRIMINANT)
+}
+
+/// The reason a Stylus program halted abnormally.
+///
+/// Only the traps arbos-revm ends the program's call distinctly for are known. Programs executing
+/// `unreachable`, e.g. because they panicked or could not grow their memory past `page_limit`,
+/// revert without data, like programs reverting on purpose, so they are not reported as traps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StylusTrapKind {
+    /// The program ran out of ink.
+    OutOfInk,
+    /// The program exceeded its maximum stack depth, `max_stack_depth`.
+    StackOverflow,
+}
+
+impl StylusTrapKind {
+    /// Returns the trap a Stylus call frame halted with, if its result is one.
+    ///
+    /// arbos-revm ends Stylus frames the way Nitro does: a program running out of ink halts with
+    /// `OutOfGas`, and one exceeding `max_stack_depth` with `CallTooDeep`.
+    pub fn from_result(result: &InterpreterResult) -> Option<Self> {
+        match result.result {
+            InstructionResult::OutOfGas => Some(Self::OutOfInk),
+            InstructionResult::CallTooDeep | InstructionResult::StackOverflow => {
+                Some(Self::StackOverflow)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for StylusTrapKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfInk => f.write_str("out of ink"),
+            Self::StackOverflow => f.write_str("stack overflow"),
+        }
+    }
+}
+
+/// A Stylus program halting abnormally.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StylusTrap {
+    /// Why the program halted.
+    pub kind: StylusTrapKind,
+    /// The address of the program.
+    pub address: Address,
+}
+
+impl fmt::Display for StylusTrap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stylus trap: {} in {}", self.kind, self.address)
+    }
+}
+
+/// Returns the trap the Stylus program executed by a call halted with, if any.
+///
+/// Only loads the code of the call's bytecode address when the result looks like a trap, so
+/// ordinary calls are not slowed down.
+pub fn call_stylus_trap<J: JournalTr>(
+    journal: &mut J,
+    inputs: &CallInputs,
+    result: &InterpreterResult,
+) -> Option<StylusTrap> {
+    let kind = StylusTrapKind::from_result(result)?;
+    let code = journal.code(inputs.bytecode_address).ok()?.data;
+    is_stylus_code(&code).then_some(StylusTrap { kind, address: inputs.target_address })
+}
+
+/// Converts a Stylus artifact into the runtime code that is stored on chain.
+///
+/// Accepts either an uncompressed WASM binary, which is stripped of custom sections and
+/// brotli-compressed, or an already compressed binary. The result is prefixed with the Stylus
+/// discriminant and dictionary byte unless it already is.
+pub fn stylus_runtime_code(artifact: Vec<u8>) -> Result<Bytes> {
+    // If it starts with the WASM magic number, we first need to strip and compress it.
+    let artifact = if artifact.starts_with(&WASM_MAGIC) {
+        // Strip user metadata and dangling reference types
+        let artifact = strip_wasm_for_stylus(&artifact)
+            .map_err(|e| eyre::eyre!("failed to strip WASM for Stylus: {e}"))?;
+
+        brotli_compress(&artifact, 11, 22, Dictionary::Empty)
+            .map_err(|_| eyre::eyre!("failed to compress stylus artifact"))?
+    } else {
+        artifact
+    };
+
+    let artifact = if is_stylus_code(&artifact) {
+        artifact
+    } else {
+        [STYLUS_DISCRIMINANT, &[0], artifact.as_ref()].concat()
+    };
+
+    Ok(Bytes::from(artifact))
+}
+
+/// Recovers the WASM binary of a Stylus program from the runtime code stored on chain.
+///
+/// This undoes [`stylus_runtime_code`], so the result is the stripped binary that was deployed.
+pub fn stylus_wasm(runtime_code: &[u8]) -> Result<Vec<u8>> {
+    let Some(rest) = runtime_code.strip_prefix(STYLUS_DISCRIMINANT) else {
+        eyre::bail!("not a Stylus program");
+    };
+    let (dictionary, compressed) =
+        rest.split_first().ok_or_else(|| eyre::eyre!("missing Stylus dictionary byte"))?;
+    let dictionary = match dictionary {
+        0 => Dictionary::Empty,
+        1 => Dictionary::StylusProgram,
+        _ => eyre::bail!("unknown Stylus dictionary {dictionary}"),
+    };
+    brotli_decompress(compressed, dictionary)
+        .map_err(|_| eyre::eyre!("failed to decompress Stylus program"))
+}
+
+/// Returns the calldata of a call to the constructor of a Stylus program with the given
+/// ABI-encoded arguments.
+pub fn stylus_constructor_calldata(args: &[u8]) -> Bytes {
+    [STYLUS_CONSTRUCTOR_SELECTOR.as_slice(), args].concat().into()
+}
+
+/// Bumps an activation data fee by `percent`, which is usually [`DATA_FEE_BUMP_PERCENT`].
+pub fn bump_data_fee(data_fee: U256, percent: u64) -> U256 {
+    data_fee + data_fee * U256::from(percent) / U256::from(100)
+}
+
+/// Wraps Stylus runtime code in EVM init code that deploys it with an empty constructor.
+pub fn stylus_init_code(runtime_code: &[u8]) -> Bytes {
+    let mut init = Vec::with_capacity(2 + runtime_code.len() + 4);
+
+    // step 1: fixed header
+    init.extend_from_slice(hex!("608060405234801561001057600080fd5b50").as_slice());
+
+    // step 2: push the bytecode length (always 2 bytes)
+    init.extend_from_slice(&[0x61]);
+
+    let bytecode_len = runtime_code.len();
+
+    // always returns at least 32 bytes
+    let bytecode_len_bytes = bytecode_len.to_be_bytes();
+
+    // can safely ignore the first 30 bytes as length is always less than 2^16
+    init.extend_from_slice(&bytecode_len_bytes[bytecode_len_bytes.len() - 2..]);
+
+    // step 3: fixed footer
+    init.extend_from_slice(hex!("806100206000396000f3fe").as_slice());
+
+    // push the bytecode itself
+    init.extend_from_slice(runtime_code);
+
+    init.into()
+}
@@ -16,9 +16,12 @@
     eip7702::{RecoveredAuthority, RecoveredAuthorization},
 };
 use alloy_primitives::{Address, Bytes, TxKind};
+use arbitrum::ArbitrumTxEnvelope;
 use arbos_revm::transaction::ArbitrumTransaction;
 use revm::{context::TxEnv, context_interface::either::Either};
 
+pub mod arbitrum;
+
 /// Type alias for the Foundry transaction environment.
 pub type FoundryTxEnv = ArbitrumTransaction;
 
@@ -298,6 +301,29 @@ fn from_encoded_tx(tx: &TxEip7702, sender: Address, _encoded: Bytes) -> Self {
     }
 }
 
+impl FromRecoveredTx<ArbitrumTxEnvelope> for TxEnv {
+    fn from_recovered_tx(tx: &ArbitrumTxEnvelope, caller: Address) -> Self {
+        Self {
+            tx_type: tx.ty(),
+            caller,
+            gas_limit: tx.gas_limit(),
+            gas_price: tx.gas_fee_cap().saturating_to(),
+            kind: tx.kind(),
+            value: tx.value(),
+            data: tx.input(),
+            nonce: tx.nonce(),
+            chain_id: Some(tx.chain_id().saturating_to()),
+            ..Default::default()
+        }
+    }
+}
+
+impl FromTxWithEncoded<ArbitrumTxEnvelope> for TxEnv {
+    fn from_encoded_tx(tx: &ArbitrumTxEnvelope, sender: Address, _encoded: Bytes) -> Self {
+        Self::from_recovered_tx(tx, sender)
+    }
+}
+
 /// Abstracts over different `Recovered<T>` implementations.
 #[auto_impl::auto_impl(&)]
 pub trait RecoveredTx<T> {
@@ -506,6 +532,18 @@ fn from_encoded_tx(tx: &TxEip7702, sender: Address, _encoded: Bytes) -> Self {
     }
 }
 
+impl FromRecoveredTx<ArbitrumTxEnvelope> for FoundryTxEnv {
+    fn from_recovered_tx(tx: &ArbitrumTxEnvelope, sender: Address) -> Self {
+        TxEnv::from_recovered_tx(tx, sender).into()
+    }
+}
+
+impl FromTxWithEncoded<ArbitrumTxEnvelope> for FoundryTxEnv {
+    fn from_encoded_tx(tx: &ArbitrumTxEnvelope, sender: Address, _encoded: Bytes) -> Self {
+        TxEnv::from_recovered_tx(tx, sender).into()
+    }
+}
+
 impl<Eip4844: AsRef<TxEip4844>> FromTxWithEncoded<EthereumTxEnvelope<Eip4844>> for FoundryTxEnv {
     fn from_encoded_tx(tx: &EthereumTxEnvelope<Eip4844>, caller: Address, encoded: Bytes) -> Self {
         TxEnv::from_encoded_tx(tx, caller, encoded).into()
@@ -0,0 +1,435 @@
+//! Arbitrum transaction types.
+//!
+//! These are the transaction types Nitro adds on top of the Ethereum ones. They are not signed:
+//! they are either derived from L1 messages or created by ArbOS itself, and carry their sender.
+
+use alloy_eips::Typed2718;
+use alloy_primitives::{Address, B256, Bytes, TxKind, U256, address, keccak256};
+use alloy_rlp::{Decodable, Encodable, RlpDecodable, RlpEncodable};
+use alloy_sol_types::SolCall;
+use foundry_evm_abi::arbitrum::ArbRetryableTx;
+use foundry_evm_networks::arbitrum::ARB_RETRYABLE_TX;
+use serde::{Deserialize, Serialize};
+
+/// Identifier of an [`TxArbitrumDeposit`] transaction.
+pub const ARBITRUM_DEPOSIT_TX_TYPE_ID: u8 = 0x64;
+
+/// Identifier of an [`TxArbitrumUnsigned`] transaction.
+pub const ARBITRUM_UNSIGNED_TX_TYPE_ID: u8 = 0x65;
+
+/// Identifier of an [`TxArbitrumContract`] transaction.
+pub const ARBITRUM_CONTRACT_TX_TYPE_ID: u8 = 0x66;
+
+/// Identifier of an [`TxArbitrumRetry`] transaction.
+pub const ARBITRUM_RETRY_TX_TYPE_ID: u8 = 0x68;
+
+/// Identifier of an [`TxArbitrumSubmitRetryable`] transaction.
+pub const ARBITRUM_SUBMIT_RETRYABLE_TX_TYPE_ID: u8 = 0x69;
+
+/// Identifier of an [`TxArbitrumInternal`] transaction.
+pub const ARBITRUM_INTERNAL_TX_TYPE_ID: u8 = 0x6a;
+
+/// The address ArbOS sends internal transactions from.
+pub const ARBOS_ADDRESS: Address = address!("0x00000000000000000000000000000000000a4b05");
+
+/// Returns `true` if `ty` is one of the Arbitrum transaction types.
+pub const fn is_arbitrum_tx_type(ty: u8) -> bool {
+    matches!(
+        ty,
+        ARBITRUM_DEPOSIT_TX_TYPE_ID
+            | ARBITRUM_UNSIGNED_TX_TYPE_ID
+            | ARBITRUM_CONTRACT_TX_TYPE_ID
+            | ARBITRUM_RETRY_TX_TYPE_ID
+            | ARBITRUM_SUBMIT_RETRYABLE_TX_TYPE_ID
+            | ARBITRUM_INTERNAL_TX_TYPE_ID
+    )
+}
+
+/// An ETH deposit from L1, which mints `value` to `to`.
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, RlpEncodable, RlpDecodable,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TxArbitrumDeposit {
+    pub chain_id: U256,
+    pub request_id: B256,
+    pub from: Address,
+    pub to: Address,
+    pub value: U256,
+}
+
+/// A call from an L1 account, sent through the delayed inbox.
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, RlpEncodable, RlpDecodable,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TxArbitrumUnsigned {
+    pub chain_id: U256,
+    pub from: Address,
+    #[serde(with = "alloy_serde::quantity")]
+    pub nonce: u64,
+    #[serde(rename = "maxFeePerGas")]
+    pub gas_fee_cap: U256,
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas: u64,
+    #[serde(default)]
+    pub to: TxKind,
+    pub value: U256,
+    #[serde(rename = "input")]
+    pub data: Bytes,
+}
+
+/// A call from an L1 contract, sent through the delayed inbox. Unlike [`TxArbitrumUnsigned`], it
+/// does not use the sender's nonce.
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, RlpEncodable, RlpDecodable,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TxArbitrumContract {
+    pub chain_id: U256,
+    pub request_id: B256,
+    pub from: Address,
+    #[serde(rename = "maxFeePerGas")]
+    pub gas_fee_cap: U256,
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas: u64,
+    #[serde(default)]
+    pub to: TxKind,
+    pub value: U256,
+    #[serde(rename = "input")]
+    pub data: Bytes,
+}
+
+/// The redemption of a retryable ticket, either automatic or through `ArbRetryableTx.redeem`.
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, RlpEncodable, RlpDecodable,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TxArbitrumRetry {
+    pub chain_id: U256,
+    #[serde(with = "alloy_serde::quantity")]
+    pub nonce: u64,
+    pub from: Address,
+    #[serde(rename = "maxFeePerGas")]
+    pub gas_fee_cap: U256,
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas: u64,
+    #[serde(default)]
+    pub to: TxKind,
+    pub value: U256,
+    #[serde(rename = "input")]
+    pub data: Bytes,
+    pub ticket_id: B256,
+    pub refund_to: Address,
+    pub max_refund: U256,
+    pub submission_fee_refund: U256,
+}
+
+/// The submission of a retryable ticket from L1.
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, RlpEncodable, RlpDecodable,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TxArbitrumSubmitRetryable {
+    pub chain_id: U256,
+    pub request_id: B256,
+    pub from: Address,
+    pub l1_base_fee: U256,
+    pub deposit_value: U256,
+    #[serde(rename = "maxFeePerGas")]
+    pub gas_fee_cap: U256,
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas: u64,
+    #[serde(default)]
+    pub retry_to: TxKind,
+    pub retry_value: U256,
+    pub beneficiary: Address,
+    pub max_submission_fee: U256,
+    #[serde(rename = "refundTo")]
+    pub fee_refund_addr: Address,
+    pub retry_data: Bytes,
+}
+
+impl TxArbitrumSubmitRetryable {
+    /// Returns the `ArbRetryableTx.submitRetryable` call this transaction executes as.
+    pub fn calldata(&self) -> Bytes {
+        ArbRetryableTx::submitRetryableCall {
+            requestId: self.request_id,
+            l1BaseFee: self.l1_base_fee,
+            deposit: self.deposit_value,
+            callvalue: self.retry_value,
+            gasFeeCap: self.gas_fee_cap,
+            gasLimit: self.gas,
+            maxSubmissionFee: self.max_submission_fee,
+            feeRefundAddress: self.fee_refund_addr,
+            beneficiary: self.beneficiary,
+            retryTo: self.retry_to.to().copied().unwrap_or_default(),
+            retryData: self.retry_data.clone(),
+        }
+        .abi_encode()
+        .into()
+    }
+}
+
+/// A transaction ArbOS creates itself, e.g. at the start of every block.
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, RlpEncodable, RlpDecodable,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TxArbitrumInternal {
+    pub chain_id: U256,
+    #[serde(rename = "input")]
+    pub data: Bytes,
+}
+
+/// One of the Arbitrum transaction types.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ArbitrumTxEnvelope {
+    #[serde(rename = "0x64")]
+    Deposit(TxArbitrumDeposit),
+    #[serde(rename = "0x65")]
+    Unsigned(TxArbitrumUnsigned),
+    #[serde(rename = "0x66")]
+    Contract(TxArbitrumContract),
+    #[serde(rename = "0x68")]
+    Retry(TxArbitrumRetry),
+    #[serde(rename = "0x69")]
+    SubmitRetryable(TxArbitrumSubmitRetryable),
+    #[serde(rename = "0x6a")]
+    Internal(TxArbitrumInternal),
+}
+
+impl ArbitrumTxEnvelope {
+    /// Returns the sender of the transaction.
+    pub fn sender(&self) -> Address {
+        match self {
+            Self::Deposit(tx) => tx.from,
+            Self::Unsigned(tx) => tx.from,
+            Self::Contract(tx) => tx.from,
+            Self::Retry(tx) => tx.from,
+            Self::SubmitRetryable(tx) => tx.from,
+            Self::Internal(_) => ARBOS_ADDRESS,
+        }
+    }
+
+    pub fn chain_id(&self) -> U256 {
+        match self {
+            Self::Deposit(tx) => tx.chain_id,
+            Self::Unsigned(tx) => tx.chain_id,
+            Self::Contract(tx) => tx.chain_id,
+            Self::Retry(tx) => tx.chain_id,
+            Self::SubmitRetryable(tx) => tx.chain_id,
+            Self::Internal(tx) => tx.chain_id,
+        }
+    }
+
+    /// Returns the nonce of the transaction. Only unsigned and retry transactions have one.
+    pub fn nonce(&self) -> u64 {
+        match self {
+            Self::Unsigned(tx) => tx.nonce,
+            Self::Retry(tx) => tx.nonce,
+            _ => 0,
+        }
+    }
+
+    pub fn gas_limit(&self) -> u64 {
+        match self {
+            Self::Unsigned(tx) => tx.gas,
+            Self::Contract(tx) => tx.gas,
+            Self::Retry(tx) => tx.gas,
+            Self::SubmitRetryable(tx) => tx.gas,
+            Self::Deposit(_) | Self::Internal(_) => 0,
+        }
+    }
+
+    pub fn gas_fee_cap(&self) -> U256 {
+        match self {
+            Self::Unsigned(tx) => tx.gas_fee_cap,
+            Self::Contract(tx) => tx.gas_fee_cap,
+            Self::Retry(tx) => tx.gas_fee_cap,
+            Self::SubmitRetryable(tx) => tx.gas_fee_cap,
+            Self::Deposit(_) | Self::Internal(_) => U256::ZERO,
+        }
+    }
+
+    /// Returns the value transferred by the call the transaction executes.
+    pub fn value(&self) -> U256 {
+        match self {
+            Self::Deposit(tx) => tx.value,
+            Self::Unsigned(tx) => tx.value,
+            Self::Contract(tx) => tx.value,
+            Self::Retry(tx) => tx.value,
+            Self::SubmitRetryable(_) | Self::Internal(_) => U256::ZERO,
+        }
+    }
+
+    /// Returns the callee of the transaction.
+    ///
+    /// Retryable submissions call `ArbRetryableTx` and internal transactions call ArbOS.
+    pub fn kind(&self) -> TxKind {
+        match self {
+            Self::Deposit(tx) => TxKind::Call(tx.to),
+            Self::Unsigned(tx) => tx.to,
+            Self::Contract(tx) => tx.to,
+            Self::Retry(tx) => tx.to,
+            Self::SubmitRetryable(_) => TxKind::Call(ARB_RETRYABLE_TX),
+            Self::Internal(_) => TxKind::Call(ARBOS_ADDRESS),
+        }
+    }
+
+    /// Returns the calldata of the transaction.
+    pub fn input(&self) -> Bytes {
+        match self {
+            Self::Deposit(_) => Bytes::new(),
+            Self::Unsigned(tx) => tx.data.clone(),
+            Self::Contract(tx) => tx.data.clone(),
+            Self::Retry(tx) => tx.data.clone(),
+            Self::SubmitRetryable(tx) => tx.calldata(),
+            Self::Internal(tx) => tx.data.clone(),
+        }
+    }
+
+    /// Returns the account and amount of ETH minted by ArbOS before the transaction executes.
+    pub fn mint(&self) -> Option<(Address, U256)> {
+        match self {
+            Self::Deposit(tx) => Some((tx.to, tx.value)),
+            Self::SubmitRetryable(tx) => Some((tx.from, tx.deposit_value)),
+            _ => None,
+        }
+    }
+
+    /// Returns the data field of the transaction, which for retryable submissions is the data of
+    /// the retry.
+    pub fn data(&self) -> &Bytes {
+        static EMPTY: Bytes = Bytes::new();
+        match self {
+            Self::Deposit(_) => &EMPTY,
+            Self::Unsigned(tx) => &tx.data,
+            Self::Contract(tx) => &tx.data,
+            Self::Retry(tx) => &tx.data,
+            Self::SubmitRetryable(tx) => &tx.retry_data,
+            Self::Internal(tx) => &tx.data,
+        }
+    }
+
+    /// Returns `true` if the sender's nonce is not checked against the transaction, as for every
+    /// Arbitrum transaction type but unsigned ones.
+    pub fn skip_account_checks(&self) -> bool {
+        !matches!(self, Self::Unsigned(_))
+    }
+
+    /// Returns `true` if the transaction is applied by ArbOS without executing any code.
+    pub fn is_system(&self) -> bool {
+        matches!(self, Self::Deposit(_) | Self::Internal(_))
+    }
+
+    /// Returns the hash of the transaction.
+    pub fn hash(&self) -> B256 {
+        let mut buf = Vec::with_capacity(self.encode_2718_len());
+        self.encode_2718(&mut buf);
+        keccak256(buf)
+    }
+
+    fn payload_length(&self) -> usize {
+        match self {
+            Self::Deposit(tx) => tx.length(),
+            Self::Unsigned(tx) => tx.length(),
+            Self::Contract(tx) => tx.length(),
+            Self::Retry(tx) => tx.length(),
+            Self::SubmitRetryable(tx) => tx.length(),
+            Self::Internal(tx) => tx.length(),
+        }
+    }
+
+    /// Returns the length of the EIP-2718 encoding of the transaction.
+    pub fn encode_2718_len(&self) -> usize {
+        1 + self.payload_length()
+    }
+
+    /// Encodes the transaction as `type || rlp(fields)`.
+    pub fn encode_2718(&self, out: &mut dyn alloy_rlp::BufMut) {
+        out.put_u8(self.ty());
+        match self {
+            Self::Deposit(tx) => tx.encode(out),
+            Self::Unsigned(tx) => tx.encode(out),
+            Self::Contract(tx) => tx.encode(out),
+            Self::Retry(tx) => tx.encode(out),
+            Self::SubmitRetryable(tx) => tx.encode(out),
+            Self::Internal(tx) => tx.encode(out),
+        }
+    }
+
+    /// Decodes the RLP fields of a transaction of type `ty`.
+    pub fn typed_decode(ty: u8, buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        Ok(match ty {
+            ARBITRUM_DEPOSIT_TX_TYPE_ID => Self::Deposit(Decodable::decode(buf)?),
+            ARBITRUM_UNSIGNED_TX_TYPE_ID => Self::Unsigned(Decodable::decode(buf)?),
+            ARBITRUM_CONTRACT_TX_TYPE_ID => Self::Contract(Decodable::decode(buf)?),
+            ARBITRUM_RETRY_TX_TYPE_ID => Self::Retry(Decodable::decode(buf)?),
+            ARBITRUM_SUBMIT_RETRYABLE_TX_TYPE_ID => Self::SubmitRetryable(Decodable::decode(buf)?),
+            ARBITRUM_INTERNAL_TX_TYPE_ID => Self::Internal(Decodable::decode(buf)?),
+            _ => return Err(alloy_rlp::Error::Custom("unexpected Arbitrum tx type")),
+        })
+    }
+}
+
+impl Typed2718 for ArbitrumTxEnvelope {
+    fn ty(&self) -> u8 {
+        match self {
+            Self::Deposit(_) => ARBITRUM_DEPOSIT_TX_TYPE_ID,
+            Self::Unsigned(_) => ARBITRUM_UNSIGNED_TX_TYPE_ID,
+            Self::Contract(_) => ARBITRUM_CONTRACT_TX_TYPE_ID,
+            Self::Retry(_) => ARBITRUM_RETRY_TX_TYPE_ID,
+            Self::SubmitRetryable(_) => ARBITRUM_SUBMIT_RETRYABLE_TX_TYPE_ID,
+            Self::Internal(_) => ARBITRUM_INTERNAL_TX_TYPE_ID,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn encode_decode_roundtrip() {
+        let txs = [
+            ArbitrumTxEnvelope::Deposit(TxArbitrumDeposit {
+                chain_id: U256::from(42161),
+                request_id: B256::repeat_byte(1),
+                from: Address::repeat_byte(2),
+                to: Address::repeat_byte(3),
+                value: U256::from(1_000_000_000_000_000_000u128),
+            }),
+            ArbitrumTxEnvelope::Retry(TxArbitrumRetry {
+                chain_id: U256::from(42161),
+                nonce: 1,
+                from: Address::repeat_byte(2),
+                gas_fee_cap: U256::from(100_000_000),
+                gas: 100_000,
+                to: TxKind::Create,
+                data: hex!("6080").into(),
+                ticket_id: B256::repeat_byte(4),
+                ..Default::default()
+            }),
+            ArbitrumTxEnvelope::Internal(TxArbitrumInternal {
+                chain_id: U256::from(42161),
+                data: hex!("6bf6a42d").into(),
+            }),
+        ];
+
+        for tx in txs {
+            let mut buf = Vec::new();
+            tx.encode_2718(&mut buf);
+            assert_eq!(buf.len(), tx.encode_2718_len());
+            assert_eq!(tx.hash(), keccak256(&buf));
+
+            let decoded = ArbitrumTxEnvelope::typed_decode(buf[0], &mut &buf[1..]).unwrap();
+            assert_eq!(decoded, tx);
+
+            let json = serde_json::to_value(&tx).unwrap();
+            assert_eq!(serde_json::from_value::<ArbitrumTxEnvelope>(json).unwrap(), tx);
+        }
+    }
+}
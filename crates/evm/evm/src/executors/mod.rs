@@ -32,6 +32,7 @@
         DEFAULT_CREATE2_DEPLOYER_CODE, DEFAULT_CREATE2_DEPLOYER_DEPLOYER,
     },
     decode::{RevertDecoder, SkipReason},
+    stylus::StylusTrap,
     utils::StateChangeset,
 };
 use foundry_evm_coverage::HitMaps;
@@ -333,24 +334,13 @@ pub fn create2_deployer(&self) -> Address {
     ///
     /// This creates a temporary journal context, loads `ArbosStateParams` (with defaults
     /// populated from context if state is empty), applies the closure to modify them,
-    /// and only initializes/commits if the params were actually changed.
+    /// and only initializes/commits if the params were actually changed. In fork mode the
+    /// closure is applied on top of the forked chain's state, so the parameters it leaves alone
+    /// and the activated programs are kept.
     #[inline]
     pub fn apply_arbitrum_state_overrides(&mut self, f: impl FnOnce(&mut ArbosStateParams)) {
         let is_fork = self.backend.is_in_forking_mode();
 
-        // First, check if the closure would make any changes using default params.
-        // This avoids touching the database/journal if nothing would change.
-        // For non-fork mode, we can safely skip if defaults are unchanged.
-        let default_params = ArbosStateParams::default();
-        let mut test_params = default_params.clone();
-        f(&mut test_params);
-
-        // If no changes would be made and we're not in fork mode, skip journal operations
-        // In fork mode, we need to read actual state to compare
-        if test_params == default_params && !is_fork {
-            return;
-        }
-
         let changes = {
             let env = &self.env;
             let mut context = ArbitrumContext {
@@ -368,15 +358,16 @@ pub fn apply_arbitrum_state_overrides(&mut self, f: impl FnOnce(&mut ArbosStateP
             // Get current state (with defaults populated from context if empty)
             let original_params = state.get().unwrap();
 
-            // In non-fork mode, use the pre-computed test_params
-            // In fork mode, we already checked that test_params != default_params,
-            // so we should initialize
-            if is_fork {
-                // For fork mode, always initialize with the modified params
-                state.initialize(&test_params).unwrap();
-                context.journaled_state.finalize()
-            } else if test_params != original_params {
-                state.initialize(&test_params).unwrap();
+            // Outside of fork mode the closure is applied to the default params, and nothing is
+            // initialized if it leaves them unchanged. In fork mode it is applied to the remote
+            // chain's state instead.
+            let base_params =
+                if is_fork { original_params.clone() } else { ArbosStateParams::default() };
+            let mut params = base_params.clone();
+            f(&mut params);
+
+            if params != base_params && params != original_params {
+                state.initialize(&params).unwrap();
                 context.journaled_state.finalize()
             } else {
                 Default::default()
@@ -946,6 +937,8 @@ pub struct RawCallResult {
     /// The chisel state
     pub chisel_state: Option<(Vec<U256>, Vec<u8>)>,
     pub reverter: Option<Address>,
+    /// The Stylus trap the call failed with, if any
+    pub stylus_trap: Option<StylusTrap>,
 }
 
 impl Default for RawCallResult {
@@ -970,6 +963,7 @@ fn default() -> Self {
             out: None,
             chisel_state: None,
             reverter: None,
+            stylus_trap: None,
         }
     }
 }
@@ -997,7 +991,10 @@ pub fn into_evm_error(self, rd: Option<&RevertDecoder>) -> EvmError {
         if let Some(reason) = SkipReason::decode(&self.result) {
             return EvmError::Skip(reason);
         }
-        let reason = rd.unwrap_or_default().decode(&self.result, self.exit_reason);
+        let reason = match &self.stylus_trap {
+            Some(trap) if self.result.is_empty() => trap.to_string(),
+            _ => rd.unwrap_or_default().decode(&self.result, self.exit_reason),
+        };
         EvmError::Execution(Box::new(self.into_execution_error(reason)))
     }
 
@@ -1148,6 +1145,7 @@ fn convert_executed_result(
         cheatcodes,
         chisel_state,
         reverter,
+        stylus_trap,
     } = inspector.collect();
 
     if logs.is_empty() {
@@ -1179,6 +1177,7 @@ fn convert_executed_result(
         out,
         chisel_state,
         reverter,
+        stylus_trap,
     })
 }
 
@@ -13,6 +13,7 @@
     ContextExt, Env, Evm, FoundryContext, InspectorExt,
     backend::{DatabaseExt, JournaledState},
     evm::new_evm_with_inspector,
+    stylus::{StylusTrap, call_stylus_trap},
 };
 use foundry_evm_coverage::HitMaps;
 use foundry_evm_networks::NetworkConfigs;
@@ -281,6 +282,7 @@ pub struct InspectorData {
     pub cheatcodes: Option<Box<Cheatcodes>>,
     pub chisel_state: Option<(Vec<U256>, Vec<u8>)>,
     pub reverter: Option<Address>,
+    pub stylus_trap: Option<StylusTrap>,
 }
 
 /// Contains data about the state of outer/main EVM which created and invoked the inner EVM context.
@@ -347,6 +349,8 @@ pub struct InspectorStackInner {
     pub top_frame_journal: HashMap<Address, Account>,
     /// Address that reverted the call, if any.
     pub reverter: Option<Address>,
+    /// The Stylus trap the call failed with, if any.
+    pub stylus_trap: Option<StylusTrap>,
 }
 
 /// Struct keeping mutable references to both parts of [InspectorStack] and implementing
@@ -532,6 +536,7 @@ pub fn collect(self) -> InspectorData {
                     log_collector,
                     tracer,
                     reverter,
+                    stylus_trap,
                     ..
                 },
         } = self;
@@ -566,6 +571,7 @@ pub fn collect(self) -> InspectorData {
             cheatcodes,
             chisel_state: chisel_state.and_then(|state| state.state),
             reverter,
+            stylus_trap,
         }
     }
 }
@@ -1036,6 +1042,13 @@ fn call_end(
 
         self.do_call_end(ecx, inputs, outcome);
 
+        // A Stylus trap only explains the failure while it propagates without revert data.
+        if let Some(trap) = call_stylus_trap(&mut ecx.journaled_state, inputs, &outcome.result) {
+            self.stylus_trap = Some(trap);
+        } else if outcome.result.is_ok() || !outcome.output().is_empty() {
+            self.stylus_trap = None;
+        }
+
         if ecx.journaled_state.depth == 0 {
             self.top_level_frame_end(ecx, outcome.result.result);
         }
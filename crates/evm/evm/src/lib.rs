@@ -10,6 +10,7 @@
 
 pub mod executors;
 pub mod inspectors;
+pub mod stylus;
 
 pub use foundry_evm_core as core;
 pub use foundry_evm_core::{
@@ -0,0 +1,108 @@
+//! Stylus programs as known contracts.
+
+use alloy_json_abi::JsonAbi;
+use eyre::{Result, WrapErr};
+use foundry_common::{
+    fs,
+    stylus::{StylusArtifacts, prebuilt_stylus_programs, stylus_abi_path},
+};
+use foundry_compilers::{
+    ArtifactId,
+    artifacts::{
+        BytecodeObject, CompactBytecode, CompactContractBytecodeCow, CompactDeployedBytecode,
+    },
+};
+use foundry_config::{Config, fs_permissions::FsAccessKind};
+use foundry_evm_core::stylus::{stylus_init_code, stylus_runtime_code};
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
+
+/// Returns the Stylus programs of the project as contract artifacts.
+///
+/// These are the programs built from `[stylus.programs]`, and the prebuilt `.wasm` and `.wasm.br`
+/// files with an ABI sidecar file in the readable `fs_permissions` paths, i.e. the programs
+/// `vm.deployStylusCode` can deploy.
+///
+/// The creation code is the EVM init code used by `vm.deployStylusCode` and the runtime code is
+/// the compressed and prefixed program, so deployed programs can be identified like any other
+/// contract. Programs which have not been built yet are skipped.
+pub fn stylus_contracts(
+    config: &Config,
+) -> Result<Vec<(ArtifactId, CompactContractBytecodeCow<'static>)>> {
+    let mut contracts = Vec::new();
+
+    if !config.stylus.programs.is_empty() {
+        let out = config.root.join(&config.out);
+        let artifacts = StylusArtifacts::read(&out)?;
+        for artifact in artifacts.artifacts.values() {
+            if !artifact.wasm.exists() {
+                continue;
+            }
+            let abi = artifact.read_abi()?.unwrap_or_default();
+            let source =
+                artifact.crate_path.strip_prefix(&config.root).unwrap_or(&artifact.crate_path);
+            let contract = stylus_contract(&artifact.wasm, abi)
+                .wrap_err_with(|| format!("failed to load Stylus program `{}`", artifact.name))?;
+            let id = ArtifactId {
+                path: artifact.wasm.clone(),
+                name: artifact.name.clone(),
+                source: source.to_path_buf(),
+                version: semver::Version::new(0, 0, 0),
+                build_id: artifact.source_hash.to_string(),
+                profile: Config::DEFAULT_PROFILE.to_string(),
+            };
+            contracts.push((id, contract));
+        }
+    }
+
+    let dirs: Vec<PathBuf> = config
+        .fs_permissions
+        .permissions
+        .iter()
+        .filter(|permission| permission.is_granted(FsAccessKind::Read))
+        .map(|permission| config.root.join(&permission.path))
+        .collect();
+    for wasm in prebuilt_stylus_programs(dirs.iter().map(PathBuf::as_path)) {
+        // Skip files which are not valid programs, they can't be deployed either.
+        let Ok(abi) = fs::read_json_file(&stylus_abi_path(&wasm)) else { continue };
+        let Ok(contract) = stylus_contract(&wasm, abi) else { continue };
+        let name = wasm.file_name().unwrap_or_default().to_string_lossy();
+        let name = name.strip_suffix(".br").unwrap_or(&name);
+        let name = name.strip_suffix(".wasm").unwrap_or(name).to_string();
+        let id = ArtifactId {
+            source: wasm.strip_prefix(&config.root).unwrap_or(&wasm).to_path_buf(),
+            path: wasm,
+            name,
+            version: semver::Version::new(0, 0, 0),
+            build_id: String::new(),
+            profile: Config::DEFAULT_PROFILE.to_string(),
+        };
+        contracts.push((id, contract));
+    }
+
+    Ok(contracts)
+}
+
+/// Loads the Stylus program at `wasm` as a contract artifact with the given ABI.
+fn stylus_contract(wasm: &Path, abi: JsonAbi) -> Result<CompactContractBytecodeCow<'static>> {
+    let runtime_code = stylus_runtime_code(fs::read(wasm)?)?;
+    let init_code = stylus_init_code(&runtime_code);
+    Ok(CompactContractBytecodeCow {
+        abi: Some(Cow::Owned(abi)),
+        bytecode: Some(Cow::Owned(CompactBytecode {
+            object: BytecodeObject::Bytecode(init_code),
+            source_map: None,
+            link_references: Default::default(),
+        })),
+        deployed_bytecode: Some(Cow::Owned(CompactDeployedBytecode {
+            bytecode: Some(CompactBytecode {
+                object: BytecodeObject::Bytecode(runtime_code),
+                source_map: None,
+                link_references: Default::default(),
+            }),
+            immutable_references: Default::default(),
+        })),
+    })
+}
@@ -0,0 +1,108 @@
+//! Arbitrum precompile addresses and address aliasing.
+
+use alloy_primitives::{Address, U160, address};
+
+/// The ArbSys precompile address.
+pub const ARB_SYS: Address = address!("0x0000000000000000000000000000000000000064");
+
+/// The ArbInfo precompile address.
+pub const ARB_INFO: Address = address!("0x0000000000000000000000000000000000000065");
+
+/// The ArbAddressTable precompile address.
+pub const ARB_ADDRESS_TABLE: Address = address!("0x0000000000000000000000000000000000000066");
+
+/// The ArbOwnerPublic precompile address.
+pub const ARB_OWNER_PUBLIC: Address = address!("0x000000000000000000000000000000000000006b");
+
+/// The ArbGasInfo precompile address.
+pub const ARB_GAS_INFO: Address = address!("0x000000000000000000000000000000000000006c");
+
+/// The ArbAggregator precompile address.
+pub const ARB_AGGREGATOR: Address = address!("0x000000000000000000000000000000000000006d");
+
+/// The ArbRetryableTx precompile address.
+pub const ARB_RETRYABLE_TX: Address = address!("0x000000000000000000000000000000000000006e");
+
+/// The ArbStatistics precompile address.
+pub const ARB_STATISTICS: Address = address!("0x000000000000000000000000000000000000006f");
+
+/// The ArbOwner precompile address.
+pub const ARB_OWNER: Address = address!("0x0000000000000000000000000000000000000070");
+
+/// The ArbWasm precompile address.
+pub const ARB_WASM: Address = address!("0x0000000000000000000000000000000000000071");
+
+/// The ArbWasmCache precompile address.
+pub const ARB_WASM_CACHE: Address = address!("0x0000000000000000000000000000000000000072");
+
+/// The ArbNativeTokenManager precompile address.
+pub const ARB_NATIVE_TOKEN_MANAGER: Address =
+    address!("0x0000000000000000000000000000000000000073");
+
+/// The ArbDebug precompile address.
+pub const ARB_DEBUG: Address = address!("0x00000000000000000000000000000000000000ff");
+
+/// The address of `NodeInterface`, a virtual contract which Nitro nodes answer outside of EVM
+/// execution in `eth_call` and `eth_estimateGas`.
+pub const NODE_INTERFACE: Address = address!("0x00000000000000000000000000000000000000c8");
+
+/// Arbitrum precompile addresses and their names.
+pub const ARBITRUM_PRECOMPILES: &[(Address, &str)] = &[
+    (ARB_SYS, "ArbSys"),
+    (ARB_INFO, "ArbInfo"),
+    (ARB_ADDRESS_TABLE, "ArbAddressTable"),
+    (ARB_OWNER_PUBLIC, "ArbOwnerPublic"),
+    (ARB_GAS_INFO, "ArbGasInfo"),
+    (ARB_AGGREGATOR, "ArbAggregator"),
+    (ARB_RETRYABLE_TX, "ArbRetryableTx"),
+    (ARB_STATISTICS, "ArbStatistics"),
+    (ARB_OWNER, "ArbOwner"),
+    (ARB_WASM, "ArbWasm"),
+    (ARB_WASM_CACHE, "ArbWasmCache"),
+    (ARB_NATIVE_TOKEN_MANAGER, "ArbNativeTokenManager"),
+    (ARB_DEBUG, "ArbDebug"),
+];
+
+/// Returns the name of the Arbitrum precompile at the given address, if any.
+pub fn precompile_name(address: Address) -> Option<&'static str> {
+    ARBITRUM_PRECOMPILES.iter().find(|(addr, _)| *addr == address).map(|(_, name)| *name)
+}
+
+/// The offset added to an L1 contract address to derive its L2 alias.
+///
+/// Messages sent from L1 contracts to L2 execute with the aliased address as `msg.sender`, so
+/// that an L1 contract cannot impersonate an L2 contract deployed at the same address.
+pub const L1_TO_L2_ALIAS_OFFSET: Address = address!("0x1111000000000000000000000000000000001111");
+
+/// Returns the L2 alias of an L1 address.
+pub fn apply_l1_to_l2_alias(address: Address) -> Address {
+    Address::from(U160::from_be_bytes(address.into_array()).wrapping_add(alias_offset()))
+}
+
+/// Returns the L1 address from its L2 alias.
+pub fn undo_l1_to_l2_alias(address: Address) -> Address {
+    Address::from(U160::from_be_bytes(address.into_array()).wrapping_sub(alias_offset()))
+}
+
+fn alias_offset() -> U160 {
+    U160::from_be_bytes(L1_TO_L2_ALIAS_OFFSET.into_array())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn l1_to_l2_alias() {
+        let l1 = address!("0xffffffffffffffffffffffffffffffffffffffff");
+        let alias = apply_l1_to_l2_alias(l1);
+        assert_eq!(alias, address!("0x1111000000000000000000000000000000001110"));
+        assert_eq!(undo_l1_to_l2_alias(alias), l1);
+
+        let l1 = address!("0x0000000000000000000000000000000000000001");
+        assert_eq!(
+            apply_l1_to_l2_alias(l1),
+            address!("0x1111000000000000000000000000000000001112")
+        );
+    }
+}
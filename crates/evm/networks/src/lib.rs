@@ -7,6 +7,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
+pub mod arbitrum;
+
 // Re-export precompile types from foundry-evm-core when available, but define a minimal
 // trait here to avoid circular dependencies.
 
@@ -55,11 +57,17 @@ pub fn inject_precompiles<P: ExtendablePrecompiles>(self, _precompiles: &mut P)
 
     /// Returns precompiles label for configured networks, to be used in traces.
     pub fn precompiles_label(self) -> AddressHashMap<String> {
-        AddressHashMap::default()
+        arbitrum::ARBITRUM_PRECOMPILES
+            .iter()
+            .map(|(address, name)| (*address, name.to_string()))
+            .collect()
     }
 
     /// Returns precompiles for configured networks.
     pub fn precompiles(self) -> BTreeMap<String, Address> {
-        BTreeMap::new()
+        arbitrum::ARBITRUM_PRECOMPILES
+            .iter()
+            .map(|(address, name)| (name.to_string(), *address))
+            .collect()
     }
 }
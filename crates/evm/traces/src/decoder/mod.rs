@@ -14,7 +14,7 @@
     get_contract_name, selectors::SelectorKind,
 };
 use foundry_evm_core::{
-    abi::{Vm, console},
+    abi::{Vm, arbitrum::precompile_abi, console},
     constants::{CALLER, CHEATCODE_ADDRESS, DEFAULT_CREATE2_DEPLOYER, HARDHAT_CONSOLE_ADDRESS},
     decode::RevertDecoder,
     precompiles::{
@@ -22,6 +22,7 @@
         RIPEMD_160, SHA_256,
     },
 };
+use foundry_evm_networks::arbitrum::{ARBITRUM_PRECOMPILES, NODE_INTERFACE};
 use itertools::Itertools;
 use revm_inspectors::tracing::types::{DecodedCallLog, DecodedCallTrace};
 use std::{collections::BTreeMap, sync::OnceLock};
@@ -166,7 +167,7 @@ pub fn new() -> &'static Self {
 
     #[instrument(name = "CallTraceDecoder::init", level = "debug")]
     fn init() -> Self {
-        Self {
+        let mut decoder = Self {
             contracts: Default::default(),
             labels: HashMap::from_iter([
                 (CHEATCODE_ADDRESS, "VM".to_string()),
@@ -207,12 +208,25 @@ fn init() -> Self {
             debug_identifier: None,
 
             disable_labels: false,
+        };
+
+        // Arbitrum precompiles and `NodeInterface` are always known, so they never need to be
+        // identified.
+        let node_interface = (NODE_INTERFACE, "NodeInterface");
+        for &(address, name) in ARBITRUM_PRECOMPILES.iter().chain([&node_interface]) {
+            decoder.labels.insert(address, name.to_string());
+            decoder.contracts.insert(address, name.to_string());
+            if let Some(abi) = precompile_abi(name) {
+                decoder.collect_abi(&abi, Some(address));
+            }
         }
+
+        decoder
     }
 
     /// Clears all known addresses.
     pub fn clear_addresses(&mut self) {
-        self.contracts.clear();
+        self.contracts.clone_from(&Self::new().contracts);
 
         let default_labels = &Self::new().labels;
         if self.labels.len() > default_labels.len() {
@@ -905,6 +919,34 @@ fn test_selector_collision_resolution_second_function() {
         assert_eq!(result[0].signature(), "gasprice_bit_ether(int128)");
     }
 
+    #[tokio::test]
+    async fn test_decode_arbitrum_precompile() {
+        use alloy_sol_types::{SolCall, SolError};
+        use foundry_evm_core::abi::arbitrum::ArbWasm;
+        use foundry_evm_networks::arbitrum::ARB_WASM;
+        use revm::interpreter::InstructionResult;
+
+        let decoder = CallTraceDecoder::new();
+
+        let trace = CallTrace {
+            address: ARB_WASM,
+            data: ArbWasm::programVersionCall { program: Address::from([0x12; 20]) }
+                .abi_encode()
+                .into(),
+            output: ArbWasm::ProgramNotActivated {}.abi_encode().into(),
+            success: false,
+            status: Some(InstructionResult::Revert),
+            ..Default::default()
+        };
+
+        let decoded = decoder.decode_function(&trace).await;
+        assert_eq!(decoded.label.as_deref(), Some("ArbWasm"));
+        let call_data = decoded.call_data.unwrap();
+        assert_eq!(call_data.signature, "programVersion(address)");
+        assert_eq!(call_data.args, ["0x1212121212121212121212121212121212121212"]);
+        assert_eq!(decoded.return_data.as_deref(), Some("ProgramNotActivated()"));
+    }
+
     #[test]
     fn test_should_redact() {
         let decoder = CallTraceDecoder::new();
@@ -4,6 +4,7 @@
 use alloy_primitives::{Address, Bytes, map::HashMap};
 use foundry_common::contracts::{ContractsByArtifact, bytecode_diff_score};
 use foundry_compilers::ArtifactId;
+use foundry_evm_core::stylus::is_stylus_code;
 use revm_inspectors::tracing::types::CallTraceNode;
 use std::borrow::Cow;
 
@@ -46,6 +47,12 @@ pub fn identify_code(
         runtime_code: &[u8],
         creation_code: &[u8],
     ) -> Option<(&'a ArtifactId, &'a JsonAbi)> {
+        // Stylus programs are brotli-compressed, so the diff score between two programs is
+        // meaningless. Only accept exact matches of the deployed code.
+        if is_stylus_code(runtime_code) {
+            return self.identify_stylus_code(runtime_code);
+        }
+
         let len = runtime_code.len();
 
         let mut min_score = f64::MAX;
@@ -128,6 +135,19 @@ pub fn identify_code(
         if min_score < 0.85 { min_score_id } else { None }
     }
 
+    /// Identifies a Stylus program by comparing its deployed code with the known Stylus artifacts.
+    fn identify_stylus_code(&self, runtime_code: &[u8]) -> Option<(&'a ArtifactId, &'a JsonAbi)> {
+        let len = runtime_code.len();
+        self.ordered_ids[self.find_index(len)..]
+            .iter()
+            .take_while(|(_, probe)| *probe == len)
+            .find_map(|&(id, _)| {
+                let contract = self.known_contracts.get(id)?;
+                (contract.deployed_bytecode()?.as_ref() == runtime_code)
+                    .then_some((id, &contract.abi))
+            })
+    }
+
     /// Returns the index of the artifact with the given code length, or the index of the first
     /// artifact with a greater code length if the exact code length is not found.
     fn find_index(&self, len: usize) -> usize {
@@ -6,7 +6,7 @@
     opts::{BuildOpts, configure_pcx_from_solc, get_solar_sources_from_compile_output},
     utils::{LoadConfig, cache_local_signatures},
 };
-use foundry_common::{compile::ProjectCompiler, shell};
+use foundry_common::{compile::ProjectCompiler, shell, stylus::StylusCompiler};
 use foundry_compilers::{
     CompilationError, FileFilter, Project, ProjectCompileOutput,
     compilers::{Language, multi::MultiCompilerLanguage},
@@ -106,6 +106,15 @@ pub async fn run(self) -> Result<ProjectCompileOutput> {
 
         let mut output = compiler.compile(&project)?;
 
+        // Build Stylus programs configured in `[stylus.programs]`.
+        if !config.stylus.programs.is_empty() {
+            StylusCompiler::new().quiet(format_json).compile(
+                project.root(),
+                &project.paths.artifacts,
+                &config.stylus.programs,
+            )?;
+        }
+
         // Cache project selectors.
         cache_local_signatures(&output)?;
 
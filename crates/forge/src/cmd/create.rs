@@ -2,12 +2,15 @@
 use alloy_chains::Chain;
 use alloy_dyn_abi::{DynSolValue, JsonAbiExt, Specifier};
 use alloy_json_abi::{Constructor, JsonAbi};
-use alloy_network::{AnyNetwork, AnyTransactionReceipt, EthereumWallet, TransactionBuilder};
-use alloy_primitives::{Address, Bytes, hex};
+use alloy_network::{
+    AnyNetwork, AnyTransactionReceipt, EthereumWallet, ReceiptResponse, TransactionBuilder,
+};
+use alloy_primitives::{Address, Bytes, U256, hex};
 use alloy_provider::{PendingTransactionError, Provider, ProviderBuilder};
 use alloy_rpc_types::TransactionRequest;
 use alloy_serde::WithOtherFields;
 use alloy_signer::Signer;
+use alloy_sol_types::{SolCall, SolError};
 use alloy_transport::TransportError;
 use clap::{Parser, ValueHint};
 use eyre::{Context, Result};
@@ -20,6 +23,7 @@
     compile::{self},
     fmt::parse_tokens,
     shell,
+    stylus::stylus_abi_path,
 };
 use foundry_compilers::{
     ArtifactId, artifacts::BytecodeObject, info::ContractInfo, utils::canonicalize,
@@ -32,8 +36,22 @@
     },
     merge_impl_figment_convert,
 };
+use foundry_evm::core::{
+    abi::arbitrum::ArbWasm,
+    stylus::{
+        DATA_FEE_BUMP_PERCENT, bump_data_fee, stylus_constructor_calldata, stylus_init_code,
+        stylus_runtime_code,
+    },
+};
+use foundry_evm_networks::arbitrum::ARB_WASM;
 use serde_json::json;
-use std::{borrow::Borrow, marker::PhantomData, path::PathBuf, sync::Arc, time::Duration};
+use std::{
+    borrow::Borrow,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 
 merge_impl_figment_convert!(CreateArgs, build, eth);
 
@@ -41,7 +59,22 @@
 #[derive(Clone, Debug, Parser)]
 pub struct CreateArgs {
     /// The contract identifier in the form `<path>:<contractname>`.
-    contract: ContractInfo,
+    #[arg(required_unless_present = "stylus")]
+    contract: Option<ContractInfo>,
+
+    /// Deploy a Stylus program from a WASM binary (`.wasm` or `.wasm.br`) instead of a contract.
+    ///
+    /// The program is deployed, activated with `ArbWasm.activateProgram`, paying the estimated
+    /// data fee, and initialized through its constructor if it has one. Constructor arguments are
+    /// encoded with the ABI next to the binary (`<name>.abi.json`), and `--value` is sent to the
+    /// constructor.
+    #[arg(
+        long,
+        value_hint = ValueHint::FilePath,
+        value_name = "PATH",
+        conflicts_with_all = ["contract", "verify"],
+    )]
+    stylus: Option<PathBuf>,
 
     /// The constructor arguments.
     #[arg(
@@ -102,9 +135,13 @@ pub struct CreateArgs {
 
 impl CreateArgs {
     /// Executes the command to create a contract
-    pub async fn run(mut self) -> Result<()> {
+    pub async fn run(self) -> Result<()> {
         let mut config = self.load_config()?;
 
+        if let Some(wasm) = self.stylus.clone() {
+            return self.run_stylus(&config, wasm).await;
+        }
+
         // Install missing dependencies.
         if install::install_missing_dependencies(&mut config).await && config.auto_detect_remappings
         {
@@ -115,15 +152,16 @@ pub async fn run(mut self) -> Result<()> {
         // Find Project & Compile
         let project = config.project()?;
 
-        let target_path = if let Some(ref mut path) = self.contract.path {
+        let contract = self.contract();
+        let target_path = if let Some(path) = &contract.path {
             canonicalize(project.root().join(path))?
         } else {
-            project.find_contract_path(&self.contract.name)?
+            project.find_contract_path(&contract.name)?
         };
 
         let output = compile::compile_target(&target_path, &project, shell::is_json())?;
 
-        let (abi, bin, id) = remove_contract(output, &target_path, &self.contract.name)?;
+        let (abi, bin, id) = remove_contract(output, &target_path, &contract.name)?;
 
         let bin = match bin.object {
             BytecodeObject::Bytecode(_) => bin.object,
@@ -204,6 +242,95 @@ pub async fn run(mut self) -> Result<()> {
         }
     }
 
+    /// Deploys, activates and initializes a Stylus program.
+    async fn run_stylus(self, config: &Config, wasm: PathBuf) -> Result<()> {
+        let runtime_code = stylus_runtime_code(
+            foundry_common::fs::read(&wasm)
+                .wrap_err_with(|| format!("failed to read Stylus program {}", wasm.display()))?,
+        )?;
+
+        // Stylus constructors are regular functions called after activation.
+        let abi_path = stylus_abi_path(&wasm);
+        let abi: Option<JsonAbi> = if abi_path.exists() {
+            Some(foundry_common::fs::read_json_file(&abi_path)?)
+        } else {
+            None
+        };
+        let constructor_calldata = match abi.as_ref().and_then(|abi| abi.constructor.as_ref()) {
+            Some(constructor) => {
+                let constructor_args = self
+                    .constructor_args_path
+                    .clone()
+                    .map(read_constructor_args_file)
+                    .transpose()?;
+                let params = self.parse_constructor_args(
+                    constructor,
+                    constructor_args.as_deref().unwrap_or(&self.constructor_args),
+                )?;
+                Some(stylus_constructor_calldata(&constructor.abi_encode_input(&params)?))
+            }
+            None if self.constructor_args.is_empty() && self.constructor_args_path.is_none() => {
+                None
+            }
+            None => eyre::bail!(
+                "no constructor found for {}; constructor arguments are encoded with the ABI at {}",
+                wasm.display(),
+                abi_path.display()
+            ),
+        };
+        if constructor_calldata.is_none() && self.tx.value.is_some() {
+            eyre::bail!("`--value` is sent to the constructor, but the Stylus program has none");
+        }
+
+        let provider = utils::get_provider(config)?;
+        let chain_id = if let Some(chain_id) = self.chain_id() {
+            chain_id
+        } else {
+            provider.get_chain_id().await?
+        };
+        let dry_run = !self.broadcast;
+        let timeout = config.transaction_timeout;
+
+        if self.unlocked {
+            let sender = self.eth.wallet.from.expect("required");
+            self.deploy_stylus(
+                &wasm,
+                runtime_code,
+                constructor_calldata,
+                provider,
+                chain_id,
+                sender,
+                timeout,
+                dry_run,
+            )
+            .await
+        } else {
+            let signer = self.eth.wallet.signer().await?;
+            let deployer = signer.address();
+            let provider = ProviderBuilder::<_, _, AnyNetwork>::default()
+                .wallet(EthereumWallet::new(signer))
+                .connect_provider(provider);
+            self.deploy_stylus(
+                &wasm,
+                runtime_code,
+                constructor_calldata,
+                provider,
+                chain_id,
+                deployer,
+                timeout,
+                dry_run,
+            )
+            .await
+        }
+    }
+
+    /// Returns the contract to deploy.
+    ///
+    /// Only `None` when deploying a Stylus program, which `run` handles first.
+    fn contract(&self) -> &ContractInfo {
+        self.contract.as_ref().expect("contract is required without `--stylus`")
+    }
+
     /// Returns the provided chain id, if any.
     fn chain_id(&self) -> Option<u64> {
         self.eth.etherscan.chain.map(|chain| chain.id())
@@ -225,7 +352,7 @@ async fn verify_preflight_check(
         // since we don't know the address yet.
         let mut verify = VerifyArgs {
             address: Default::default(),
-            contract: Some(self.contract.clone()),
+            contract: self.contract.clone(),
             compiler_version: Some(id.version.to_string()),
             constructor_args,
             constructor_args_path: None,
@@ -282,7 +409,7 @@ async fn deploy<P: Provider<AnyNetwork>>(
     ) -> Result<()> {
         let bin = bin.into_bytes().unwrap_or_default();
         if bin.is_empty() {
-            eyre::bail!("no bytecode found in bin object for {}", self.contract.name)
+            eyre::bail!("no bytecode found in bin object for {}", self.contract().name)
         }
 
         let provider = Arc::new(provider);
@@ -297,54 +424,25 @@ async fn deploy<P: Provider<AnyNetwork>>(
                     e
                 }
             })?;
-        let is_legacy = self.tx.legacy || Chain::from(chain).is_legacy();
-
-        deployer.tx.set_from(deployer_address);
-        deployer.tx.set_chain_id(chain);
         // `to` field must be set explicitly, cannot be None.
         if deployer.tx.to.is_none() {
             deployer.tx.set_create();
         }
-        deployer.tx.set_nonce(if let Some(nonce) = self.tx.nonce {
-            Ok(nonce.to())
-        } else {
-            provider.get_transaction_count(deployer_address).await
-        }?);
 
         // set tx value if specified
         if let Some(value) = self.tx.value {
             deployer.tx.set_value(value);
         }
 
-        deployer.tx.set_gas_limit(if let Some(gas_limit) = self.tx.gas_limit {
-            Ok(gas_limit.to())
-        } else {
-            provider.estimate_gas(deployer.tx.clone()).await
-        }?);
-
-        if is_legacy {
-            let gas_price = if let Some(gas_price) = self.tx.gas_price {
-                gas_price.to()
-            } else {
-                provider.get_gas_price().await?
-            };
-            deployer.tx.set_gas_price(gas_price);
-        } else {
-            let estimate = provider.estimate_eip1559_fees().await.wrap_err("Failed to estimate EIP1559 fees. This chain might not support EIP1559, try adding --legacy to your command.")?;
-            let priority_fee = if let Some(priority_fee) = self.tx.priority_gas_price {
-                priority_fee.to()
-            } else {
-                estimate.max_priority_fee_per_gas
-            };
-            let max_fee = if let Some(max_fee) = self.tx.gas_price {
-                max_fee.to()
-            } else {
-                estimate.max_fee_per_gas
-            };
-
-            deployer.tx.set_max_fee_per_gas(max_fee);
-            deployer.tx.set_max_priority_fee_per_gas(priority_fee);
-        }
+        self.fill_tx(
+            &mut deployer.tx,
+            &*provider,
+            chain,
+            deployer_address,
+            self.tx.nonce.map(|nonce| nonce.to()),
+            self.tx.gas_limit.map(|gas_limit| gas_limit.to()),
+        )
+        .await?;
 
         // Before we actually deploy the contract we try check if the verify settings are valid
         let mut constructor_args = None;
@@ -364,7 +462,7 @@ async fn deploy<P: Provider<AnyNetwork>>(
             if !shell::is_json() {
                 sh_warn!("Dry run enabled, not broadcasting transaction\n")?;
 
-                sh_println!("Contract: {}", self.contract.name)?;
+                sh_println!("Contract: {}", self.contract().name)?;
                 sh_println!(
                     "Transaction: {}",
                     serde_json::to_string_pretty(&deployer.tx.clone())?
@@ -376,7 +474,7 @@ async fn deploy<P: Provider<AnyNetwork>>(
                 )?;
             } else {
                 let output = json!({
-                    "contract": self.contract.name,
+                    "contract": self.contract().name,
                     "transaction": &deployer.tx,
                     "abi":&abi
                 });
@@ -417,7 +515,7 @@ async fn deploy<P: Provider<AnyNetwork>>(
 
         let verify = VerifyArgs {
             address,
-            contract: Some(self.contract),
+            contract: self.contract,
             compiler_version: Some(id.version.to_string()),
             constructor_args,
             constructor_args_path: None,
@@ -446,6 +544,170 @@ async fn deploy<P: Provider<AnyNetwork>>(
         verify.run().await
     }
 
+    /// Deploys a Stylus program, then activates and initializes it.
+    ///
+    /// The data fee is estimated once the program is deployed, and bumped in case it changes
+    /// before the activation is included; the excess is refunded.
+    #[expect(clippy::too_many_arguments)]
+    async fn deploy_stylus<P: Provider<AnyNetwork>>(
+        self,
+        wasm: &Path,
+        runtime_code: Bytes,
+        constructor_calldata: Option<Bytes>,
+        provider: P,
+        chain: u64,
+        deployer_address: Address,
+        timeout: u64,
+        dry_run: bool,
+    ) -> Result<()> {
+        let mut tx = WithOtherFields::new(
+            TransactionRequest::default().input(stylus_init_code(&runtime_code).into()),
+        );
+        tx.set_create();
+        self.fill_tx(
+            &mut tx,
+            &provider,
+            chain,
+            deployer_address,
+            self.tx.nonce.map(|nonce| nonce.to()),
+            self.tx.gas_limit.map(|gas_limit| gas_limit.to()),
+        )
+        .await?;
+
+        if dry_run {
+            if !shell::is_json() {
+                sh_warn!("Dry run enabled, not broadcasting transaction\n")?;
+
+                sh_println!("Stylus program: {}", wasm.display())?;
+                sh_println!("Transaction: {}", serde_json::to_string_pretty(&tx)?)?;
+                sh_println!(
+                    "The program is activated{} once deployed.\n",
+                    if constructor_calldata.is_some() { " and initialized" } else { "" }
+                )?;
+
+                sh_warn!(
+                    "To broadcast this transaction, add --broadcast to the previous command. See forge create --help for more."
+                )?;
+            } else {
+                let output = json!({
+                    "stylus": wasm,
+                    "transaction": &tx,
+                });
+                sh_println!("{}", serde_json::to_string_pretty(&output)?)?;
+            }
+
+            return Ok(());
+        }
+
+        let receipt = send_tx(&provider, tx, timeout).await?;
+        let address = receipt
+            .contract_address
+            .ok_or_else(|| eyre::eyre!("Stylus program was not deployed"))?;
+
+        // Activate the program, paying the data fee.
+        let mut tx = WithOtherFields::new(
+            TransactionRequest::default().from(deployer_address).to(ARB_WASM).input(
+                Bytes::from(ArbWasm::activateProgramCall { program: address }.abi_encode()).into(),
+            ),
+        );
+        let data_fee = estimate_data_fee(&provider, tx.clone()).await?;
+        tx.set_value(bump_data_fee(data_fee, DATA_FEE_BUMP_PERCENT));
+        self.fill_tx(&mut tx, &provider, chain, deployer_address, None, None).await?;
+        let activation_receipt = send_tx(&provider, tx, timeout).await?;
+
+        // Initialize the program through its constructor.
+        let constructor_receipt = match constructor_calldata {
+            Some(calldata) => {
+                let mut tx = WithOtherFields::new(
+                    TransactionRequest::default().to(address).input(calldata.into()),
+                );
+                if let Some(value) = self.tx.value {
+                    tx.set_value(value);
+                }
+                self.fill_tx(&mut tx, &provider, chain, deployer_address, None, None).await?;
+                Some(send_tx(&provider, tx, timeout).await?)
+            }
+            None => None,
+        };
+
+        if shell::is_json() {
+            let output = json!({
+                "deployer": deployer_address.to_string(),
+                "deployedTo": address.to_string(),
+                "transactionHash": receipt.transaction_hash,
+                "activationTransactionHash": activation_receipt.transaction_hash,
+                "dataFee": data_fee.to_string(),
+                "constructorTransactionHash": constructor_receipt.map(|receipt| receipt.transaction_hash),
+            });
+            sh_println!("{}", serde_json::to_string_pretty(&output)?)?;
+        } else {
+            sh_println!("Deployer: {deployer_address}")?;
+            sh_println!("Deployed to: {address}")?;
+            sh_println!("Transaction hash: {:?}", receipt.transaction_hash)?;
+            sh_println!("Activation transaction hash: {:?}", activation_receipt.transaction_hash)?;
+            sh_println!("Data fee: {data_fee} wei")?;
+            if let Some(receipt) = constructor_receipt {
+                sh_println!("Constructor transaction hash: {:?}", receipt.transaction_hash)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets the sender, chain id, nonce, gas limit and fees of a transaction, estimating the nonce
+    /// and gas limit unless given.
+    async fn fill_tx<P: Provider<AnyNetwork>>(
+        &self,
+        tx: &mut WithOtherFields<TransactionRequest>,
+        provider: &P,
+        chain: u64,
+        from: Address,
+        nonce: Option<u64>,
+        gas_limit: Option<u64>,
+    ) -> Result<()> {
+        let is_legacy = self.tx.legacy || Chain::from(chain).is_legacy();
+
+        tx.set_from(from);
+        tx.set_chain_id(chain);
+        tx.set_nonce(if let Some(nonce) = nonce {
+            Ok(nonce)
+        } else {
+            provider.get_transaction_count(from).await
+        }?);
+
+        tx.set_gas_limit(if let Some(gas_limit) = gas_limit {
+            Ok(gas_limit)
+        } else {
+            provider.estimate_gas(tx.clone()).await
+        }?);
+
+        if is_legacy {
+            let gas_price = if let Some(gas_price) = self.tx.gas_price {
+                gas_price.to()
+            } else {
+                provider.get_gas_price().await?
+            };
+            tx.set_gas_price(gas_price);
+        } else {
+            let estimate = provider.estimate_eip1559_fees().await.wrap_err("Failed to estimate EIP1559 fees. This chain might not support EIP1559, try adding --legacy to your command.")?;
+            let priority_fee = if let Some(priority_fee) = self.tx.priority_gas_price {
+                priority_fee.to()
+            } else {
+                estimate.max_priority_fee_per_gas
+            };
+            let max_fee = if let Some(max_fee) = self.tx.gas_price {
+                max_fee.to()
+            } else {
+                estimate.max_fee_per_gas
+            };
+
+            tx.set_max_fee_per_gas(max_fee);
+            tx.set_max_priority_fee_per_gas(priority_fee);
+        }
+
+        Ok(())
+    }
+
     /// Parses the given constructor arguments into a vector of `DynSolValue`s, by matching them
     /// against the constructor's input params.
     ///
@@ -482,6 +744,48 @@ fn data(&self) -> Result<Map<Profile, Dict>, figment::Error> {
     }
 }
 
+/// Sends a transaction and waits for its receipt, failing if it reverted.
+async fn send_tx<P: Provider<AnyNetwork>>(
+    provider: &P,
+    tx: WithOtherFields<TransactionRequest>,
+    timeout: u64,
+) -> Result<AnyTransactionReceipt> {
+    let receipt = provider
+        .send_transaction(tx)
+        .await?
+        .with_required_confirmations(1)
+        .with_timeout(Some(Duration::from_secs(timeout)))
+        .get_receipt()
+        .await?;
+    if !receipt.status() {
+        eyre::bail!("transaction {:?} reverted", receipt.transaction_hash);
+    }
+    Ok(receipt)
+}
+
+/// Estimates the data fee of an `ArbWasm.activateProgram` call sent without value.
+///
+/// The call reverts with `ProgramInsufficientValue` if the data fee is not paid, which reports
+/// the fee that is required.
+async fn estimate_data_fee<P: Provider<AnyNetwork>>(
+    provider: &P,
+    tx: WithOtherFields<TransactionRequest>,
+) -> Result<U256> {
+    match provider.call(tx).await {
+        Ok(output) => Ok(ArbWasm::activateProgramCall::abi_decode_returns(&output)?.dataFee),
+        Err(err) => {
+            let required = err
+                .as_error_resp()
+                .and_then(|resp| resp.as_revert_data())
+                .and_then(|data| ArbWasm::ProgramInsufficientValue::abi_decode(&data).ok());
+            match required {
+                Some(required) => Ok(required.want),
+                None => Err(eyre::Report::from(err).wrap_err("failed to activate Stylus program")),
+            }
+        }
+    }
+}
+
 /// `ContractFactory` is a [`DeploymentTxFactory`] object with an
 /// [`Arc`] middleware. This type alias exists to preserve backwards
 /// compatibility with less-abstract Contracts.
@@ -650,6 +954,14 @@ fn can_parse_chain_id() {
         assert_eq!(args.chain_id(), Some(9999));
     }
 
+    #[test]
+    fn can_parse_create_stylus() {
+        let args: CreateArgs =
+            CreateArgs::parse_from(["foundry-cli", "--stylus", "program.wasm", "--broadcast"]);
+        assert_eq!(args.stylus, Some(PathBuf::from("program.wasm")));
+        assert!(args.contract.is_none());
+    }
+
     #[test]
     fn test_parse_constructor_args() {
         let args: CreateArgs = CreateArgs::parse_from([
@@ -1,12 +1,16 @@
+use crate::stylus::StylusProgramInfo;
 use alloy_json_abi::{EventParam, InternalType, JsonAbi, Param};
 use alloy_primitives::{hex, keccak256};
 use clap::Parser;
 use comfy_table::{Cell, Table, modifiers::UTF8_ROUND_CORNERS, presets::ASCII_MARKDOWN};
 use eyre::{Result, eyre};
-use foundry_cli::opts::{BuildOpts, CompilerOpts};
+use foundry_cli::{
+    opts::{BuildOpts, CompilerOpts},
+    utils::LoadConfig,
+};
 use foundry_common::{
     compile::{PathOrContractInfo, ProjectCompiler},
-    find_matching_contract_artifact, find_target_path, shell,
+    find_matching_contract_artifact, find_target_path, fs, shell,
 };
 use foundry_compilers::{
     artifacts::{
@@ -52,6 +56,16 @@ pub fn run(self) -> Result<()> {
 
         trace!(target: "forge", ?field, ?contract, "running forge inspect");
 
+        if field.is_stylus() {
+            let name = contract
+                .name()
+                .ok_or_else(|| eyre!("`{field}` requires the name of a Stylus program"))?;
+            let config = build.load_config()?;
+            let artifact = forge_verify::stylus::build_stylus_program(&config, name)?;
+            let info = StylusProgramInfo::new(fs::read(&artifact.wasm)?)?;
+            return print_stylus_field(&info, field);
+        }
+
         // Map field to ContractOutputSelection
         let mut cos = build.compiler.extra_output;
         if !field.can_skip_field() && !cos.iter().any(|selected| field == *selected) {
@@ -428,6 +442,14 @@ pub enum ContractArtifactField {
     Events,
     StandardJson,
     Libraries,
+    StylusSize,
+    CompressedSize,
+    Imports,
+    Exports,
+    MemoryPages,
+    ActivationGas,
+    DataFee,
+    Codehash,
 }
 
 macro_rules! impl_value_enum {
@@ -512,6 +534,14 @@ enum ContractArtifactField {
         Events            => "events" | "ev",
         StandardJson      => "standardJson" | "standard-json" | "standard_json",
         Libraries         => "libraries" | "lib" | "libs",
+        StylusSize        => "stylus-size" | "stylusSize" | "stylus_size",
+        CompressedSize    => "compressed-size" | "compressedSize" | "compressed_size",
+        Imports           => "imports",
+        Exports           => "exports",
+        MemoryPages       => "memory-pages" | "memoryPages" | "memory_pages",
+        ActivationGas     => "activation-gas" | "activationGas" | "activation_gas",
+        DataFee           => "data-fee" | "dataFee" | "data_fee",
+        Codehash          => "codehash" | "code-hash" | "codeHash" | "code_hash",
     }
 }
 
@@ -545,6 +575,14 @@ fn try_from(field: ContractArtifactField) -> Result<Self, Self::Error> {
                 Err(eyre!("StandardJson is not supported for ContractOutputSelection"))
             }
             Caf::Libraries => Err(eyre!("Libraries is not supported for ContractOutputSelection")),
+            Caf::StylusSize
+            | Caf::CompressedSize
+            | Caf::Imports
+            | Caf::Exports
+            | Caf::MemoryPages
+            | Caf::ActivationGas
+            | Caf::DataFee
+            | Caf::Codehash => Err(eyre!("{field} is only supported for Stylus programs")),
         }
     }
 }
@@ -586,10 +624,51 @@ pub const fn can_skip_field(&self) -> bool {
         matches!(
             self,
             Self::Bytecode | Self::DeployedBytecode | Self::StandardJson | Self::Libraries
+        ) || self.is_stylus()
+    }
+
+    /// Returns true if this field is only available for Stylus programs.
+    pub const fn is_stylus(&self) -> bool {
+        matches!(
+            self,
+            Self::StylusSize
+                | Self::CompressedSize
+                | Self::Imports
+                | Self::Exports
+                | Self::MemoryPages
+                | Self::ActivationGas
+                | Self::DataFee
+                | Self::Codehash
         )
     }
 }
 
+fn print_stylus_field(info: &StylusProgramInfo, field: ContractArtifactField) -> Result<()> {
+    type Caf = ContractArtifactField;
+    let value = match field {
+        Caf::StylusSize => info.stylus_size.to_string(),
+        Caf::CompressedSize => info.compressed_size.to_string(),
+        Caf::Imports | Caf::Exports => {
+            let items = if field == Caf::Imports { &info.imports } else { &info.exports };
+            if shell::is_json() {
+                return print_json(items);
+            }
+            items.join("\n")
+        }
+        Caf::MemoryPages => info.memory_pages.to_string(),
+        Caf::ActivationGas => info.activation_gas.to_string(),
+        Caf::DataFee => info.data_fee.to_string(),
+        Caf::Codehash => info.codehash.to_string(),
+        _ => unreachable!("{field} is not a Stylus field"),
+    };
+    if shell::is_json() {
+        print_json(&value)
+    } else {
+        sh_println!("{value}")?;
+        Ok(())
+    }
+}
+
 fn print_json(obj: &impl serde::Serialize) -> Result<()> {
     sh_println!("{}", serde_json::to_string_pretty(obj)?)?;
     Ok(())
@@ -662,6 +741,14 @@ fn contract_output_selection() {
                         .to_string()
                         .eq("Libraries is not supported for ContractOutputSelection")
                 );
+            } else if field.is_stylus() {
+                let selection: Result<ContractOutputSelection, _> = field.try_into();
+                assert_eq!(
+                    selection.unwrap_err().to_string(),
+                    format!("{field} is only supported for Stylus programs")
+                );
+                assert!(field.can_skip_field());
+                assert_eq!(field.as_str().parse::<ContractArtifactField>().unwrap(), field);
             } else {
                 let selection: ContractOutputSelection = field.try_into().unwrap();
                 assert_eq!(field, selection);
@@ -4,7 +4,7 @@
     decode::decode_console_logs,
     gas_report::GasReport,
     multi_runner::matches_artifact,
-    result::{SuiteResult, TestOutcome, TestStatus},
+    result::{SuiteResult, TestOutcome, TestStatus, mark_arbos_version_differences},
     traces::{
         CallTraceDecoderBuilder, InternalTraceMode, TraceKind,
         debug::{ContractSources, DebugTraceIdentifier},
@@ -20,7 +20,9 @@
     opts::{BuildOpts, EvmArgs, GlobalArgs},
     utils::{self, LoadConfig},
 };
-use foundry_common::{EmptyTestFilter, TestFunctionExt, compile::ProjectCompiler, fs, shell};
+use foundry_common::{
+    EmptyTestFilter, TestFunctionExt, compile::ProjectCompiler, fs, shell, stylus::StylusCompiler,
+};
 use foundry_compilers::{
     ProjectCompileOutput,
     artifacts::output_selection::OutputSelection,
@@ -276,6 +278,15 @@ pub async fn compile_and_run(&mut self) -> Result<TestOutcome> {
             .files(self.get_sources_to_compile(&config, &filter)?);
         let output = compiler.compile(&project)?;
 
+        // Build Stylus programs configured in `[stylus.programs]`.
+        if !config.stylus.programs.is_empty() {
+            StylusCompiler::new().quiet(shell::is_json() || self.junit).compile(
+                project.root(),
+                &project.paths.artifacts,
+                &config.stylus.programs,
+            )?;
+        }
+
         self.run_tests(&project.paths.root, config, evm_opts, &output, &filter, false).await
     }
 
@@ -499,6 +510,7 @@ async fn run_tests_inner(
                     }
                 }
             });
+            mark_arbos_version_differences(&mut results);
             sh_println!("{}", serde_json::to_string(&results)?)?;
             return Ok(TestOutcome::new(Some(runner), results, self.allow_failure));
         }
@@ -835,6 +847,17 @@ async fn run_tests_inner(
 
         if !self.summary && !shell::is_json() {
             sh_println!("{}", outcome.summary(duration))?;
+
+            let differences = outcome.arbos_version_differences();
+            if !differences.is_empty() {
+                sh_println!("\n{}", "Tests whose results differ between ArbOS versions:".yellow())?;
+                for difference in differences {
+                    sh_println!("{}::{}", difference.suite, difference.signature.bold())?;
+                    for (version, result) in difference.results {
+                        sh_println!("  ArbOS {version}: {result} {}", result.kind.report())?;
+                    }
+                }
+            }
         }
 
         if self.summary && !outcome.results.is_empty() {
@@ -5,9 +5,11 @@
     traces::{CallTraceArena, CallTraceDecoder, CallTraceNode, DecodedCallData},
 };
 use alloy_primitives::map::HashSet;
+use alloy_sol_types::SolCall;
 use comfy_table::{Cell, Color, Table, modifiers::UTF8_ROUND_CORNERS, presets::ASCII_MARKDOWN};
 use foundry_common::{TestFunctionExt, calc, shell};
-use foundry_evm::traces::CallKind;
+use foundry_evm::{core::abi::arbitrum::ArbWasm, traces::CallKind};
+use foundry_evm_networks::arbitrum::ARB_WASM;
 
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -79,6 +81,11 @@ async fn analyze_node(&mut self, node: &CallTraceNode, decoder: &CallTraceDecode
             return;
         }
 
+        if trace.address == ARB_WASM {
+            self.analyze_activation(node, decoder);
+            return;
+        }
+
         let Some(name) = decoder.contracts.get(&node.trace.address) else { return };
         let contract_name = name.rsplit(':').next().unwrap_or(name);
 
@@ -121,6 +128,25 @@ async fn analyze_node(&mut self, node: &CallTraceNode, decoder: &CallTraceDecode
         }
     }
 
+    /// Records the gas used to activate Stylus programs through the `ArbWasm` precompile.
+    ///
+    /// This is the only Stylus-specific column: the ink, init cost and cached pricing of calls are
+    /// not exposed by arbos-revm.
+    fn analyze_activation(&mut self, node: &CallTraceNode, decoder: &CallTraceDecoder) {
+        let trace = &node.trace;
+        if !trace.success {
+            return;
+        }
+        let Ok(call) = ArbWasm::activateProgramCall::abi_decode(&trace.data) else { return };
+        let Some(name) = decoder.contracts.get(&call.program) else { return };
+        let contract_name = name.rsplit(':').next().unwrap_or(name);
+        if !self.should_report(contract_name) {
+            return;
+        }
+        // Programs are activated after they are deployed but before they are called.
+        self.contracts.entry(name.to_string()).or_default().activation_gas = Some(trace.gas_used);
+    }
+
     /// Finalizes the gas report by calculating the min, max, mean, and median for each function.
     #[must_use]
     pub fn finalize(mut self) -> Self {
@@ -184,12 +210,17 @@ fn format_json_output(&self) -> String {
                         })
                         .collect::<BTreeMap<_, _>>();
 
+                    let mut deployment = json!({
+                        "gas": contract.gas,
+                        "size": contract.size,
+                    });
+                    if let Some(activation_gas) = contract.activation_gas {
+                        deployment["activation"] = activation_gas.into();
+                    }
+
                     Some(json!({
                         "contract": name,
-                        "deployment": {
-                            "gas": contract.gas,
-                            "size": contract.size,
-                        },
+                        "deployment": deployment,
                         "functions": functions,
                     }))
                 })
@@ -208,14 +239,18 @@ fn format_table_output(&self, contract: &ContractInfo, name: &str) -> Table {
 
         table.set_header(vec![Cell::new(format!("{name} Contract")).fg(Color::Magenta)]);
 
-        table.add_row(vec![
+        let mut deployment_header = vec![
             Cell::new("Deployment Cost").fg(Color::Cyan),
             Cell::new("Deployment Size").fg(Color::Cyan),
-        ]);
-        table.add_row(vec![
-            Cell::new(contract.gas.to_string()),
-            Cell::new(contract.size.to_string()),
-        ]);
+        ];
+        let mut deployment_row =
+            vec![Cell::new(contract.gas.to_string()), Cell::new(contract.size.to_string())];
+        if let Some(activation_gas) = contract.activation_gas {
+            deployment_header.push(Cell::new("Activation Cost").fg(Color::Cyan));
+            deployment_row.push(Cell::new(activation_gas.to_string()));
+        }
+        table.add_row(deployment_header);
+        table.add_row(deployment_row);
 
         // Add a blank row to separate deployment info from function info.
         table.add_row(vec![Cell::new("")]);
@@ -254,6 +289,9 @@ fn format_table_output(&self, contract: &ContractInfo, name: &str) -> Table {
 pub struct ContractInfo {
     pub gas: u64,
     pub size: usize,
+    /// Gas used to activate the Stylus program through `ArbWasm`, if it was activated manually.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activation_gas: Option<u64>,
     /// Function name -> Function signature -> GasInfo
     pub functions: BTreeMap<String, BTreeMap<String, GasInfo>>,
 }
@@ -30,6 +30,8 @@
 mod progress;
 pub mod result;
 
+pub mod stylus;
+
 // TODO: remove
 pub use foundry_common::traits::TestFilter;
 pub use foundry_evm::*;
@@ -2,7 +2,7 @@
 
 use crate::{
     ContractRunner, TestFilter, progress::TestsProgress, result::SuiteResult,
-    runner::LIBRARY_DEPLOYER,
+    runner::LIBRARY_DEPLOYER, stylus::stylus_contracts,
 };
 use alloy_json_abi::{Function, JsonAbi};
 use alloy_primitives::{Address, Bytes, U256};
@@ -32,7 +32,7 @@
 use rayon::prelude::*;
 use revm::primitives::hardfork::SpecId;
 use std::{
-    borrow::Borrow,
+    borrow::{Borrow, Cow},
     collections::BTreeMap,
     path::Path,
     sync::{Arc, mpsc},
@@ -191,30 +191,36 @@ pub fn test(
             find_time,
         );
 
+        // Every suite runs once per ArbOS version of the version matrix, if any.
+        let tcfgs = self.tcfg.arbos_version_matrix();
+        let suites = contracts
+            .iter()
+            .flat_map(|&(id, contract)| tcfgs.iter().map(move |tcfg| (id, contract, tcfg)))
+            .collect::<Vec<_>>();
+
         if show_progress {
-            let tests_progress = TestsProgress::new(contracts.len(), rayon::current_num_threads());
+            let tests_progress = TestsProgress::new(suites.len(), rayon::current_num_threads());
             // Collect test suite results to stream at the end of test run.
-            let results: Vec<(String, SuiteResult)> = contracts
+            let results: Vec<(String, SuiteResult)> = suites
                 .par_iter()
-                .map(|&(id, contract)| {
+                .map(|&(id, contract, tcfg)| {
                     let _guard = tokio_handle.enter();
-                    tests_progress.inner.lock().start_suite_progress(&id.identifier());
+                    let suite_name = tcfg.suite_name(&id.identifier());
+                    tests_progress.inner.lock().start_suite_progress(&suite_name);
 
                     let result = self.run_test_suite(
                         id,
                         contract,
+                        tcfg,
                         &db,
                         filter,
                         &tokio_handle,
                         Some(&tests_progress),
                     );
 
-                    tests_progress
-                        .inner
-                        .lock()
-                        .end_suite_progress(&id.identifier(), result.summary());
+                    tests_progress.inner.lock().end_suite_progress(&suite_name, result.summary());
 
-                    (id.identifier(), result)
+                    (suite_name, result)
                 })
                 .collect();
 
@@ -224,10 +230,11 @@ pub fn test(
                 let _ = tx.send(result.to_owned());
             });
         } else {
-            contracts.par_iter().for_each(|&(id, contract)| {
+            suites.par_iter().for_each(|&(id, contract, tcfg)| {
                 let _guard = tokio_handle.enter();
-                let result = self.run_test_suite(id, contract, &db, filter, &tokio_handle, None);
-                let _ = tx.send((id.identifier(), result));
+                let result =
+                    self.run_test_suite(id, contract, tcfg, &db, filter, &tokio_handle, None);
+                let _ = tx.send((tcfg.suite_name(&id.identifier()), result));
             })
         }
 
@@ -238,6 +245,7 @@ fn run_test_suite(
         &self,
         artifact_id: &ArtifactId,
         contract: &TestContract,
+        tcfg: &TestRunnerConfig,
         db: &Backend,
         filter: &dyn TestFilter,
         tokio_handle: &tokio::runtime::Handle,
@@ -255,7 +263,7 @@ fn run_test_suite(
 
         debug!("start executing all tests in contract");
 
-        let executor = self.tcfg.executor(
+        let executor = tcfg.executor(
             self.known_contracts.clone(),
             self.analysis.clone(),
             artifact_id,
@@ -268,9 +276,12 @@ fn run_test_suite(
             progress,
             tokio_handle,
             span,
+            tcfg,
             self,
         );
-        let r = runner.run_tests(filter);
+        let mut r = runner.run_tests(filter);
+        r.identifier = artifact_id.identifier();
+        r.arbos_version = tcfg.arbos_version;
 
         debug!(duration=?r.duration, "executed all tests in contract");
 
@@ -309,9 +320,47 @@ pub struct TestRunnerConfig {
     pub networks: NetworkConfigs,
     /// Whether to exit early on test failure or if test run interrupted.
     pub early_exit: EarlyExit,
+    /// The ArbOS version of the version matrix which this configuration runs, if any.
+    pub arbos_version: Option<u16>,
 }
 
 impl TestRunnerConfig {
+    /// Returns the configurations to run every suite with: one per ArbOS version of the
+    /// `arbos_versions` matrix, or this configuration if there is none.
+    pub fn arbos_version_matrix(&self) -> Vec<Cow<'_, Self>> {
+        if self.config.stylus.arbos_versions.is_empty() {
+            return vec![Cow::Borrowed(self)];
+        }
+        self.config
+            .stylus
+            .arbos_versions
+            .iter()
+            .map(|&version| Cow::Owned(self.with_arbos_version(version)))
+            .collect()
+    }
+
+    /// Returns a copy of this configuration running against the given ArbOS version.
+    pub fn with_arbos_version(&self, version: u16) -> Self {
+        let mut config = (*self.config).clone();
+        config.stylus.arbos_version = Some(version);
+
+        let mut tcfg = self.clone();
+        tcfg.evm_opts.stylus_config.arbos_version = Some(version);
+        tcfg.env.evm_env.cfg_env.arbos_version = version;
+        tcfg.arbos_version = Some(version);
+        tcfg.config = Arc::new(config);
+        tcfg
+    }
+
+    /// Returns the name which the suite with the given identifier is reported under, tagged with
+    /// the ArbOS version of the version matrix.
+    pub fn suite_name(&self, identifier: &str) -> String {
+        match self.arbos_version {
+            Some(version) => format!("{identifier} [ArbOS {version}]"),
+            None => identifier.to_string(),
+        }
+    }
+
     /// Reconfigures all fields using the given `config`.
     /// This is for example used to override the configuration with inline config.
     pub fn reconfigure_with(&mut self, config: Arc<Config>) {
@@ -520,10 +569,14 @@ pub fn build<C: Compiler<CompilerContract = Contract>>(
             .collect();
         let linker = Linker::new(root, contracts);
 
+        // Stylus programs are not part of the compiler output, but are known contracts.
+        let stylus_contracts = stylus_contracts(&self.config)?;
+
         // Build revert decoder from ABIs of all artifacts.
         let abis = linker
             .contracts
             .iter()
+            .chain(stylus_contracts.iter().map(|(id, contract)| (id, contract)))
             .filter_map(|(_, contract)| contract.abi.as_ref().map(|abi| abi.borrow()));
         let revert_decoder = RevertDecoder::new().with_abis(abis);
 
@@ -559,9 +612,12 @@ pub fn build<C: Compiler<CompilerContract = Contract>>(
             }
         }
 
-        // Create known contracts from linked contracts and storage layout information (if any).
-        let known_contracts =
-            ContractsByArtifactBuilder::new(linked_contracts).with_output(output, root).build();
+        // Create known contracts from linked contracts, Stylus programs and storage layout
+        // information (if any).
+        let known_contracts = ContractsByArtifactBuilder::new(linked_contracts)
+            .with_output(output, root)
+            .with_artifacts(stylus_contracts)
+            .build();
 
         // Initialize and configure the solar compiler.
         let mut analysis = solar::sema::Compiler::new(
@@ -611,6 +667,7 @@ pub fn build<C: Compiler<CompilerContract = Contract>>(
                 isolation: self.isolation,
                 networks: self.networks,
                 early_exit: EarlyExit::new(self.fail_fast || self.config.show_progress),
+                arbos_version: None,
                 config: self.config,
             },
 
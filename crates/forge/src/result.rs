@@ -203,6 +203,12 @@ pub fn ensure_ok(&self, silent: bool) -> eyre::Result<()> {
         std::process::exit(1);
     }
 
+    /// Returns the tests whose status or gas differ between the ArbOS versions of the version
+    /// matrix, grouped by suite and signature.
+    pub fn arbos_version_differences(&self) -> Vec<ArbosVersionDifference<'_>> {
+        arbos_version_differences(&self.results)
+    }
+
     /// Removes first test result, if any.
     pub fn remove_first(&mut self) -> Option<(String, String, TestResult)> {
         self.results.iter_mut().find_map(|(suite_name, suite)| {
@@ -216,6 +222,64 @@ pub fn remove_first(&mut self) -> Option<(String, String, TestResult)> {
     }
 }
 
+/// Returns the tests whose status or gas differ between the ArbOS versions of the version matrix,
+/// grouped by suite and signature.
+pub fn arbos_version_differences(
+    results: &BTreeMap<String, SuiteResult>,
+) -> Vec<ArbosVersionDifference<'_>> {
+    let mut tests = BTreeMap::<_, Vec<_>>::new();
+    for suite in results.values() {
+        let Some(version) = suite.arbos_version else { continue };
+        for (signature, result) in suite.tests() {
+            tests
+                .entry((suite.identifier.as_str(), signature.as_str()))
+                .or_default()
+                .push((version, result));
+        }
+    }
+
+    tests
+        .into_iter()
+        .filter(|(_, results)| {
+            results.windows(2).any(|pair| {
+                let ((_, a), (_, b)) = (pair[0], pair[1]);
+                a.status != b.status || a.kind.report().gas() != b.kind.report().gas()
+            })
+        })
+        .map(|((suite, signature), mut results)| {
+            results.sort_by_key(|(version, _)| *version);
+            ArbosVersionDifference { suite, signature, results }
+        })
+        .collect()
+}
+
+/// Lists in every suite of the version matrix the tests whose results differ between the ArbOS
+/// versions, so they are part of the JSON output.
+pub fn mark_arbos_version_differences(results: &mut BTreeMap<String, SuiteResult>) {
+    let differences = arbos_version_differences(results)
+        .into_iter()
+        .map(|difference| (difference.suite.to_string(), difference.signature.to_string()))
+        .collect::<Vec<_>>();
+    for suite in results.values_mut() {
+        suite.arbos_version_differences = differences
+            .iter()
+            .filter(|(identifier, _)| *identifier == suite.identifier)
+            .map(|(_, signature)| signature.clone())
+            .collect();
+    }
+}
+
+/// A test whose results differ between the ArbOS versions of the version matrix.
+#[derive(Clone, Debug)]
+pub struct ArbosVersionDifference<'a> {
+    /// The identifier of the suite, without the ArbOS version.
+    pub suite: &'a str,
+    /// The signature of the test.
+    pub signature: &'a str,
+    /// The results of the test by ArbOS version.
+    pub results: Vec<(u16, &'a TestResult)>,
+}
+
 /// A set of test results for a single test suite, which is all the tests in a single contract.
 #[derive(Clone, Debug, Serialize)]
 pub struct SuiteResult {
@@ -226,6 +290,16 @@ pub struct SuiteResult {
     pub test_results: BTreeMap<String, TestResult>,
     /// Generated warnings.
     pub warnings: Vec<String>,
+    /// The identifier of the contract (`path:contract_name`), which the suite is reported under
+    /// when it is not part of the version matrix.
+    #[serde(skip)]
+    pub identifier: String,
+    /// The ArbOS version of the version matrix which the suite ran against, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arbos_version: Option<u16>,
+    /// The tests whose results differ between the ArbOS versions of the version matrix.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub arbos_version_differences: Vec<String>,
 }
 
 impl SuiteResult {
@@ -252,7 +326,14 @@ pub fn new(
             warnings.push(warning);
         }
 
-        Self { duration, test_results, warnings }
+        Self {
+            duration,
+            test_results,
+            warnings,
+            identifier: String::new(),
+            arbos_version: None,
+            arbos_version_differences: Vec::new(),
+        }
     }
 
     /// Returns an iterator over all individual succeeding tests and their names.
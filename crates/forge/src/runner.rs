@@ -57,6 +57,8 @@
 pub struct ContractRunner<'a> {
     /// The name of the contract.
     name: &'a str,
+    /// The name the suite is reported under.
+    suite_name: String,
     /// The data of the contract.
     contract: &'a TestContract,
     /// The EVM executor.
@@ -83,6 +85,7 @@ fn deref(&self) -> &Self::Target {
 }
 
 impl<'a> ContractRunner<'a> {
+    #[expect(clippy::too_many_arguments)]
     pub fn new(
         name: &'a str,
         contract: &'a TestContract,
@@ -90,16 +93,18 @@ pub fn new(
         progress: Option<&'a TestsProgress>,
         tokio_handle: &'a tokio::runtime::Handle,
         span: Span,
+        tcfg: &'a TestRunnerConfig,
         mcr: &'a MultiContractRunner,
     ) -> Self {
         Self {
             name,
+            suite_name: tcfg.suite_name(name),
             contract,
             executor,
             progress,
             tokio_handle,
             span,
-            tcfg: Cow::Borrowed(&mcr.tcfg),
+            tcfg: Cow::Borrowed(tcfg),
             mcr,
         }
     }
@@ -637,7 +642,7 @@ fn run_table_test(mut self, func: &Function) -> TestResult {
 
         let progress = start_fuzz_progress(
             self.cr.progress,
-            self.cr.name,
+            &self.cr.suite_name,
             &func.name,
             None,
             fixtures_len as u32,
@@ -764,7 +769,7 @@ fn run_invariant_test(
 
         let progress = start_fuzz_progress(
             self.cr.progress,
-            self.cr.name,
+            &self.cr.suite_name,
             &func.name,
             invariant_config.timeout,
             invariant_config.runs,
@@ -990,7 +995,7 @@ fn run_fuzz_test(mut self, func: &Function) -> TestResult {
 
         let progress = start_fuzz_progress(
             self.cr.progress,
-            self.cr.name,
+            &self.cr.suite_name,
             &func.name,
             fuzz_config.timeout,
             fuzz_config.runs,
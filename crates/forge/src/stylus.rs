@@ -0,0 +1,125 @@
+//! Stylus program artifacts.
+
+use alloy_primitives::{Address, B256, Bytes, U256, keccak256};
+use alloy_sol_types::{SolCall, SolError};
+use eyre::Result;
+use foundry_evm::{
+    Env,
+    backend::Backend,
+    constants::CALLER,
+    core::{
+        abi::arbitrum::ArbWasm,
+        stylus::{stylus_runtime_code, stylus_wasm},
+    },
+    decode::RevertDecoder,
+    executors::ExecutorBuilder,
+};
+use foundry_evm_networks::arbitrum::ARB_WASM;
+use revm::bytecode::Bytecode;
+use serde::Serialize;
+use wasmparser::{Parser, Payload};
+
+pub use foundry_evm::stylus::stylus_contracts;
+
+/// The address the program is deployed at for its dry activation.
+const DRY_ACTIVATION_ADDRESS: Address = Address::repeat_byte(0x57);
+
+/// The size, interface and activation cost of a Stylus program.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StylusProgramInfo {
+    /// The size of the stripped WASM binary, in bytes.
+    pub stylus_size: usize,
+    /// The size of the compressed and prefixed runtime code stored on chain, in bytes.
+    pub compressed_size: usize,
+    /// The imported functions, as `module::name`.
+    pub imports: Vec<String>,
+    /// The exported items.
+    pub exports: Vec<String>,
+    /// The number of WASM pages the program starts with.
+    pub memory_pages: u64,
+    /// The gas used by `ArbWasm.activateProgram`, excluding the intrinsic transaction gas.
+    pub activation_gas: u64,
+    /// The data fee paid to `ArbWasm.activateProgram`, in wei.
+    pub data_fee: U256,
+    /// The codehash of the runtime code.
+    pub codehash: B256,
+}
+
+impl StylusProgramInfo {
+    /// Inspects a WASM binary as it would be deployed.
+    ///
+    /// The binary goes through the same strip and compress pipeline as `vm.deployStylusCode`, and
+    /// is then activated against the default ArbOS parameters in an empty in-memory state.
+    pub fn new(wasm: Vec<u8>) -> Result<Self> {
+        let runtime_code = stylus_runtime_code(wasm)?;
+        let stripped = stylus_wasm(&runtime_code)?;
+
+        let mut imports = Vec::new();
+        let mut exports = Vec::new();
+        let mut memory_pages = 0;
+        for payload in Parser::new(0).parse_all(&stripped) {
+            match payload? {
+                Payload::ImportSection(reader) => {
+                    for import in reader {
+                        let import = import?;
+                        imports.push(format!("{}::{}", import.module, import.name));
+                    }
+                }
+                Payload::ExportSection(reader) => {
+                    for export in reader {
+                        exports.push(export?.name.to_string());
+                    }
+                }
+                Payload::MemorySection(reader) => {
+                    for memory in reader {
+                        memory_pages += memory?.initial;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let (activation_gas, data_fee) = dry_activate(runtime_code.clone())?;
+
+        Ok(Self {
+            stylus_size: stripped.len(),
+            compressed_size: runtime_code.len(),
+            imports,
+            exports,
+            memory_pages,
+            activation_gas,
+            data_fee,
+            codehash: keccak256(&runtime_code),
+        })
+    }
+}
+
+/// Activates a program in an empty in-memory state, returning the gas used by the activation and
+/// the data fee it requires.
+fn dry_activate(runtime_code: Bytes) -> Result<(u64, U256)> {
+    let mut executor = ExecutorBuilder::new().build(Env::default(), Backend::spawn(None)?);
+    executor.set_code(DRY_ACTIVATION_ADDRESS, Bytecode::new_raw(runtime_code))?;
+    executor.set_balance(CALLER, U256::MAX)?;
+
+    let calldata: Bytes =
+        ArbWasm::activateProgramCall { program: DRY_ACTIVATION_ADDRESS }.abi_encode().into();
+
+    // The activation reverts with the required data fee if it is not paid.
+    let mut value = U256::ZERO;
+    loop {
+        let result = executor.call_raw(CALLER, ARB_WASM, calldata.clone(), value)?;
+        if !result.reverted {
+            let data_fee =
+                ArbWasm::activateProgramCall::abi_decode_returns(&result.result)?.dataFee;
+            return Ok((result.gas_used.saturating_sub(result.stipend), data_fee));
+        }
+        match ArbWasm::ProgramInsufficientValue::abi_decode(&result.result) {
+            Ok(required) if value.is_zero() => value = required.want,
+            _ => eyre::bail!(
+                "failed to activate Stylus program: {}",
+                RevertDecoder::new().decode(&result.result, result.exit_reason)
+            ),
+        }
+    }
+}
@@ -4,6 +4,7 @@
 use foundry_compilers::artifacts::{ConfigurableContractArtifact, Metadata, remappings::Remapping};
 use foundry_config::{
     BasicConfig, Chain, Config, DenyLevel, FuzzConfig, InvariantConfig, SolidityErrorCode,
+    fs_permissions::{FsPermissions, PathPermission},
     parse_with_profile,
 };
 use foundry_test_utils::{
@@ -2932,6 +2933,77 @@ struct Bar {
         );
 });
 
+// Stylus programs are reported like contracts, with their activation cost when they are activated
+// through `ArbWasm`.
+forgetest_init!(gas_report_stylus_program, |prj, cmd| {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../testdata/fixtures/Stylus");
+    fs::create_dir_all(prj.root().join("stylus")).unwrap();
+    fs::copy(fixtures.join("foundry_stylus_program.wasm"), prj.root().join("stylus/echo.wasm"))
+        .unwrap();
+    fs::write(
+        prj.root().join("stylus/echo.abi.json"),
+        r#"[{"type":"function","name":"echo","inputs":[{"name":"value","type":"uint256"}],"outputs":[{"name":"","type":"uint256"}],"stateMutability":"nonpayable"}]"#,
+    )
+    .unwrap();
+    prj.update_config(|config| {
+        config.fs_permissions = FsPermissions::new(vec![PathPermission::read("stylus")]);
+    });
+    prj.add_test(
+        "EchoTest.sol",
+        r#"
+import {Test} from "forge-std/Test.sol";
+interface StylusVm {
+    function getStylusCode(string calldata artifactPath) external view returns (bytes memory);
+}
+interface ArbWasm {
+    function activateProgram(address program) external payable returns (uint16, uint256);
+}
+interface Echo {
+    function echo(uint256 value) external returns (uint256);
+}
+contract EchoTest is Test {
+    function test_echo() external {
+        address program = address(0x1234);
+        vm.etch(program, StylusVm(address(vm)).getStylusCode("stylus/echo.wasm"));
+        ArbWasm(address(0x71)).activateProgram{value: 1 ether}(program);
+        Echo(program).echo(1);
+    }
+}
+"#,
+    );
+
+    let out = cmd
+        .args(["test", "--mt", "test_echo", "--gas-report"])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let table = out
+        .split("\n\n")
+        .find(|table| table.contains("stylus/echo.wasm:echo Contract"))
+        .unwrap_or_else(|| panic!("missing Stylus program table: {out}"));
+    assert!(table.contains("Activation Cost"), "{table}");
+    // Every row of the table has the same number of columns.
+    let columns = |line: &str| line.matches(['|', '+']).count();
+    let rows = table.lines().filter(|line| line.starts_with('|')).collect::<Vec<_>>();
+    assert!(rows.iter().all(|row| columns(row) == columns(rows[0])), "{table}");
+
+    let out = cmd
+        .forge_fuse()
+        .args(["test", "--mt", "test_echo", "--gas-report", "--json"])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let report: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+    let echo = report
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|contract| contract["contract"] == "stylus/echo.wasm:echo")
+        .unwrap_or_else(|| panic!("missing Stylus program: {out}"));
+    assert!(echo["deployment"]["activation"].as_u64().is_some_and(|gas| gas > 0), "{echo}");
+    assert_eq!(echo["functions"]["echo(uint256)"]["calls"], 1, "{echo}");
+});
+
 forgetest_init!(can_use_absolute_imports, |prj, cmd| {
     prj.initialize_default_contracts();
     prj.update_config(|config| {
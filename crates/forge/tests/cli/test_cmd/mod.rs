@@ -4299,3 +4299,55 @@ struct SomeStruct {
 
 "#]]);
 });
+
+// Runs every suite once per ArbOS version of the version matrix.
+forgetest_init!(arbos_version_matrix, |prj, cmd| {
+    prj.update_config(|config| config.stylus.arbos_versions = vec![30, 31]);
+
+    let out =
+        cmd.args(["test", "--mc", "CounterTest"]).assert_success().get_output().stdout_lossy();
+    assert!(out.contains("for test/Counter.t.sol:CounterTest [ArbOS 30]"), "{out}");
+    assert!(out.contains("for test/Counter.t.sol:CounterTest [ArbOS 31]"), "{out}");
+    assert!(out.contains("Ran 2 test suites"), "{out}");
+});
+
+// Reports the tests whose results differ between the ArbOS versions of the version matrix.
+forgetest_init!(arbos_version_matrix_differences, |prj, cmd| {
+    prj.update_config(|config| config.stylus.arbos_versions = vec![30, 31]);
+    prj.add_test(
+        "ArbosVersion.t.sol",
+        r#"
+import {Test} from "forge-std/Test.sol";
+
+contract ArbosVersionTest is Test {
+    uint256 number;
+
+    function testSame() public {
+        number = 1;
+    }
+
+    function testDifferent() public {
+        (, bytes memory version) = address(0x64).staticcall(abi.encodeWithSignature("arbOSVersion()"));
+        // ArbOS versions are offset by 55 in `ArbSys.arbOSVersion`.
+        if (abi.decode(version, (uint256)) > 55 + 30) {
+            number = 1;
+        }
+    }
+}
+"#,
+    );
+
+    let out =
+        cmd.args(["test", "--mc", "ArbosVersionTest"]).assert_success().get_output().stdout_lossy();
+    assert!(out.contains("Tests whose results differ between ArbOS versions:"), "{out}");
+    assert!(out.contains("test/ArbosVersion.t.sol:ArbosVersionTest::testDifferent()"), "{out}");
+    assert!(!out.contains("ArbosVersionTest::testSame()"), "{out}");
+
+    let out = cmd.arg("--json").assert_success().get_output().stdout_lossy();
+    let results: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+    for version in [30, 31] {
+        let suite = &results[format!("test/ArbosVersion.t.sol:ArbosVersionTest [ArbOS {version}]")];
+        assert_eq!(suite["arbos_version"], version);
+        assert_eq!(suite["arbos_version_differences"], serde_json::json!(["testDifferent()"]));
+    }
+});
@@ -8,16 +8,17 @@
 use forge_script_sequence::ScriptSequence;
 use foundry_cheatcodes::Wallets;
 use foundry_common::{
-    ContractData, ContractsByArtifact, compile::ProjectCompiler, provider::try_get_http_provider,
+    ContractData, ContractsByArtifact, ContractsByArtifactBuilder, compile::ProjectCompiler,
+    provider::try_get_http_provider,
 };
 use foundry_compilers::{
     ArtifactId, ProjectCompileOutput,
-    artifacts::{BytecodeObject, Libraries},
+    artifacts::{BytecodeObject, CompactContractBytecodeCow, Libraries},
     compilers::{Language, multi::MultiCompilerLanguage},
     info::ContractInfo,
     utils::source_files_iter,
 };
-use foundry_evm::traces::debug::ContractSources;
+use foundry_evm::{stylus::stylus_contracts, traces::debug::ContractSources};
 use foundry_linking::Linker;
 use std::{path::PathBuf, str::FromStr, sync::Arc};
 
@@ -30,6 +31,8 @@ pub struct BuildData {
     pub output: ProjectCompileOutput,
     /// ID of target contract artifact.
     pub target: ArtifactId,
+    /// The Stylus programs of the project, which are not part of the compiler output.
+    pub stylus_contracts: Vec<(ArtifactId, CompactContractBytecodeCow<'static>)>,
 }
 
 impl BuildData {
@@ -137,8 +140,11 @@ pub fn new(
             Some(&libraries),
         )?;
 
-        let known_contracts =
-            ContractsByArtifact::new(build_data.get_linker().get_linked_artifacts(&libraries)?);
+        let known_contracts = ContractsByArtifactBuilder::new(
+            build_data.get_linker().get_linked_artifacts_cow(&libraries)?,
+        )
+        .with_artifacts(build_data.stylus_contracts.clone())
+        .build();
 
         Ok(Self { build_data, known_contracts, libraries, predeploy_libraries, sources })
     }
@@ -231,7 +237,12 @@ pub fn compile(self) -> Result<CompiledState> {
             args,
             script_config,
             script_wallets,
-            build_data: BuildData { output, target, project_root: project.root().to_path_buf() },
+            build_data: BuildData {
+                output,
+                target,
+                project_root: project.root().to_path_buf(),
+                stylus_contracts: stylus_contracts(&script_config.config)?,
+            },
         })
     }
 }
@@ -100,6 +100,12 @@ pub struct VerifyBytecodeArgs {
     /// Ignore verification for creation or runtime bytecode.
     #[arg(long, value_name = "BYTECODE_TYPE")]
     pub ignore: Option<BytecodeType>,
+
+    /// Verify a Stylus program configured in `[stylus.programs]`, named by the contract argument.
+    ///
+    /// The program is rebuilt and compared with the onchain code, without querying the explorer.
+    #[arg(long, conflicts_with = "ignore")]
+    pub stylus: bool,
 }
 
 impl figment::Provider for VerifyBytecodeArgs {
@@ -133,6 +139,9 @@ impl VerifyBytecodeArgs {
     pub async fn run(mut self) -> Result<()> {
         // Setup
         let config = self.load_config()?;
+        if self.stylus {
+            return crate::stylus::verify_stylus_bytecode(&self, &config).await;
+        }
         let provider = utils::get_provider(&config)?;
 
         // If chain is not set, we try to get it from the RPC.
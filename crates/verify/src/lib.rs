@@ -21,6 +21,8 @@
 
 mod sourcify;
 
+pub mod stylus;
+
 pub mod verify;
 pub use verify::{VerifierArgs, VerifyArgs, VerifyCheckArgs};
 
@@ -0,0 +1,147 @@
+//! Verification of Stylus programs.
+//!
+//! Stylus programs are verified from the Rust crate configured for them in `[stylus.programs]`.
+//! The crate is rebuilt locally, and the resulting WASM binary is stripped and compressed the same
+//! way it is for deployment, so that its codehash can be compared with the one of the onchain
+//! program, with `forge verify-bytecode --stylus`.
+//!
+//! Only this local match is implemented: the crate is not packaged or submitted to Arbiscan or
+//! Sourcify, so `forge verify-contract` does not support Stylus programs.
+
+use crate::{bytecode::VerifyBytecodeArgs, types::VerificationType};
+use alloy_primitives::{Address, B256, Bytes, keccak256};
+use alloy_provider::Provider;
+use alloy_rpc_types::BlockId;
+use eyre::{OptionExt, Result};
+use foundry_cli::utils;
+use foundry_common::{
+    fs, shell,
+    stylus::{StylusArtifact, StylusCompiler},
+};
+use foundry_config::Config;
+use foundry_evm::core::stylus::{is_stylus_code, stylus_runtime_code, stylus_wasm};
+use serde::Serialize;
+use yansi::Paint;
+
+/// Builds the Stylus program with the given name, as configured in `[stylus.programs]`.
+pub fn build_stylus_program(config: &Config, name: &str) -> Result<StylusArtifact> {
+    if !config.stylus.programs.contains_key(name) {
+        let names = config.stylus.programs.keys().map(String::as_str).collect::<Vec<_>>();
+        eyre::bail!(
+            "unknown Stylus program `{name}`, expected one of: {}",
+            if names.is_empty() { "<none configured>".to_string() } else { names.join(", ") }
+        );
+    }
+
+    let out = config.root.join(&config.out);
+    let artifacts =
+        StylusCompiler::new().quiet(true).compile(&config.root, &out, &config.stylus.programs)?;
+    artifacts.get(name).cloned().ok_or_eyre("Stylus program was not built")
+}
+
+/// The result of comparing a locally built Stylus program with its onchain code.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StylusCodeMatch {
+    /// The codehash of the locally built program.
+    pub local_codehash: B256,
+    /// The codehash of the onchain program.
+    pub onchain_codehash: B256,
+    /// How well the programs match, or `None` if they do not.
+    pub match_type: Option<VerificationType>,
+}
+
+/// Compares a locally built WASM binary with the onchain code of a Stylus program.
+///
+/// The match is full if the codehashes are equal, and partial if only the WASM binaries are, i.e.
+/// the onchain program was compressed differently.
+pub fn match_stylus_code(wasm: &[u8], onchain_code: &[u8]) -> Result<StylusCodeMatch> {
+    let local_code = stylus_runtime_code(wasm.to_vec())?;
+    let local_codehash = keccak256(&local_code);
+    let onchain_codehash = keccak256(onchain_code);
+
+    let match_type = if local_codehash == onchain_codehash {
+        Some(VerificationType::Full)
+    } else if stylus_wasm(onchain_code)? == stylus_wasm(&local_code)? {
+        Some(VerificationType::Partial)
+    } else {
+        None
+    };
+
+    Ok(StylusCodeMatch { local_codehash, onchain_codehash, match_type })
+}
+
+/// Fetches the code of the Stylus program at `address`.
+async fn stylus_code_at(
+    config: &Config,
+    address: Address,
+    block: Option<BlockId>,
+) -> Result<Bytes> {
+    if config.get_rpc_url().is_none() {
+        eyre::bail!("You have to provide a valid RPC URL to verify Stylus programs");
+    }
+    let provider = utils::get_provider(config)?;
+    let code = provider.get_code_at(address).block_id(block.unwrap_or_default()).await?;
+    if code.is_empty() {
+        eyre::bail!("No bytecode found at address {address}");
+    }
+    if !is_stylus_code(&code) {
+        eyre::bail!("The contract at {address} is not a Stylus program");
+    }
+    Ok(code)
+}
+
+/// Runs `forge verify-bytecode --stylus`, comparing the onchain program with a local build
+/// without querying the explorer.
+pub(crate) async fn verify_stylus_bytecode(
+    args: &VerifyBytecodeArgs,
+    config: &Config,
+) -> Result<()> {
+    let name = &args.contract.name;
+    let code = stylus_code_at(config, args.address, args.block).await?;
+    if !shell::is_json() {
+        sh_println!("Verifying Stylus program {name} at address {}", args.address)?;
+    }
+
+    let artifact = build_stylus_program(config, name)?;
+    let result = match_stylus_code(&fs::read(&artifact.wasm)?, &code)?;
+
+    if shell::is_json() {
+        sh_println!("{}", serde_json::to_string(&result)?)?;
+    } else if let Some(match_type) = result.match_type {
+        sh_println!(
+            "{} with status {}\nCodehash: {}",
+            "Runtime code matched".green().bold(),
+            match_type.green().bold(),
+            result.onchain_codehash
+        )?;
+    } else {
+        sh_err!(
+            "Runtime code did not match - this may be due to a different toolchain or build \
+             profile\nLocal codehash:   {}\nOnchain codehash: {}",
+            result.local_codehash,
+            result.onchain_codehash
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use foundry_evm::core::stylus::WASM_MAGIC;
+
+    #[test]
+    fn matches_stylus_code() {
+        let wasm = [WASM_MAGIC.as_slice(), &[0x01, 0x00, 0x00, 0x00]].concat();
+        let onchain = stylus_runtime_code(wasm.clone()).unwrap();
+
+        let result = match_stylus_code(&wasm, &onchain).unwrap();
+        assert_eq!(result.match_type, Some(VerificationType::Full));
+        assert_eq!(result.onchain_codehash, keccak256(&onchain));
+
+        // An empty type section.
+        let other = [wasm.as_slice(), &[0x01, 0x01, 0x00]].concat();
+        assert_eq!(match_stylus_code(&other, &onchain).unwrap().match_type, None);
+    }
+}
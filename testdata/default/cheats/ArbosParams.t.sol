@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.18;
+
+import "utils/Test.sol";
+
+interface IArbSys {
+    function arbOSVersion() external view returns (uint256);
+}
+
+interface IArbGasInfo {
+    function getL1BaseFeeEstimate() external view returns (uint256);
+    function getMinimumGasPrice() external view returns (uint256);
+    function getPricesInWei() external view returns (uint256, uint256, uint256, uint256, uint256, uint256);
+}
+
+interface IArbWasm {
+    function inkPrice() external view returns (uint32);
+}
+
+contract ArbosParamsTest is Test {
+    IArbSys constant ARB_SYS = IArbSys(address(0x64));
+    IArbGasInfo constant ARB_GAS_INFO = IArbGasInfo(address(0x6c));
+    IArbWasm constant ARB_WASM = IArbWasm(address(0x71));
+
+    function testSetL1BaseFee() public {
+        vm.setL1BaseFee(42 gwei);
+        assertEq(ARB_GAS_INFO.getL1BaseFeeEstimate(), 42 gwei);
+
+        vm.setArbosParam("l1_base_fee", 7 gwei);
+        assertEq(ARB_GAS_INFO.getL1BaseFeeEstimate(), 7 gwei);
+    }
+
+    function testSetL2BaseFee() public {
+        vm.setArbosParam("l2_min_base_fee", 0.01 gwei);
+        vm.setL2BaseFee(0.1 gwei);
+        assertEq(block.basefee, 0.1 gwei);
+        assertEq(ARB_GAS_INFO.getMinimumGasPrice(), 0.01 gwei);
+
+        (,,, uint256 perArbGasBase,, uint256 perArbGasTotal) = ARB_GAS_INFO.getPricesInWei();
+        assertEq(perArbGasBase, 0.01 gwei);
+        assertEq(perArbGasTotal, 0.1 gwei);
+    }
+
+    function testSetArbOSVersion() public {
+        vm.setArbOSVersion(32);
+        // ArbOS versions are offset by 55 in `ArbSys.arbOSVersion`.
+        assertEq(ARB_SYS.arbOSVersion(), 55 + 32);
+    }
+
+    function testSetStylusParam() public {
+        vm.setArbosParam("ink_price", 20000);
+        assertEq(ARB_WASM.inkPrice(), 20000);
+
+        vm._expectCheatcodeRevert("vm.setArbosParam: 4294967296 is out of range for `ink_price`");
+        vm.setArbosParam("ink_price", uint256(type(uint32).max) + 1);
+    }
+
+    function testSetArbosParams() public {
+        string[] memory names = new string[](3);
+        names[0] = "l1_base_fee";
+        names[1] = "l2_min_base_fee";
+        names[2] = "ink_price";
+        uint256[] memory values = new uint256[](3);
+        values[0] = 7 gwei;
+        values[1] = 0.01 gwei;
+        values[2] = 20000;
+        vm.setArbosParams(names, values);
+        assertEq(ARB_GAS_INFO.getL1BaseFeeEstimate(), 7 gwei);
+        assertEq(ARB_GAS_INFO.getMinimumGasPrice(), 0.01 gwei);
+        assertEq(ARB_WASM.inkPrice(), 20000);
+
+        // Nothing is set if a value is out of range.
+        values[0] = 8 gwei;
+        values[2] = uint256(type(uint32).max) + 1;
+        vm._expectCheatcodeRevert("vm.setArbosParams: 4294967296 is out of range for `ink_price`");
+        vm.setArbosParams(names, values);
+        assertEq(ARB_GAS_INFO.getL1BaseFeeEstimate(), 7 gwei);
+
+        vm._expectCheatcodeRevert("vm.setArbosParams: names and values must have the same length");
+        vm.setArbosParams(names, new uint256[](2));
+    }
+
+    function testSetUnknownParam() public {
+        vm._expectCheatcodeRevert();
+        vm.setArbosParam("l1BaseFee", 1);
+    }
+}
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.18;
+
+import "utils/Test.sol";
+
+contract EmptyReverter {
+    fallback() external {
+        revert();
+    }
+}
+
+contract ExpectStylusTrapTest is Test {
+    address program;
+
+    function setUp() public {
+        program = vm.deployStylusCode("fixtures/Stylus/foundry_stylus_trap.wasm");
+    }
+
+    function testExpectOutOfInk() public {
+        vm.expectStylusTrap(Vm.StylusTrapKind.OutOfInk);
+        (bool success,) = program.call{gas: 1_000_000}(hex"02");
+        assertTrue(success);
+    }
+
+    function testExpectStackOverflow() public {
+        vm.expectStylusTrap(Vm.StylusTrapKind.StackOverflow);
+        (bool success,) = program.call(hex"03");
+        assertTrue(success);
+    }
+
+    // `unreachable` and exceeding the page limit revert without data, like a plain revert.
+    function testUnreachableIsNotATrap() public {
+        vm.expectStylusTrap(Vm.StylusTrapKind.OutOfInk);
+        (bool success, bytes memory data) = program.call(hex"01");
+        assertFalse(success);
+        assertEq(data, cheatcodeError("next call failed without a Stylus trap, expected `out of ink`"));
+    }
+
+    function testPageLimitIsNotATrap() public {
+        vm.expectStylusTrap(Vm.StylusTrapKind.OutOfInk);
+        (bool success, bytes memory data) = program.call(hex"04");
+        assertFalse(success);
+        assertEq(data, cheatcodeError("next call failed without a Stylus trap, expected `out of ink`"));
+    }
+
+    function testExpectStylusTrapWrongKind() public {
+        vm.expectStylusTrap(Vm.StylusTrapKind.StackOverflow);
+        (bool success, bytes memory data) = program.call{gas: 1_000_000}(hex"02");
+        assertFalse(success);
+        string memory message = string.concat(
+            "expected Stylus trap `stack overflow`, got Stylus trap: out of ink in ", vm.toString(program)
+        );
+        assertEq(data, cheatcodeError(message));
+    }
+
+    function testExpectStylusTrapNoTrap() public {
+        vm.expectStylusTrap(Vm.StylusTrapKind.OutOfInk);
+        (bool success, bytes memory data) = program.call(hex"00");
+        assertFalse(success);
+        assertEq(data, cheatcodeError("next call did not trap with `out of ink` as expected"));
+    }
+
+    function testExpectStylusTrapEvmRevert() public {
+        address reverter = address(new EmptyReverter());
+        vm.expectStylusTrap(Vm.StylusTrapKind.OutOfInk);
+        (bool success, bytes memory data) = reverter.call("");
+        assertFalse(success);
+        assertEq(data, cheatcodeError("next call failed without a Stylus trap, expected `out of ink`"));
+    }
+
+    function testExpectStylusTrapOnlyChecksNextCall() public {
+        vm.expectStylusTrap(Vm.StylusTrapKind.OutOfInk);
+        (bool success,) = program.call{gas: 1_000_000}(hex"02");
+        assertTrue(success);
+
+        (success,) = program.call{gas: 1_000_000}(hex"02");
+        assertFalse(success);
+    }
+
+    function cheatcodeError(string memory message) internal pure returns (bytes memory) {
+        return abi.encodeWithSignature("CheatcodeError(string)", message);
+    }
+}
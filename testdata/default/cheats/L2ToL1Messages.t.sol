@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.18;
+
+import "utils/Test.sol";
+
+interface IArbSys {
+    function sendTxToL1(address destination, bytes calldata data) external payable returns (uint256);
+    function withdrawEth(address destination) external payable returns (uint256);
+}
+
+contract Withdrawer {
+    IArbSys constant ARB_SYS = IArbSys(address(0x64));
+
+    function sendAndRevert(address destination, bytes calldata data) external {
+        ARB_SYS.sendTxToL1(destination, data);
+        revert("reverted");
+    }
+}
+
+contract L2ToL1MessagesTest is Test {
+    IArbSys constant ARB_SYS = IArbSys(address(0x64));
+
+    address destination = address(0xdead);
+    bytes data = abi.encodeWithSignature("finalize(uint256)", 42);
+
+    function testGetL2ToL1Messages() public {
+        vm.deal(address(this), 1 ether);
+        ARB_SYS.sendTxToL1(destination, data);
+        ARB_SYS.withdrawEth{value: 1 ether}(destination);
+
+        Vm.L2ToL1Message[] memory messages = vm.getL2ToL1Messages();
+        assertEq(messages.length, 2);
+
+        assertEq(messages[0].caller, address(this));
+        assertEq(messages[0].destination, destination);
+        assertEq(messages[0].callvalue, 0);
+        assertEq(messages[0].data, data);
+
+        assertEq(messages[1].caller, address(this));
+        assertEq(messages[1].destination, destination);
+        assertEq(messages[1].callvalue, 1 ether);
+        assertEq(messages[1].data.length, 0);
+    }
+
+    function testRevertedMessagesAreDiscarded() public {
+        Withdrawer withdrawer = new Withdrawer();
+        try withdrawer.sendAndRevert(destination, data) {} catch {}
+        assertEq(vm.getL2ToL1Messages().length, 0);
+    }
+
+    function testMessagesAreRevertedWithState() public {
+        ARB_SYS.sendTxToL1(destination, data);
+        (bytes32 root,) = vm.getL2ToL1MessageProof(0);
+
+        uint256 snapshot = vm.snapshotState();
+        ARB_SYS.sendTxToL1(destination, abi.encode(1));
+        assertEq(vm.getL2ToL1Messages().length, 2);
+
+        vm.revertToState(snapshot);
+        Vm.L2ToL1Message[] memory messages = vm.getL2ToL1Messages();
+        assertEq(messages.length, 1);
+        assertEq(messages[0].data, data);
+        (bytes32 revertedRoot, bytes32[] memory proof) = vm.getL2ToL1MessageProof(0);
+        assertEq(revertedRoot, root);
+        assertEq(proof.length, 0);
+
+        // the next message takes the position of the reverted one
+        ARB_SYS.sendTxToL1(destination, abi.encode(2));
+        messages = vm.getL2ToL1Messages();
+        assertEq(messages.length, 2);
+        assertEq(messages[1].position, 1);
+        assertEq(messages[1].data, abi.encode(2));
+    }
+
+    function testExpectL2ToL1Message() public {
+        vm.expectL2ToL1Message(destination, data);
+        ARB_SYS.sendTxToL1(destination, data);
+
+        vm.deal(address(this), 1 ether);
+        vm.expectL2ToL1Message(destination, 1 ether, "");
+        ARB_SYS.withdrawEth{value: 1 ether}(destination);
+    }
+
+    function testL2ToL1MessageProof() public {
+        for (uint256 i = 0; i < 3; i++) {
+            ARB_SYS.sendTxToL1(destination, abi.encode(i));
+        }
+
+        Vm.L2ToL1Message[] memory messages = vm.getL2ToL1Messages();
+        for (uint256 i = 0; i < messages.length; i++) {
+            Vm.L2ToL1Message memory message = messages[i];
+            bytes32 itemHash = keccak256(
+                abi.encodePacked(
+                    message.caller,
+                    message.destination,
+                    message.arbBlockNum,
+                    message.ethBlockNum,
+                    message.timestamp,
+                    message.callvalue,
+                    message.data
+                )
+            );
+            assertEq(uint256(itemHash), message.hash);
+
+            (bytes32 root, bytes32[] memory proof) = vm.getL2ToL1MessageProof(i);
+            assertEq(proof.length, 2);
+            assertEq(message.position, i);
+            assertEq(calculateMerkleRoot(proof, message.position, itemHash), root);
+        }
+
+        vm._expectCheatcodeRevert("vm.getL2ToL1MessageProof: no L2 to L1 message at index 3, only 3 were sent");
+        vm.getL2ToL1MessageProof(3);
+    }
+
+    // Mirrors `Outbox.calculateMerkleRoot`.
+    function calculateMerkleRoot(bytes32[] memory proof, uint256 path, bytes32 item)
+        internal
+        pure
+        returns (bytes32 hash)
+    {
+        hash = keccak256(abi.encodePacked(item));
+        for (uint256 i = 0; i < proof.length; i++) {
+            if ((path & (1 << i)) == 0) {
+                hash = keccak256(abi.encodePacked(hash, proof[i]));
+            } else {
+                hash = keccak256(abi.encodePacked(proof[i], hash));
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.18;
+
+import "utils/Test.sol";
+
+interface IArbSys {
+    function wasMyCallersAddressAliased() external view returns (bool);
+    function myCallersAddressWithoutAliasing() external view returns (address);
+}
+
+contract L1Gated {
+    IArbSys constant ARB_SYS = IArbSys(address(0x64));
+
+    address public l1Owner;
+    uint256 public value;
+
+    constructor(address _l1Owner) {
+        l1Owner = _l1Owner;
+    }
+
+    function setValue(uint256 _value) external {
+        require(ARB_SYS.wasMyCallersAddressAliased(), "not aliased");
+        require(ARB_SYS.myCallersAddressWithoutAliasing() == l1Owner, "not owner");
+        value = _value;
+    }
+
+    function senders() external view returns (address sender, address origin) {
+        return (msg.sender, tx.origin);
+    }
+}
+
+contract PrankFromL1Test is Test {
+    address l1Owner = address(0x1234);
+    L1Gated gated;
+
+    function setUp() public {
+        gated = new L1Gated(l1Owner);
+    }
+
+    function testAliasing() public {
+        address l1 = address(0xFFfFfFffFFfffFFfFFfFFFFFffFFFffffFfFFFfF);
+        address l2 = address(0x1111000000000000000000000000000000001110);
+        assertEq(vm.applyL1ToL2Alias(l1), l2);
+        assertEq(vm.undoL1ToL2Alias(l2), l1);
+        assertEq(
+            vm.applyL1ToL2Alias(l1Owner), address(uint160(l1Owner) + uint160(0x1111000000000000000000000000000000001111))
+        );
+    }
+
+    function testPrankFromL1SetsAliasedSenders() public {
+        address aliased = vm.applyL1ToL2Alias(l1Owner);
+        vm.prankFromL1(l1Owner);
+        (address sender, address origin) = gated.senders();
+        assertEq(sender, aliased);
+        assertEq(origin, aliased);
+
+        // The prank only applies to the next call.
+        (sender,) = gated.senders();
+        assertEq(sender, address(this));
+    }
+
+    function testPrankFromL1IsAliased() public {
+        vm.prankFromL1(l1Owner);
+        gated.setValue(42);
+        assertEq(gated.value(), 42);
+    }
+
+    function testPrankFromOtherL1Sender() public {
+        vm.prankFromL1(address(0xbad));
+        vm.expectRevert("not owner");
+        gated.setValue(42);
+    }
+
+    function testPrankWithAliasIsNotAliased() public {
+        // Pranking the alias does not make the call come from L1.
+        vm.prank(vm.applyL1ToL2Alias(l1Owner));
+        vm.expectRevert("not aliased");
+        gated.setValue(42);
+    }
+}
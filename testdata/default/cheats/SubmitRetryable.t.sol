@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.18;
+
+import "utils/Test.sol";
+
+interface IArbRetryableTx {
+    function redeem(bytes32 ticketId) external returns (bytes32);
+    function getTimeout(bytes32 ticketId) external view returns (uint256);
+    function keepalive(bytes32 ticketId) external returns (uint256);
+    function getBeneficiary(bytes32 ticketId) external view returns (address);
+    function cancel(bytes32 ticketId) external;
+
+    error NoTicketWithID();
+}
+
+contract Receiver {
+    bool public ready;
+    address public lastSender;
+    uint256 public received;
+
+    function setReady(bool _ready) external {
+        ready = _ready;
+    }
+
+    function receiveMessage() external payable {
+        require(ready, "not ready");
+        lastSender = msg.sender;
+        received += msg.value;
+    }
+}
+
+contract SubmitRetryableTest is Test {
+    IArbRetryableTx constant ARB_RETRYABLE_TX = IArbRetryableTx(address(0x6e));
+    uint256 constant LIFETIME = 7 days;
+
+    address l1Sender = address(0x1234);
+    address aliased = address(uint160(l1Sender) + uint160(0x1111000000000000000000000000000000001111));
+    address beneficiary = address(0xbeef);
+    Receiver receiver;
+
+    function setUp() public {
+        receiver = new Receiver();
+    }
+
+    function submit(uint64 gasLimit) internal returns (bytes32) {
+        return vm.submitRetryable(
+            l1Sender,
+            address(receiver),
+            1 ether,
+            2 ether,
+            gasLimit,
+            beneficiary,
+            abi.encodeCall(Receiver.receiveMessage, ())
+        );
+    }
+
+    function testAutoRedeem() public {
+        receiver.setReady(true);
+        bytes32 ticketId = submit(1_000_000);
+
+        assertEq(receiver.lastSender(), aliased);
+        assertEq(receiver.received(), 1 ether);
+        assertEq(aliased.balance, 1 ether);
+
+        // redeemed tickets are deleted
+        vm.expectRevert(IArbRetryableTx.NoTicketWithID.selector);
+        ARB_RETRYABLE_TX.getTimeout(ticketId);
+    }
+
+    function testFailedAutoRedeemThenRedeem() public {
+        bytes32 ticketId = submit(1_000_000);
+        assertEq(receiver.received(), 0);
+        assertEq(ARB_RETRYABLE_TX.getTimeout(ticketId), block.timestamp + LIFETIME);
+        assertEq(ARB_RETRYABLE_TX.getBeneficiary(ticketId), beneficiary);
+
+        receiver.setReady(true);
+        ARB_RETRYABLE_TX.redeem(ticketId);
+        assertEq(receiver.lastSender(), aliased);
+        assertEq(receiver.received(), 1 ether);
+    }
+
+    function testNoAutoRedeem() public {
+        receiver.setReady(true);
+        bytes32 ticketId = submit(0);
+        assertEq(receiver.received(), 0);
+
+        ARB_RETRYABLE_TX.redeem(ticketId);
+        assertEq(receiver.received(), 1 ether);
+    }
+
+    function testKeepaliveAndExpiry() public {
+        bytes32 ticketId = submit(0);
+        uint256 timeout = ARB_RETRYABLE_TX.getTimeout(ticketId);
+
+        // a ticket can be extended while its timeout is at most a lifetime away
+        assertEq(ARB_RETRYABLE_TX.keepalive(ticketId), timeout + LIFETIME);
+        assertEq(ARB_RETRYABLE_TX.getTimeout(ticketId), timeout + LIFETIME);
+
+        vm.expectRevert("timeout too far into the future");
+        ARB_RETRYABLE_TX.keepalive(ticketId);
+
+        vm.warp(timeout);
+        assertEq(ARB_RETRYABLE_TX.keepalive(ticketId), timeout + 2 * LIFETIME);
+
+        vm.warp(timeout + 2 * LIFETIME + 1);
+        receiver.setReady(true);
+        vm.expectRevert(IArbRetryableTx.NoTicketWithID.selector);
+        ARB_RETRYABLE_TX.redeem(ticketId);
+    }
+
+    function testCancel() public {
+        bytes32 ticketId = submit(0);
+
+        vm.expectRevert("only the beneficiary may cancel a retryable");
+        ARB_RETRYABLE_TX.cancel(ticketId);
+
+        vm.prank(beneficiary);
+        ARB_RETRYABLE_TX.cancel(ticketId);
+        assertEq(beneficiary.balance, 1 ether);
+
+        vm.expectRevert(IArbRetryableTx.NoTicketWithID.selector);
+        ARB_RETRYABLE_TX.getTimeout(ticketId);
+    }
+
+    function testCancelWithDrainedEscrowReverts() public {
+        bytes32 ticketId = submit(0);
+        address escrow = address(uint160(uint256(keccak256(abi.encodePacked("retryable escrow", ticketId)))));
+        assertEq(escrow.balance, 1 ether);
+        vm.deal(escrow, 0);
+
+        vm.prank(beneficiary);
+        vm.expectRevert("failed to refund the retryable call value: OutOfFunds");
+        ARB_RETRYABLE_TX.cancel(ticketId);
+
+        // the ticket is kept
+        assertEq(ARB_RETRYABLE_TX.getBeneficiary(ticketId), beneficiary);
+    }
+
+    function testTicketsAreReverted() public {
+        uint256 snapshot = vm.snapshotState();
+        bytes32 ticketId = submit(0);
+        assertEq(ARB_RETRYABLE_TX.getBeneficiary(ticketId), beneficiary);
+
+        vm.revertToState(snapshot);
+        vm.expectRevert(IArbRetryableTx.NoTicketWithID.selector);
+        ARB_RETRYABLE_TX.getTimeout(ticketId);
+    }
+
+    function testChargesPrecompileGas() public {
+        bytes32 ticketId = submit(0);
+
+        uint256 gasBefore = gasleft();
+        ARB_RETRYABLE_TX.getTimeout(ticketId);
+        uint256 gasUsed = gasBefore - gasleft();
+        // the ArbOS version and the ticket's timeout are read from storage
+        assertGt(gasUsed, 2_400);
+        assertLt(gasUsed, 20_000);
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.18;
+
+import "utils/Test.sol";
+
+interface INodeInterface {
+    function estimateRetryableTicket(
+        address sender,
+        uint256 deposit,
+        address to,
+        uint256 l2CallValue,
+        address excessFeeRefundAddress,
+        address callValueRefundAddress,
+        bytes calldata data
+    ) external;
+    function gasEstimateComponents(address to, bool contractCreation, bytes calldata data)
+        external
+        payable
+        returns (uint64 gasEstimate, uint64 gasEstimateForL1, uint256 baseFee, uint256 l1BaseFeeEstimate);
+    function gasEstimateL1Component(address to, bool contractCreation, bytes calldata data)
+        external
+        payable
+        returns (uint64 gasEstimateForL1, uint256 baseFee, uint256 l1BaseFeeEstimate);
+    function nitroGenesisBlock() external pure returns (uint256 number);
+}
+
+contract Counter {
+    uint256 public count;
+
+    function increment() public {
+        count++;
+    }
+}
+
+/// @notice Tests the emulation of the virtual NodeInterface contract at address(0xc8)
+contract NodeInterfaceTest is Test {
+    INodeInterface constant NODE_INTERFACE = INodeInterface(address(0xc8));
+
+    Counter counter;
+
+    function setUp() public {
+        counter = new Counter();
+    }
+
+    function testNitroGenesisBlock() public {
+        assertEq(NODE_INTERFACE.nitroGenesisBlock(), 0);
+
+        // Arbitrum One started with Arbitrum Classic.
+        vm.chainId(42161);
+        assertEq(NODE_INTERFACE.nitroGenesisBlock(), 22_207_817);
+    }
+
+    function testCallsAreCharged() public {
+        // Reading the ArbOS version and pricing state costs 800 gas per slot.
+        uint256 gasBefore = gasleft();
+        NODE_INTERFACE.nitroGenesisBlock();
+        assertGt(gasBefore - gasleft(), 3 * 800);
+    }
+
+    function testGasEstimateL1Component() public {
+        vm.setL1BaseFee(10 gwei);
+        vm.setL2BaseFee(0.1 gwei);
+        (uint64 gasForL1, uint256 baseFee, uint256 l1BaseFee) =
+            NODE_INTERFACE.gasEstimateL1Component(address(counter), false, abi.encodeCall(Counter.increment, ()));
+        assertGt(gasForL1, 0);
+        assertEq(baseFee, 0.1 gwei);
+        assertEq(l1BaseFee, 10 gwei);
+
+        // The L1 component grows with the L1 base fee.
+        vm.setL1BaseFee(20 gwei);
+        (uint64 doubledGasForL1,,) =
+            NODE_INTERFACE.gasEstimateL1Component(address(counter), false, abi.encodeCall(Counter.increment, ()));
+        assertApproxEqAbs(doubledGasForL1, 2 * gasForL1, 1);
+    }
+
+    function testGasEstimateComponents() public {
+        vm.setL1BaseFee(10 gwei);
+        vm.setL2BaseFee(0.1 gwei);
+        (uint64 gasEstimate, uint64 gasForL1,,) =
+            NODE_INTERFACE.gasEstimateComponents(address(counter), false, abi.encodeCall(Counter.increment, ()));
+        // The intrinsic gas and the first write of `count`, plus the L1 component.
+        assertGt(gasEstimate, 21_000 + 20_000 + gasForL1);
+
+        // The estimated message is not committed.
+        assertEq(counter.count(), 0);
+    }
+
+    function testEstimatedMessagesIgnoreCheatcodes() public {
+        // The estimated message is not part of the test, so it does not count as an expected call.
+        vm.expectCall(address(counter), abi.encodeCall(Counter.increment, ()), 0);
+        NODE_INTERFACE.gasEstimateComponents(address(counter), false, abi.encodeCall(Counter.increment, ()));
+    }
+
+    function testGasEstimateL1ComponentPricesMessage() public {
+        vm.setL1BaseFee(10 gwei);
+        vm.setL2BaseFee(0.1 gwei);
+        // The message is priced as a transaction, so its target and value are charged too.
+        (uint64 gasForL1,,) = NODE_INTERFACE.gasEstimateL1Component(address(0), false, "");
+        assertGt(gasForL1, 0);
+        (uint64 gasWithValue,,) =
+            NODE_INTERFACE.gasEstimateL1Component{value: uint256(keccak256("value")) % 1 ether}(address(0), false, "");
+        assertGt(gasWithValue, gasForL1);
+    }
+
+    function testEstimateRetryableTicket() public {
+        address sender = makeAddr("sender");
+        // NodeInterface has no code, so it is called directly to skip Solidity's code size check.
+        (bool success,) = address(NODE_INTERFACE).call(
+            abi.encodeCall(
+                INodeInterface.estimateRetryableTicket,
+                (sender, 1 ether, address(counter), 0, sender, sender, abi.encodeCall(Counter.increment, ()))
+            )
+        );
+        assertTrue(success);
+        assertEq(counter.count(), 0);
+
+        // The retry is executed, and fails like it would on L2.
+        (success,) = address(NODE_INTERFACE).call(
+            abi.encodeCall(
+                INodeInterface.estimateRetryableTicket,
+                (sender, 1 ether, address(counter), 1, sender, sender, abi.encodeCall(Counter.increment, ()))
+            )
+        );
+        assertFalse(success);
+    }
+}
@@ -0,0 +1,36 @@
+;; Trap test program for arbos-revm
+;; Halts abnormally depending on the first byte of its input arguments:
+;; 0x01 executes `unreachable`, 0x02 loops until it runs out of ink, 0x03 recurses until it
+;; exceeds the maximum stack depth and 0x04 grows its memory past the default page limit of 128
+;; pages, executing `unreachable` like an allocation failure would when the growth is refused.
+;; Any other input returns successfully.
+
+(module
+    (import "vm_hooks" "read_args" (func $read_args (param i32)))
+    (memory (export "memory") 1)
+    (func $recurse
+        (call $recurse)
+    )
+    (func $main (export "user_entrypoint") (param $args_len i32) (result i32)
+        (local $kind i32)
+        ;; load args to 0x0
+        (call $read_args (i32.const 0))
+
+        (if (local.get $args_len)
+            (then (local.set $kind (i32.load8_u (i32.const 0)))))
+
+        (if (i32.eq (local.get $kind) (i32.const 1))
+            (then unreachable))
+        (if (i32.eq (local.get $kind) (i32.const 2))
+            (then (loop $spin (br $spin))))
+        (if (i32.eq (local.get $kind) (i32.const 3))
+            (then (call $recurse)))
+        (if (i32.eq (local.get $kind) (i32.const 4))
+            (then
+                (if (i32.eq (memory.grow (i32.const 128)) (i32.const -1))
+                    (then unreachable))))
+
+        ;; return success
+        i32.const 0
+    )
+)
@@ -10,6 +10,7 @@ interface Vm {
     enum AccountAccessKind { Call, DelegateCall, CallCode, StaticCall, Create, SelfDestruct, Resume, Balance, Extcodesize, Extcodehash, Extcodecopy }
     enum ForgeContext { TestGroup, Test, Coverage, Snapshot, ScriptGroup, ScriptDryRun, ScriptBroadcast, ScriptResume, Unknown }
     enum BroadcastTxType { Call, Create, Create2 }
+    enum StylusTrapKind { OutOfInk, StackOverflow }
     struct Log { bytes32[] topics; bytes data; address emitter; }
     struct Rpc { string key; string url; }
     struct EthGetLogs { address emitter; bytes32[] topics; bytes data; bytes32 blockHash; uint64 blockNumber; bytes32 transactionHash; uint64 transactionIndex; uint256 logIndex; bool removed; }
@@ -27,6 +28,7 @@ interface Vm {
     struct SignedDelegation { uint8 v; bytes32 r; bytes32 s; uint64 nonce; address implementation; }
     struct PotentialRevert { address reverter; bool partialMatch; bytes revertData; }
     struct AccessListItem { address target; bytes32[] storageKeys; }
+    struct L2ToL1Message { address caller; address destination; uint256 hash; uint256 position; uint256 arbBlockNum; uint256 ethBlockNum; uint256 timestamp; uint256 callvalue; bytes data; }
     function _expectCheatcodeRevert() external;
     function _expectCheatcodeRevert(bytes4 revertData) external;
     function _expectCheatcodeRevert(bytes calldata revertData) external;
@@ -35,6 +37,7 @@ interface Vm {
     function activeFork() external view returns (uint256 forkId);
     function addr(uint256 privateKey) external pure returns (address keyAddr);
     function allowCheatcodes(address account) external;
+    function applyL1ToL2Alias(address l1Address) external pure returns (address l2Address);
     function assertApproxEqAbsDecimal(uint256 left, uint256 right, uint256 maxDelta, uint256 decimals) external pure;
     function assertApproxEqAbsDecimal(uint256 left, uint256 right, uint256 maxDelta, uint256 decimals, string calldata error) external pure;
     function assertApproxEqAbsDecimal(int256 left, int256 right, uint256 maxDelta, uint256 decimals) external pure;
@@ -280,6 +283,8 @@ interface Vm {
     function expectEmit(bool checkTopic1, bool checkTopic2, bool checkTopic3, bool checkData, address emitter, uint64 count) external;
     function expectEmit(uint64 count) external;
     function expectEmit(address emitter, uint64 count) external;
+    function expectL2ToL1Message(address destination, bytes calldata data) external;
+    function expectL2ToL1Message(address destination, uint256 callvalue, bytes calldata data) external;
     function expectPartialRevert(bytes4 revertData) external;
     function expectPartialRevert(bytes4 revertData, address reverter) external;
     function expectRevert() external;
@@ -296,6 +301,7 @@ interface Vm {
     function expectRevert(address reverter, uint64 count) external;
     function expectSafeMemory(uint64 min, uint64 max) external;
     function expectSafeMemoryCall(uint64 min, uint64 max) external;
+    function expectStylusTrap(StylusTrapKind kind) external;
     function fee(uint256 newBasefee) external;
     function ffi(string[] calldata commandInput) external returns (bytes memory result);
     function foundryVersionAtLeast(string calldata version) external view returns (bool);
@@ -321,6 +327,8 @@ interface Vm {
     function getDeployments(string calldata contractName, uint64 chainId) external view returns (address[] memory deployedAddresses);
     function getEvmVersion() external pure returns (string memory evm);
     function getFoundryVersion() external view returns (string memory version);
+    function getL2ToL1MessageProof(uint256 index) external view returns (bytes32 root, bytes32[] memory proof);
+    function getL2ToL1Messages() external view returns (L2ToL1Message[] memory messages);
     function getLabel(address account) external view returns (string memory currentLabel);
     function getMappingKeyAndParentOf(address target, bytes32 elementSlot) external view returns (bool found, bytes32 key, bytes32 parent);
     function getMappingLength(address target, bytes32 mappingSlot) external view returns (uint256 length);
@@ -413,6 +421,7 @@ interface Vm {
     function parseUint(string calldata stringifiedValue) external pure returns (uint256 parsedValue);
     function pauseGasMetering() external;
     function pauseTracing() external view;
+    function prankFromL1(address l1Sender) external;
     function prank(address msgSender) external;
     function prank(address msgSender, address txOrigin) external;
     function prank(address msgSender, bool delegateCall) external;
@@ -492,11 +501,17 @@ interface Vm {
     function serializeUintToHex(string calldata objectKey, string calldata valueKey, uint256 value) external returns (string memory json);
     function serializeUint(string calldata objectKey, string calldata valueKey, uint256 value) external returns (string memory json);
     function serializeUint(string calldata objectKey, string calldata valueKey, uint256[] calldata values) external returns (string memory json);
+    function setArbOSVersion(uint64 newArbOSVersion) external;
     function setArbitraryStorage(address target) external;
     function setArbitraryStorage(address target, bool overwrite) external;
+    function setArbosParam(string calldata name, uint256 value) external;
+    function setArbosParams(string[] calldata names, uint256[] calldata values) external;
     function setBlockhash(uint256 blockNumber, bytes32 blockHash) external;
     function setEnv(string calldata name, string calldata value) external;
     function setEvmVersion(string calldata evm) external;
+    function setL1BaseFee(uint256 newL1BaseFee) external;
+    function setL1BlockNumber(uint256 newL1BlockNumber) external;
+    function setL2BaseFee(uint256 newL2BaseFee) external;
     function setNonce(address account, uint64 newNonce) external;
     function setNonceUnsafe(address account, uint64 newNonce) external;
     function setSeed(uint256 seed) external;
@@ -551,6 +566,7 @@ interface Vm {
     function stopSnapshotGas(string calldata name) external returns (uint256 gasUsed);
     function stopSnapshotGas(string calldata group, string calldata name) external returns (uint256 gasUsed);
     function store(address target, bytes32 slot, bytes32 value) external;
+    function submitRetryable(address l1Sender, address to, uint256 l2CallValue, uint256 deposit, uint64 gasLimit, address beneficiary, bytes calldata data) external returns (bytes32 ticketId);
     function toBase64URL(bytes calldata data) external pure returns (string memory);
     function toBase64URL(string calldata data) external pure returns (string memory);
     function toBase64(bytes calldata data) external pure returns (string memory);
@@ -569,6 +585,7 @@ interface Vm {
     function trim(string calldata input) external pure returns (string memory output);
     function tryFfi(string[] calldata commandInput) external returns (FfiResult memory result);
     function txGasPrice(uint256 newGasPrice) external;
+    function undoL1ToL2Alias(address l2Address) external pure returns (address l1Address);
     function unixTime() external view returns (uint256 milliseconds);
     function warmSlot(address target, bytes32 slot) external;
     function warp(uint256 newTimestamp) external;